@@ -0,0 +1,262 @@
+/// Lane values which commonly expose edge cases in SIMD kernels. Generators pick them
+/// more often than uniformly random values would.
+trait InterestingLanes: Sized + 'static {
+    const INTERESTING: &'static [Self];
+}
+
+macro_rules! impl_interesting_float {
+    ($type: ident) => {
+        impl InterestingLanes for $type {
+            const INTERESTING: &'static [Self] = &[
+                0.0,
+                -0.0,
+                1.0,
+                -1.0,
+                $type::MIN,
+                $type::MAX,
+                $type::MIN_POSITIVE,
+                $type::MIN_POSITIVE / 2.0,
+                -$type::MIN_POSITIVE / 2.0,
+                $type::EPSILON,
+                $type::INFINITY,
+                $type::NEG_INFINITY,
+                $type::NAN,
+            ];
+        }
+    };
+}
+
+macro_rules! impl_interesting_integer {
+    ($type: ident) => {
+        impl InterestingLanes for $type {
+            const INTERESTING: &'static [Self] = &[
+                0,
+                1,
+                $type::MIN,
+                $type::MAX,
+                $type::MIN.wrapping_add(1),
+                $type::MAX.wrapping_sub(1),
+                $type::wrapping_sub(0, 1),
+            ];
+        }
+    };
+}
+
+impl_interesting_float!(f32);
+impl_interesting_float!(f64);
+
+impl_interesting_integer!(i8);
+impl_interesting_integer!(u8);
+impl_interesting_integer!(i16);
+impl_interesting_integer!(u16);
+impl_interesting_integer!(i32);
+impl_interesting_integer!(u32);
+impl_interesting_integer!(i64);
+impl_interesting_integer!(u64);
+
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use proptest::prelude::*;
+
+    use super::InterestingLanes;
+    use crate::*;
+
+    macro_rules! impl_arbitrary {
+        ($name: ident, $type: ty, $lanes: expr) => {
+            impl Arbitrary for $name {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    let lane = prop_oneof![
+                        3 => any::<$type>(),
+                        1 => proptest::sample::select(<$type>::INTERESTING),
+                    ];
+
+                    proptest::array::uniform::<_, $lanes>(lane)
+                        .prop_map($name::from_array)
+                        .boxed()
+                }
+            }
+        };
+    }
+
+    impl_arbitrary!(Float32x4, f32, 4);
+    impl_arbitrary!(Float64x2, f64, 2);
+
+    impl_arbitrary!(Int8x16, i8, 16);
+    impl_arbitrary!(Uint8x16, u8, 16);
+
+    impl_arbitrary!(Int16x8, i16, 8);
+    impl_arbitrary!(Uint16x8, u16, 8);
+
+    impl_arbitrary!(Int32x4, i32, 4);
+    impl_arbitrary!(Uint32x4, u32, 4);
+
+    impl_arbitrary!(Int64x2, i64, 2);
+    impl_arbitrary!(Uint64x2, u64, 2);
+
+    impl_arbitrary!(Float32x8, f32, 8);
+    impl_arbitrary!(Float64x4, f64, 4);
+
+    impl_arbitrary!(Int8x32, i8, 32);
+    impl_arbitrary!(Uint8x32, u8, 32);
+
+    impl_arbitrary!(Int16x16, i16, 16);
+    impl_arbitrary!(Uint16x16, u16, 16);
+
+    impl_arbitrary!(Int32x8, i32, 8);
+    impl_arbitrary!(Uint32x8, u32, 8);
+
+    impl_arbitrary!(Int64x4, i64, 4);
+    impl_arbitrary!(Uint64x4, u64, 4);
+
+    impl_arbitrary!(Float32x16, f32, 16);
+    impl_arbitrary!(Float64x8, f64, 8);
+
+    impl_arbitrary!(Int8x64, i8, 64);
+    impl_arbitrary!(Uint8x64, u8, 64);
+
+    impl_arbitrary!(Int16x32, i16, 32);
+    impl_arbitrary!(Uint16x32, u16, 32);
+
+    impl_arbitrary!(Int32x16, i32, 16);
+    impl_arbitrary!(Uint32x16, u32, 16);
+
+    impl_arbitrary!(Int64x8, i64, 8);
+    impl_arbitrary!(Uint64x8, u64, 8);
+
+    #[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+    mod avx512 {
+        use super::*;
+        use crate::avx512::{
+            Float32x16, Float64x8, Int16x32, Int32x16, Int64x8, Int8x64, Uint16x32, Uint32x16,
+            Uint64x8, Uint8x64,
+        };
+
+        impl_arbitrary!(Float32x16, f32, 16);
+        impl_arbitrary!(Float64x8, f64, 8);
+
+        impl_arbitrary!(Int8x64, i8, 64);
+        impl_arbitrary!(Uint8x64, u8, 64);
+
+        impl_arbitrary!(Int16x32, i16, 32);
+        impl_arbitrary!(Uint16x32, u16, 32);
+
+        impl_arbitrary!(Int32x16, i32, 16);
+        impl_arbitrary!(Uint32x16, u32, 16);
+
+        impl_arbitrary!(Int64x8, i64, 8);
+        impl_arbitrary!(Uint64x8, u64, 8);
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impl {
+    use core::array;
+
+    use quickcheck::{Arbitrary, Gen};
+
+    use super::InterestingLanes;
+    use crate::*;
+
+    fn arbitrary_lane<T: Arbitrary + InterestingLanes>(g: &mut Gen) -> T {
+        if u8::arbitrary(g) % 4 == 0 {
+            g.choose(T::INTERESTING).unwrap().clone()
+        } else {
+            T::arbitrary(g)
+        }
+    }
+
+    macro_rules! impl_arbitrary {
+        ($name: ident, $type: ty, $lanes: expr) => {
+            impl Arbitrary for $name {
+                fn arbitrary(g: &mut Gen) -> Self {
+                    $name::from_array(array::from_fn(|_| arbitrary_lane::<$type>(g)))
+                }
+
+                /// Shrinks one lane at a time, keeping all other lanes intact.
+                fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                    let array = self.to_array();
+
+                    Box::new((0..$lanes).flat_map(move |i| {
+                        array[i].shrink().map(move |lane| {
+                            let mut array = array;
+                            array[i] = lane;
+                            $name::from_array(array)
+                        })
+                    }))
+                }
+            }
+        };
+    }
+
+    impl_arbitrary!(Float32x4, f32, 4);
+    impl_arbitrary!(Float64x2, f64, 2);
+
+    impl_arbitrary!(Int8x16, i8, 16);
+    impl_arbitrary!(Uint8x16, u8, 16);
+
+    impl_arbitrary!(Int16x8, i16, 8);
+    impl_arbitrary!(Uint16x8, u16, 8);
+
+    impl_arbitrary!(Int32x4, i32, 4);
+    impl_arbitrary!(Uint32x4, u32, 4);
+
+    impl_arbitrary!(Int64x2, i64, 2);
+    impl_arbitrary!(Uint64x2, u64, 2);
+
+    impl_arbitrary!(Float32x8, f32, 8);
+    impl_arbitrary!(Float64x4, f64, 4);
+
+    impl_arbitrary!(Int8x32, i8, 32);
+    impl_arbitrary!(Uint8x32, u8, 32);
+
+    impl_arbitrary!(Int16x16, i16, 16);
+    impl_arbitrary!(Uint16x16, u16, 16);
+
+    impl_arbitrary!(Int32x8, i32, 8);
+    impl_arbitrary!(Uint32x8, u32, 8);
+
+    impl_arbitrary!(Int64x4, i64, 4);
+    impl_arbitrary!(Uint64x4, u64, 4);
+
+    impl_arbitrary!(Float32x16, f32, 16);
+    impl_arbitrary!(Float64x8, f64, 8);
+
+    impl_arbitrary!(Int8x64, i8, 64);
+    impl_arbitrary!(Uint8x64, u8, 64);
+
+    impl_arbitrary!(Int16x32, i16, 32);
+    impl_arbitrary!(Uint16x32, u16, 32);
+
+    impl_arbitrary!(Int32x16, i32, 16);
+    impl_arbitrary!(Uint32x16, u32, 16);
+
+    impl_arbitrary!(Int64x8, i64, 8);
+    impl_arbitrary!(Uint64x8, u64, 8);
+
+    #[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+    mod avx512 {
+        use super::*;
+        use crate::avx512::{
+            Float32x16, Float64x8, Int16x32, Int32x16, Int64x8, Int8x64, Uint16x32, Uint32x16,
+            Uint64x8, Uint8x64,
+        };
+
+        impl_arbitrary!(Float32x16, f32, 16);
+        impl_arbitrary!(Float64x8, f64, 8);
+
+        impl_arbitrary!(Int8x64, i8, 64);
+        impl_arbitrary!(Uint8x64, u8, 64);
+
+        impl_arbitrary!(Int16x32, i16, 32);
+        impl_arbitrary!(Uint16x32, u16, 32);
+
+        impl_arbitrary!(Int32x16, i32, 16);
+        impl_arbitrary!(Uint32x16, u32, 16);
+
+        impl_arbitrary!(Int64x8, i64, 8);
+        impl_arbitrary!(Uint64x8, u64, 8);
+    }
+}
@@ -0,0 +1,12 @@
+//! Intrinsics used by the vector types. With AVX2 these are the native ones, otherwise the
+//! 256-bit intrinsics are emulated using pairs of SSE registers. Other architectures use the scalar
+//! implementations, there is no NEON backend.
+
+#[cfg(target_feature = "avx2")]
+pub(crate) use core::arch::x86_64::*;
+
+#[cfg(not(target_feature = "avx2"))]
+mod sse;
+
+#[cfg(not(target_feature = "avx2"))]
+pub(crate) use sse::*;
@@ -0,0 +1,711 @@
+//! 256-bit AVX/AVX2 intrinsics implemented with pairs of SSE4.1 registers. Every function has
+//! the same signature and result as the native intrinsic, the low 128-bit lane is stored first.
+//! Cross-lane operations and ones without a SSE equivalent go through arrays, which the compiler
+//! turns into shuffles when their immediate operands are known.
+
+#![allow(non_camel_case_types)]
+
+use core::mem::transmute;
+
+pub(crate) use core::arch::x86_64::*;
+
+#[derive(Copy, Clone)]
+pub struct __m256([__m128; 2]);
+
+#[derive(Copy, Clone)]
+pub struct __m256d([__m128d; 2]);
+
+#[derive(Copy, Clone)]
+pub struct __m256i([__m128i; 2]);
+
+macro_rules! lanes {
+    ($type: ident, $low: expr, $high: expr) => {
+        $type([$low, $high])
+    };
+}
+
+macro_rules! unary {
+    ($type: ident, $($name: ident => $sse: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name(a: $type) -> $type {
+            lanes!($type, $sse(a.0[0]), $sse(a.0[1]))
+        }
+    )*};
+}
+
+macro_rules! binary {
+    ($type: ident, $($name: ident => $sse: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name(a: $type, b: $type) -> $type {
+            lanes!($type, $sse(a.0[0], b.0[0]), $sse(a.0[1], b.0[1]))
+        }
+    )*};
+}
+
+#[cfg(target_feature = "fma")]
+macro_rules! ternary {
+    ($type: ident, $($name: ident => $sse: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name(a: $type, b: $type, c: $type) -> $type {
+            lanes!($type, $sse(a.0[0], b.0[0], c.0[0]), $sse(a.0[1], b.0[1], c.0[1]))
+        }
+    )*};
+}
+
+/// Intrinsics with an immediate operand which applies to both 128-bit lanes in the same way.
+macro_rules! unary_immediate {
+    ($type: ident, $($name: ident => $sse: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name<const IMM8: i32>(a: $type) -> $type {
+            lanes!($type, $sse::<IMM8>(a.0[0]), $sse::<IMM8>(a.0[1]))
+        }
+    )*};
+}
+
+macro_rules! binary_immediate {
+    ($type: ident, $($name: ident => $sse: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name<const IMM8: i32>(a: $type, b: $type) -> $type {
+            lanes!($type, $sse::<IMM8>(a.0[0], b.0[0]), $sse::<IMM8>(a.0[1], b.0[1]))
+        }
+    )*};
+}
+
+/// Shifts by a count in the low 64 bits of an `__m128i`, which applies to both 128-bit lanes.
+macro_rules! shift {
+    ($type: ident, $($name: ident => $sse: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name(a: $type, count: __m128i) -> $type {
+            lanes!($type, $sse(a.0[0], count), $sse(a.0[1], count))
+        }
+    )*};
+}
+
+macro_rules! casts {
+    ($($name: ident: $from: ident => $to: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name(a: $from) -> $to {
+            transmute(a)
+        }
+    )*};
+}
+
+casts!(
+    _mm256_castsi256_ps: __m256i => __m256,
+    _mm256_castsi256_pd: __m256i => __m256d,
+    _mm256_castps_si256: __m256 => __m256i,
+    _mm256_castpd_si256: __m256d => __m256i,
+    _mm256_castps_pd: __m256 => __m256d,
+    _mm256_castpd_ps: __m256d => __m256,
+);
+
+macro_rules! impl_float_intrinsics {
+    (
+        $type: ident, $sse_type: ident, $element: ty, $lanes: expr, $postfix: ident,
+        $mask_bits: ty, $blendv: ident
+    ) => {
+        paste::paste! {
+            binary!(
+                $type,
+                [<_mm256_add_ $postfix>] => [<_mm_add_ $postfix>],
+                [<_mm256_sub_ $postfix>] => [<_mm_sub_ $postfix>],
+                [<_mm256_mul_ $postfix>] => [<_mm_mul_ $postfix>],
+                [<_mm256_div_ $postfix>] => [<_mm_div_ $postfix>],
+                [<_mm256_and_ $postfix>] => [<_mm_and_ $postfix>],
+                [<_mm256_andnot_ $postfix>] => [<_mm_andnot_ $postfix>],
+                [<_mm256_or_ $postfix>] => [<_mm_or_ $postfix>],
+                [<_mm256_xor_ $postfix>] => [<_mm_xor_ $postfix>],
+                [<_mm256_min_ $postfix>] => [<_mm_min_ $postfix>],
+                [<_mm256_max_ $postfix>] => [<_mm_max_ $postfix>],
+            );
+
+            unary!(
+                $type,
+                [<_mm256_sqrt_ $postfix>] => [<_mm_sqrt_ $postfix>],
+                [<_mm256_floor_ $postfix>] => [<_mm_floor_ $postfix>],
+                [<_mm256_ceil_ $postfix>] => [<_mm_ceil_ $postfix>],
+            );
+
+            unary_immediate!($type, [<_mm256_round_ $postfix>] => [<_mm_round_ $postfix>]);
+
+            #[cfg(target_feature = "fma")]
+            ternary!(
+                $type,
+                [<_mm256_fmadd_ $postfix>] => [<_mm_fmadd_ $postfix>],
+                [<_mm256_fmsub_ $postfix>] => [<_mm_fmsub_ $postfix>],
+            );
+
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm256_setzero_ $postfix>]() -> $type {
+                lanes!($type, [<_mm_setzero_ $postfix>](), [<_mm_setzero_ $postfix>]())
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm256_set1_ $postfix>](a: $element) -> $type {
+                lanes!($type, [<_mm_set1_ $postfix>](a), [<_mm_set1_ $postfix>](a))
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm256_loadu_ $postfix>](mem_addr: *const $element) -> $type {
+                lanes!(
+                    $type,
+                    [<_mm_loadu_ $postfix>](mem_addr),
+                    [<_mm_loadu_ $postfix>](mem_addr.add($lanes / 2))
+                )
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm256_storeu_ $postfix>](mem_addr: *mut $element, a: $type) {
+                [<_mm_storeu_ $postfix>](mem_addr, a.0[0]);
+                [<_mm_storeu_ $postfix>](mem_addr.add($lanes / 2), a.0[1]);
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm256_movemask_ $postfix>](a: $type) -> i32 {
+                let low = [<_mm_movemask_ $postfix>](a.0[0]);
+                let high = [<_mm_movemask_ $postfix>](a.0[1]);
+
+                low | (high << ($lanes / 2))
+            }
+
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm256_blend_ $postfix>]<const IMM8: i32>(
+                a: $type,
+                b: $type,
+            ) -> $type {
+                let mask: [$mask_bits; $lanes] =
+                    core::array::from_fn(|i| if (IMM8 >> i) & 1 != 0 { !0 } else { 0 });
+                let mask: $type = transmute(mask);
+
+                lanes!(
+                    $type,
+                    $blendv(a.0[0], b.0[0], mask.0[0]),
+                    $blendv(a.0[1], b.0[1], mask.0[1])
+                )
+            }
+
+            /// AVX comparison of 128-bit vectors. Supports the ordered comparison predicates.
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm_cmp_ $postfix>]<const IMM5: i32>(
+                a: $sse_type,
+                b: $sse_type,
+            ) -> $sse_type {
+                match IMM5 {
+                    _CMP_EQ_OQ => [<_mm_cmpeq_ $postfix>](a, b),
+                    _CMP_NEQ_OQ => [<_mm_and_ $postfix>](
+                        [<_mm_cmpneq_ $postfix>](a, b),
+                        [<_mm_cmpord_ $postfix>](a, b),
+                    ),
+                    _CMP_GT_OQ => [<_mm_cmpgt_ $postfix>](a, b),
+                    _CMP_LT_OQ => [<_mm_cmplt_ $postfix>](a, b),
+                    _CMP_GE_OQ => [<_mm_cmpge_ $postfix>](a, b),
+                    _CMP_LE_OQ => [<_mm_cmple_ $postfix>](a, b),
+                    _ => unimplemented!("Unsupported comparison predicate."),
+                }
+            }
+
+            /// Supports the ordered comparison predicates.
+            #[inline(always)]
+            pub(crate) unsafe fn [<_mm256_cmp_ $postfix>]<const IMM5: i32>(
+                a: $type,
+                b: $type,
+            ) -> $type {
+                lanes!(
+                    $type,
+                    [<_mm_cmp_ $postfix>]::<IMM5>(a.0[0], b.0[0]),
+                    [<_mm_cmp_ $postfix>]::<IMM5>(a.0[1], b.0[1])
+                )
+            }
+        }
+    };
+}
+
+impl_float_intrinsics!(__m256, __m128, f32, 8, ps, u32, _mm_blendv_ps);
+impl_float_intrinsics!(__m256d, __m128d, f64, 4, pd, u64, _mm_blendv_pd);
+
+unary!(
+    __m256,
+    _mm256_rsqrt_ps => _mm_rsqrt_ps,
+    _mm256_moveldup_ps => _mm_moveldup_ps,
+    _mm256_movehdup_ps => _mm_movehdup_ps,
+);
+
+unary!(__m256d, _mm256_movedup_pd => _mm_movedup_pd);
+
+binary!(__m256, _mm256_unpacklo_ps => _mm_unpacklo_ps, _mm256_unpackhi_ps => _mm_unpackhi_ps);
+
+binary!(__m256d, _mm256_unpacklo_pd => _mm_unpacklo_pd, _mm256_unpackhi_pd => _mm_unpackhi_pd);
+
+binary_immediate!(__m256, _mm256_shuffle_ps => _mm_shuffle_ps);
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_permute_ps<const IMM8: i32>(a: __m256) -> __m256 {
+    lanes!(
+        __m256,
+        _mm_shuffle_ps::<IMM8>(a.0[0], a.0[0]),
+        _mm_shuffle_ps::<IMM8>(a.0[1], a.0[1])
+    )
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_shuffle_pd<const IMM8: i32>(a: __m256d, b: __m256d) -> __m256d {
+    let a = transmute::<__m256d, [f64; 4]>(a);
+    let b = transmute::<__m256d, [f64; 4]>(b);
+    let select = |lane: usize| (IMM8 >> lane) & 1 != 0;
+
+    transmute([
+        a[select(0) as usize],
+        b[select(1) as usize],
+        a[2 + select(2) as usize],
+        b[2 + select(3) as usize],
+    ])
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_permute4x64_pd<const IMM8: i32>(a: __m256d) -> __m256d {
+    let a: [f64; 4] = transmute(a);
+
+    transmute::<[f64; 4], _>(core::array::from_fn(|i| a[(IMM8 as usize >> (i * 2)) & 3]))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_permute4x64_epi64<const IMM8: i32>(a: __m256i) -> __m256i {
+    let a: [i64; 4] = transmute(a);
+
+    transmute::<[i64; 4], _>(core::array::from_fn(|i| a[(IMM8 as usize >> (i * 2)) & 3]))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_permutevar8x32_ps(a: __m256, idx: __m256i) -> __m256 {
+    let a = transmute::<__m256, [f32; 8]>(a);
+    let idx = transmute::<__m256i, [u32; 8]>(idx);
+
+    transmute::<[f32; 8], _>(core::array::from_fn(|i| a[idx[i] as usize & 7]))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_permutevar8x32_epi32(a: __m256i, idx: __m256i) -> __m256i {
+    let a = transmute::<__m256i, [u32; 8]>(a);
+    let idx = transmute::<__m256i, [u32; 8]>(idx);
+
+    transmute::<[u32; 8], _>(core::array::from_fn(|i| a[idx[i] as usize & 7]))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_i32gather_ps<const SCALE: i32>(
+    slice: *const f32,
+    offsets: __m256i,
+) -> __m256 {
+    let offsets: [i32; 8] = transmute(offsets);
+
+    transmute::<[f32; 8], _>(core::array::from_fn(|i| {
+        slice
+            .byte_offset(offsets[i] as isize * SCALE as isize)
+            .read_unaligned()
+    }))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_broadcast_ss(f: &f32) -> __m256 {
+    _mm256_set1_ps(*f)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_broadcast_sd(f: &f64) -> __m256d {
+    _mm256_set1_pd(*f)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_broadcast_ps(a: &__m128) -> __m256 {
+    lanes!(__m256, *a, *a)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_broadcast_pd(a: &__m128d) -> __m256d {
+    lanes!(__m256d, *a, *a)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_cvtps_epi32(a: __m256) -> __m256i {
+    lanes!(__m256i, _mm_cvtps_epi32(a.0[0]), _mm_cvtps_epi32(a.0[1]))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_cvtepi32_ps(a: __m256i) -> __m256 {
+    lanes!(__m256, _mm_cvtepi32_ps(a.0[0]), _mm_cvtepi32_ps(a.0[1]))
+}
+
+/// Conversions between 256-bit vectors and their 128-bit halves.
+macro_rules! halves {
+    (
+        $type: ident, $half_type: ident, $setzero: ident,
+        $cast: ident, $cast_back: ident, $extract: ident, $insert: ident, $permute: ident
+    ) => {
+        #[inline(always)]
+        pub(crate) unsafe fn $cast(a: $type) -> $half_type {
+            a.0[0]
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn $cast_back(a: $half_type) -> $type {
+            lanes!($type, a, $setzero())
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn $extract<const IMM1: i32>(a: $type) -> $half_type {
+            a.0[IMM1 as usize & 1]
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn $insert<const IMM1: i32>(a: $type, b: $half_type) -> $type {
+            let mut a = a;
+            a.0[IMM1 as usize & 1] = b;
+            a
+        }
+
+        #[inline(always)]
+        pub(crate) unsafe fn $permute<const IMM8: i32>(a: $type, b: $type) -> $type {
+            let select = |control: i32| match control & 0b1000 {
+                0 => [a.0[0], a.0[1], b.0[0], b.0[1]][control as usize & 3],
+                _ => $setzero(),
+            };
+
+            lanes!($type, select(IMM8), select(IMM8 >> 4))
+        }
+    };
+}
+
+halves!(
+    __m256,
+    __m128,
+    _mm_setzero_ps,
+    _mm256_castps256_ps128,
+    _mm256_castps128_ps256,
+    _mm256_extractf128_ps,
+    _mm256_insertf128_ps,
+    _mm256_permute2f128_ps
+);
+
+halves!(
+    __m256d,
+    __m128d,
+    _mm_setzero_pd,
+    _mm256_castpd256_pd128,
+    _mm256_castpd128_pd256,
+    _mm256_extractf128_pd,
+    _mm256_insertf128_pd,
+    _mm256_permute2f128_pd
+);
+
+halves!(
+    __m256i,
+    __m128i,
+    _mm_setzero_si128,
+    _mm256_castsi256_si128,
+    _mm256_castsi128_si256,
+    _mm256_extracti128_si256,
+    _mm256_inserti128_si256,
+    _mm256_permute2x128_si256
+);
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_cvtps_pd(a: __m128) -> __m256d {
+    lanes!(__m256d, _mm_cvtps_pd(a), _mm_cvtps_pd(_mm_movehl_ps(a, a)))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_cvtpd_ps(a: __m256d) -> __m128 {
+    _mm_movelh_ps(_mm_cvtpd_ps(a.0[0]), _mm_cvtpd_ps(a.0[1]))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_cvtepi32_pd(a: __m128i) -> __m256d {
+    lanes!(__m256d, _mm_cvtepi32_pd(a), _mm_cvtepi32_pd(_mm_unpackhi_epi64(a, a)))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_cvtpd_epi32(a: __m256d) -> __m128i {
+    _mm_unpacklo_epi64(_mm_cvtpd_epi32(a.0[0]), _mm_cvtpd_epi32(a.0[1]))
+}
+
+/// Sign or zero extensions of a 128-bit vector, the high half is extended into the high lane.
+macro_rules! extend {
+    ($($name: ident => $sse: ident),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name(a: __m128i) -> __m256i {
+            lanes!(__m256i, $sse(a), $sse(_mm_unpackhi_epi64(a, a)))
+        }
+    )*};
+}
+
+extend!(
+    _mm256_cvtepi8_epi16 => _mm_cvtepi8_epi16,
+    _mm256_cvtepu8_epi16 => _mm_cvtepu8_epi16,
+    _mm256_cvtepi16_epi32 => _mm_cvtepi16_epi32,
+    _mm256_cvtepu16_epi32 => _mm_cvtepu16_epi32,
+    _mm256_cvtepi32_epi64 => _mm_cvtepi32_epi64,
+    _mm256_cvtepu32_epi64 => _mm_cvtepu32_epi64,
+);
+
+binary!(
+    __m256i,
+    _mm256_and_si256 => _mm_and_si128,
+    _mm256_andnot_si256 => _mm_andnot_si128,
+    _mm256_or_si256 => _mm_or_si128,
+    _mm256_xor_si256 => _mm_xor_si128,
+    _mm256_add_epi8 => _mm_add_epi8,
+    _mm256_add_epi16 => _mm_add_epi16,
+    _mm256_add_epi32 => _mm_add_epi32,
+    _mm256_add_epi64 => _mm_add_epi64,
+    _mm256_sub_epi8 => _mm_sub_epi8,
+    _mm256_sub_epi16 => _mm_sub_epi16,
+    _mm256_sub_epi32 => _mm_sub_epi32,
+    _mm256_sub_epi64 => _mm_sub_epi64,
+    _mm256_adds_epi8 => _mm_adds_epi8,
+    _mm256_adds_epu8 => _mm_adds_epu8,
+    _mm256_adds_epi16 => _mm_adds_epi16,
+    _mm256_adds_epu16 => _mm_adds_epu16,
+    _mm256_subs_epi8 => _mm_subs_epi8,
+    _mm256_subs_epu8 => _mm_subs_epu8,
+    _mm256_subs_epi16 => _mm_subs_epi16,
+    _mm256_subs_epu16 => _mm_subs_epu16,
+    _mm256_avg_epu8 => _mm_avg_epu8,
+    _mm256_avg_epu16 => _mm_avg_epu16,
+    _mm256_sad_epu8 => _mm_sad_epu8,
+    _mm256_cmpeq_epi8 => _mm_cmpeq_epi8,
+    _mm256_cmpeq_epi16 => _mm_cmpeq_epi16,
+    _mm256_cmpeq_epi32 => _mm_cmpeq_epi32,
+    _mm256_cmpeq_epi64 => _mm_cmpeq_epi64,
+    _mm256_cmpgt_epi8 => _mm_cmpgt_epi8,
+    _mm256_cmpgt_epi16 => _mm_cmpgt_epi16,
+    _mm256_cmpgt_epi32 => _mm_cmpgt_epi32,
+    _mm256_min_epi8 => _mm_min_epi8,
+    _mm256_min_epu8 => _mm_min_epu8,
+    _mm256_min_epi16 => _mm_min_epi16,
+    _mm256_min_epu16 => _mm_min_epu16,
+    _mm256_min_epi32 => _mm_min_epi32,
+    _mm256_min_epu32 => _mm_min_epu32,
+    _mm256_max_epi8 => _mm_max_epi8,
+    _mm256_max_epu8 => _mm_max_epu8,
+    _mm256_max_epi16 => _mm_max_epi16,
+    _mm256_max_epu16 => _mm_max_epu16,
+    _mm256_max_epi32 => _mm_max_epi32,
+    _mm256_max_epu32 => _mm_max_epu32,
+    _mm256_mullo_epi16 => _mm_mullo_epi16,
+    _mm256_mullo_epi32 => _mm_mullo_epi32,
+    _mm256_mul_epi32 => _mm_mul_epi32,
+    _mm256_mul_epu32 => _mm_mul_epu32,
+    _mm256_mulhi_epi16 => _mm_mulhi_epi16,
+    _mm256_mulhi_epu16 => _mm_mulhi_epu16,
+    _mm256_mulhrs_epi16 => _mm_mulhrs_epi16,
+    _mm256_madd_epi16 => _mm_madd_epi16,
+    _mm256_hadd_epi16 => _mm_hadd_epi16,
+    _mm256_hadd_epi32 => _mm_hadd_epi32,
+    _mm256_hadds_epi16 => _mm_hadds_epi16,
+    _mm256_hsub_epi16 => _mm_hsub_epi16,
+    _mm256_hsub_epi32 => _mm_hsub_epi32,
+    _mm256_hsubs_epi16 => _mm_hsubs_epi16,
+    _mm256_packs_epi16 => _mm_packs_epi16,
+    _mm256_unpacklo_epi8 => _mm_unpacklo_epi8,
+    _mm256_unpacklo_epi16 => _mm_unpacklo_epi16,
+    _mm256_unpacklo_epi32 => _mm_unpacklo_epi32,
+    _mm256_unpacklo_epi64 => _mm_unpacklo_epi64,
+    _mm256_unpackhi_epi8 => _mm_unpackhi_epi8,
+    _mm256_unpackhi_epi16 => _mm_unpackhi_epi16,
+    _mm256_unpackhi_epi32 => _mm_unpackhi_epi32,
+    _mm256_unpackhi_epi64 => _mm_unpackhi_epi64,
+    _mm256_packs_epi32 => _mm_packs_epi32,
+    _mm256_packus_epi16 => _mm_packus_epi16,
+    _mm256_packus_epi32 => _mm_packus_epi32,
+    _mm256_maddubs_epi16 => _mm_maddubs_epi16,
+    _mm256_shuffle_epi8 => _mm_shuffle_epi8,
+);
+
+unary!(
+    __m256i,
+    _mm256_abs_epi8 => _mm_abs_epi8,
+    _mm256_abs_epi16 => _mm_abs_epi16,
+    _mm256_abs_epi32 => _mm_abs_epi32,
+);
+
+unary_immediate!(
+    __m256i,
+    _mm256_slli_epi16 => _mm_slli_epi16,
+    _mm256_slli_epi32 => _mm_slli_epi32,
+    _mm256_slli_epi64 => _mm_slli_epi64,
+    _mm256_srli_epi16 => _mm_srli_epi16,
+    _mm256_srli_epi32 => _mm_srli_epi32,
+    _mm256_srli_epi64 => _mm_srli_epi64,
+    _mm256_srai_epi16 => _mm_srai_epi16,
+    _mm256_srai_epi32 => _mm_srai_epi32,
+    _mm256_shuffle_epi32 => _mm_shuffle_epi32,
+    _mm256_shufflelo_epi16 => _mm_shufflelo_epi16,
+    _mm256_shufflehi_epi16 => _mm_shufflehi_epi16,
+);
+
+shift!(
+    __m256i,
+    _mm256_sll_epi16 => _mm_sll_epi16,
+    _mm256_sll_epi32 => _mm_sll_epi32,
+    _mm256_sll_epi64 => _mm_sll_epi64,
+    _mm256_srl_epi16 => _mm_srl_epi16,
+    _mm256_srl_epi32 => _mm_srl_epi32,
+    _mm256_srl_epi64 => _mm_srl_epi64,
+    _mm256_sra_epi16 => _mm_sra_epi16,
+    _mm256_sra_epi32 => _mm_sra_epi32,
+);
+
+binary_immediate!(
+    __m256i,
+    _mm256_blend_epi16 => _mm_blend_epi16,
+    _mm256_alignr_epi8 => _mm_alignr_epi8,
+);
+
+binary!(__m256i, _mm256_cmpgt_epi64 => _mm_cmpgt_epi64);
+
+#[cfg(not(target_feature = "sse4.2"))]
+#[inline(always)]
+pub(crate) unsafe fn _mm_cmpgt_epi64(a: __m128i, b: __m128i) -> __m128i {
+    let a = transmute::<__m128i, [i64; 2]>(a);
+    let b = transmute::<__m128i, [i64; 2]>(b);
+
+    transmute::<[i64; 2], _>(core::array::from_fn(|i| -((a[i] > b[i]) as i64)))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm_blend_epi32<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    _mm_castps_si128(_mm_blend_ps::<IMM8>(_mm_castsi128_ps(a), _mm_castsi128_ps(b)))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_blendv_epi8(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+    lanes!(
+        __m256i,
+        _mm_blendv_epi8(a.0[0], b.0[0], mask.0[0]),
+        _mm_blendv_epi8(a.0[1], b.0[1], mask.0[1])
+    )
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_srav_epi32(a: __m256i, count: __m256i) -> __m256i {
+    let a = transmute::<__m256i, [i32; 8]>(a);
+    let count = transmute::<__m256i, [u32; 8]>(count);
+
+    transmute::<[i32; 8], _>(core::array::from_fn(|i| a[i] >> count[i].min(31)))
+}
+
+/// `_mm_mpsadbw_epu8` with a runtime control, the high lane of the AVX2 version uses other bits.
+#[inline(always)]
+unsafe fn mpsadbw(a: __m128i, b: __m128i, control: i32) -> __m128i {
+    match control & 7 {
+        0 => _mm_mpsadbw_epu8::<0>(a, b),
+        1 => _mm_mpsadbw_epu8::<1>(a, b),
+        2 => _mm_mpsadbw_epu8::<2>(a, b),
+        3 => _mm_mpsadbw_epu8::<3>(a, b),
+        4 => _mm_mpsadbw_epu8::<4>(a, b),
+        5 => _mm_mpsadbw_epu8::<5>(a, b),
+        6 => _mm_mpsadbw_epu8::<6>(a, b),
+        _ => _mm_mpsadbw_epu8::<7>(a, b),
+    }
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_mpsadbw_epu8<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i {
+    lanes!(__m256i, mpsadbw(a.0[0], b.0[0], IMM8), mpsadbw(a.0[1], b.0[1], IMM8 >> 3))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_blend_epi32<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i {
+    _mm256_castps_si256(_mm256_blend_ps::<IMM8>(
+        _mm256_castsi256_ps(a),
+        _mm256_castsi256_ps(b),
+    ))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_setzero_si256() -> __m256i {
+    lanes!(__m256i, _mm_setzero_si128(), _mm_setzero_si128())
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_set1_epi8(a: i8) -> __m256i {
+    lanes!(__m256i, _mm_set1_epi8(a), _mm_set1_epi8(a))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_set1_epi16(a: i16) -> __m256i {
+    lanes!(__m256i, _mm_set1_epi16(a), _mm_set1_epi16(a))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_set1_epi32(a: i32) -> __m256i {
+    lanes!(__m256i, _mm_set1_epi32(a), _mm_set1_epi32(a))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_set1_epi64x(a: i64) -> __m256i {
+    lanes!(__m256i, _mm_set1_epi64x(a), _mm_set1_epi64x(a))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_loadu_si256(mem_addr: *const __m256i) -> __m256i {
+    let mem_addr = mem_addr as *const __m128i;
+
+    lanes!(
+        __m256i,
+        _mm_loadu_si128(mem_addr),
+        _mm_loadu_si128(mem_addr.add(1))
+    )
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_storeu_si256(mem_addr: *mut __m256i, a: __m256i) {
+    let mem_addr = mem_addr as *mut __m128i;
+
+    _mm_storeu_si128(mem_addr, a.0[0]);
+    _mm_storeu_si128(mem_addr.add(1), a.0[1]);
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_broadcastsi128_si256(a: __m128i) -> __m256i {
+    lanes!(__m256i, a, a)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_movemask_epi8(a: __m256i) -> i32 {
+    _mm_movemask_epi8(a.0[0]) | (_mm_movemask_epi8(a.0[1]) << 16)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_testz_si256(a: __m256i, b: __m256i) -> i32 {
+    _mm_testz_si128(a.0[0], b.0[0]) & _mm_testz_si128(a.0[1], b.0[1])
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_testc_si256(a: __m256i, b: __m256i) -> i32 {
+    _mm_testc_si128(a.0[0], b.0[0]) & _mm_testc_si128(a.0[1], b.0[1])
+}
+
+#[inline(always)]
+pub(crate) unsafe fn _mm256_testnzc_si256(a: __m256i, b: __m256i) -> i32 {
+    (_mm256_testz_si256(a, b) == 0 && _mm256_testc_si256(a, b) == 0) as i32
+}
+
+macro_rules! insert {
+    ($($name: ident: $element: ty, $lanes: expr),* $(,)?) => {$(
+        #[inline(always)]
+        pub(crate) unsafe fn $name<const INDEX: i32>(a: __m256i, i: $element) -> __m256i {
+            let mut elements: [$element; $lanes] = transmute(a);
+            elements[INDEX as usize % $lanes] = i;
+
+            transmute(elements)
+        }
+    )*};
+}
+
+insert!(
+    _mm256_insert_epi8: i8, 32,
+    _mm256_insert_epi16: i16, 16,
+    _mm256_insert_epi32: i32, 8,
+    _mm256_insert_epi64: i64, 4,
+);
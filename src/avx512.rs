@@ -0,0 +1,8 @@
+//! Native 512-bit vector types. Comparisons return bitmasks, as AVX-512 stores them in mask
+//! registers. The types of the same names in the crate root are built from two 256-bit vectors.
+
+mod float_512;
+mod integer_512;
+
+pub use float_512::*;
+pub use integer_512::*;
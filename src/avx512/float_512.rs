@@ -0,0 +1,393 @@
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+
+macro_rules! impl_operator {
+    ($name: ident, $op: ident, $op_function: ident, $function: item) => {
+        impl ops::$op for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            $function
+        }
+
+        paste! {
+            impl ops::[<$op Assign>] for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: Self) {
+                    *self = <Self as ops::$op>::$op_function(*self, rhs);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! make_vector_type {
+    (
+        $name: ident, $type: ty, $lanes: expr, $avx_type: ty, $mask: ty, $postfix: ident,
+        $integer_postfix: ident, $from_512i: ident, $to_512i: ident
+    ) => {
+        #[derive(Copy, Clone)]
+        #[repr(transparent)]
+        pub struct $name(pub(crate) $avx_type);
+
+        macro_rules! intrinsic {
+            ($function: ident) => {
+                paste! { [< $function _ $postfix>] }
+            };
+        }
+
+        macro_rules! comparison {
+            ($comparison_name: ident, $comparison_constant: ident) => {
+                /// Returns a bitmask of lanes for which the comparison holds.
+                #[inline(always)]
+                #[must_use]
+                pub fn $comparison_name(self, rhs: Self) -> $mask {
+                    unsafe {
+                        paste! {
+                            [<_mm512_cmp_ $postfix _mask>]::<$comparison_constant>(self.0, rhs.0)
+                        }
+                    }
+                }
+            };
+        }
+
+        macro_rules! rounding {
+            ($rounding_name: ident, $rounding_mode: expr) => {
+                #[inline(always)]
+                #[must_use]
+                pub fn $rounding_name(self) -> Self {
+                    unsafe {
+                        paste! {
+                            Self([<_mm512_roundscale_ $postfix>]::<$rounding_mode>(self.0))
+                        }
+                    }
+                }
+            };
+        }
+
+        impl VectorBits for $name {
+            type Bits = __m512i;
+
+            #[inline(always)]
+            fn from_bits(x: __m512i) -> Self {
+                unsafe { Self($from_512i(x)) }
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> __m512i {
+                unsafe { $to_512i(self.0) }
+            }
+        }
+
+        impl From<$avx_type> for $name {
+            #[inline(always)]
+            fn from(x: $avx_type) -> Self {
+                Self(x)
+            }
+        }
+
+        impl From<$name> for $avx_type {
+            #[inline(always)]
+            fn from(x: $name) -> Self {
+                x.0
+            }
+        }
+
+        impl $name {
+            fn _size_check() {
+                unsafe {
+                    core::mem::transmute::<[$type; $lanes], [u8; 512 / 8]>([0.0; $lanes]);
+                }
+            }
+
+            comparison!(eq, _CMP_EQ_OQ);
+            comparison!(ne, _CMP_NEQ_OQ);
+
+            comparison!(gt, _CMP_GT_OQ);
+            comparison!(lt, _CMP_LT_OQ);
+
+            comparison!(ge, _CMP_GE_OQ);
+            comparison!(le, _CMP_LE_OQ);
+
+            #[inline(always)]
+            #[must_use]
+            pub fn zero() -> Self {
+                unsafe { Self(intrinsic!(_mm512_setzero)()) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn splat(v: $type) -> Self {
+                unsafe { Self(intrinsic!(_mm512_set1)(v)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_array(array: [$type; $lanes]) -> Self {
+                unsafe { Self(intrinsic!(_mm512_loadu)(array.as_ptr() as *const _)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_array(self) -> [$type; $lanes] {
+                unsafe {
+                    let mut array: MaybeUninit<[$type; $lanes]> = MaybeUninit::uninit();
+                    intrinsic!(_mm512_storeu)(array.as_mut_ptr() as *mut _, self.0);
+                    array.assume_init()
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; 512 / 8]) -> Self {
+                unsafe { Self(intrinsic!(_mm512_loadu)(bytes.as_ptr() as *const _)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_bytes(self) -> [u8; 512 / 8] {
+                unsafe {
+                    let mut bytes: MaybeUninit<[u8; 512 / 8]> = MaybeUninit::uninit();
+                    intrinsic!(_mm512_storeu)(bytes.as_mut_ptr() as *mut _, self.0);
+                    bytes.assume_init()
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn as_raw(self) -> $avx_type {
+                self.0
+            }
+
+            /// Set each bit of mask based on the sign bit of the corresponding lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn mask(self) -> $mask {
+                unsafe {
+                    paste! {
+                        [<_mm512_cmplt_ $integer_postfix _mask>](
+                            self.to_bits(),
+                            _mm512_setzero_si512(),
+                        )
+                    }
+                }
+            }
+
+            /// Returns true if all bits are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                unsafe { _mm512_test_epi64_mask(self.to_bits(), self.to_bits()) == 0 }
+            }
+
+            /// Same as `is_zero`, named to pair with `is_all_ones`.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            /// Returns true if all bits are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_ones(self) -> bool {
+                (!self).is_zero()
+            }
+
+            /// Returns true if any bits of `self` selected by `mask` are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn test(self, mask: Self) -> bool {
+                unsafe { _mm512_test_epi64_mask(self.to_bits(), mask.to_bits()) != 0 }
+            }
+
+            /// Takes lanes of `rhs` where the corresponding bit of `mask` is set and lanes of
+            /// `self` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn blend_mask(self, rhs: Self, mask: $mask) -> Self {
+                unsafe { Self(intrinsic!(_mm512_mask_blend)(mask, self.0, rhs.0)) }
+            }
+
+            /// ~self & rhs
+            #[inline(always)]
+            #[must_use]
+            pub fn andnot(self, rhs: Self) -> Self {
+                unsafe { Self::from_bits(_mm512_andnot_si512(self.to_bits(), rhs.to_bits())) }
+            }
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                unsafe {
+                    Self::from_bits(_mm512_ternarylogic_epi64::<0xca>(
+                        mask.to_bits(),
+                        if_set.to_bits(),
+                        if_clear.to_bits(),
+                    ))
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                unsafe { Self(intrinsic!(_mm512_min)(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                unsafe { Self(intrinsic!(_mm512_max)(self.0, rhs.0)) }
+            }
+
+            // _MM_FROUND_TO_* | _MM_FROUND_NO_EXC
+            rounding!(floor, 0x09);
+            rounding!(ceil, 0x0a);
+            rounding!(trunc, 0x0b);
+            rounding!(round, 0x08);
+
+            #[inline(always)]
+            #[must_use]
+            pub fn sqrt(self) -> Self {
+                unsafe { Self(intrinsic!(_mm512_sqrt)(self.0)) }
+            }
+
+            /// (self * b) + c
+            #[inline(always)]
+            #[must_use]
+            pub fn fmadd(self, b: Self, c: Self) -> Self {
+                unsafe { Self(intrinsic!(_mm512_fmadd)(self.0, b.0, c.0)) }
+            }
+
+            /// (self * b) - c
+            #[inline(always)]
+            #[must_use]
+            pub fn fmsub(self, b: Self, c: Self) -> Self {
+                unsafe { Self(intrinsic!(_mm512_fmsub)(self.0, b.0, c.0)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn convert<T>(self) -> T
+            where
+                Self: VectorConvertInto<T>,
+            {
+                <Self as VectorConvertInto<T>>::convert_vector(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn transmute<T>(self) -> T
+            where
+                Self: VectorTransmuteInto<T>,
+            {
+                <Self as VectorTransmuteInto<T>>::transmute_vector(self)
+            }
+        }
+
+        impl_operator! { $name, Add, add,
+            fn add(self, rhs: Self) -> Self::Output {
+                unsafe { Self(intrinsic!(_mm512_add)(self.0, rhs.0)) }
+            }
+        }
+
+        impl_operator! { $name, Sub, sub,
+            fn sub(self, rhs: Self) -> Self::Output {
+                unsafe { Self(intrinsic!(_mm512_sub)(self.0, rhs.0)) }
+            }
+        }
+
+        impl_operator! { $name, Mul, mul,
+            fn mul(self, rhs: Self) -> Self::Output {
+                unsafe { Self(intrinsic!(_mm512_mul)(self.0, rhs.0)) }
+            }
+        }
+
+        impl_operator! { $name, Div, div,
+            fn div(self, rhs: Self) -> Self::Output {
+                unsafe { Self(intrinsic!(_mm512_div)(self.0, rhs.0)) }
+            }
+        }
+
+        // Floating-point logical instructions require AVX-512DQ, integer ones have the same
+        // effect.
+        impl_operator! { $name, BitAnd, bitand,
+            fn bitand(self, rhs: Self) -> Self::Output {
+                unsafe { Self::from_bits(_mm512_and_si512(self.to_bits(), rhs.to_bits())) }
+            }
+        }
+
+        impl_operator! { $name, BitOr, bitor,
+            fn bitor(self, rhs: Self) -> Self::Output {
+                unsafe { Self::from_bits(_mm512_or_si512(self.to_bits(), rhs.to_bits())) }
+            }
+        }
+
+        impl_operator! { $name, BitXor, bitxor,
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                unsafe { Self::from_bits(_mm512_xor_si512(self.to_bits(), rhs.to_bits())) }
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                unsafe { Self::from_bits(_mm512_xor_si512(self.to_bits(), _mm512_set1_epi8(-1))) }
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                <[$type; $lanes] as fmt::Debug>::fmt(&self.to_array(), f)
+            }
+        }
+    };
+}
+
+make_vector_type!(
+    Float32x16,
+    f32,
+    16,
+    __m512,
+    u16,
+    ps,
+    epi32,
+    _mm512_castsi512_ps,
+    _mm512_castps_si512
+);
+make_vector_type!(
+    Float64x8,
+    f64,
+    8,
+    __m512d,
+    u8,
+    pd,
+    epi64,
+    _mm512_castsi512_pd,
+    _mm512_castpd_si512
+);
+
+impl Float32x16 {
+    /// Every 2 bits of PATTERN select the source element for the corresponding destination
+    /// element. The same selection is applied to every 128-bit lane.
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle<const PATTERN: i32>(self) -> Self {
+        unsafe { Self(_mm512_permute_ps::<PATTERN>(self.0)) }
+    }
+}
+
+impl VectorConvertInto<super::Int32x16> for Float32x16 {
+    #[inline(always)]
+    fn convert_vector(self) -> super::Int32x16 {
+        unsafe { super::Int32x16(_mm512_cvtps_epi32(self.0)) }
+    }
+}
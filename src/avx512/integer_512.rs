@@ -0,0 +1,718 @@
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+
+macro_rules! impl_operator {
+    ($name: ident, $op: ident, $op_function: ident, $function: item) => {
+        impl ops::$op for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            $function
+        }
+
+        paste! {
+            impl ops::[<$op Assign>] for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: Self) {
+                    *self = <Self as ops::$op>::$op_function(*self, rhs);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! make_vector_type {
+    ($name: ident, $type: ty, $lanes: expr, $mask: ty, $postfix: ident, $compare_postfix: ident) => {
+        #[derive(Copy, Clone)]
+        #[repr(transparent)]
+        pub struct $name(pub(crate) __m512i);
+
+        macro_rules! intrinsic {
+            ($function: ident) => {
+                paste! { [< $function _ $postfix>] }
+            };
+        }
+
+        macro_rules! comparison {
+            ($comparison_name: ident, $comparison_constant: ident) => {
+                /// Returns a bitmask of lanes for which the comparison holds.
+                #[inline(always)]
+                #[must_use]
+                pub fn $comparison_name(self, rhs: Self) -> $mask {
+                    unsafe {
+                        paste! {
+                            [<_mm512_cmp_ $compare_postfix _mask>]::<$comparison_constant>(
+                                self.0,
+                                rhs.0,
+                            )
+                        }
+                    }
+                }
+            };
+        }
+
+        impl VectorBits for $name {
+            type Bits = __m512i;
+
+            #[inline(always)]
+            fn from_bits(x: __m512i) -> Self {
+                Self(x)
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> __m512i {
+                self.0
+            }
+        }
+
+        impl From<__m512i> for $name {
+            #[inline(always)]
+            fn from(x: __m512i) -> Self {
+                Self(x)
+            }
+        }
+
+        impl From<$name> for __m512i {
+            #[inline(always)]
+            fn from(x: $name) -> Self {
+                x.0
+            }
+        }
+
+        impl $name {
+            #[allow(clippy::useless_transmute)]
+            fn _size_check() {
+                unsafe {
+                    core::mem::transmute::<[$type; $lanes], [u8; 512 / 8]>([0; $lanes]);
+                }
+            }
+
+            comparison!(eq, _MM_CMPINT_EQ);
+            comparison!(ne, _MM_CMPINT_NE);
+
+            comparison!(lt, _MM_CMPINT_LT);
+            comparison!(le, _MM_CMPINT_LE);
+
+            comparison!(gt, _MM_CMPINT_NLE);
+            comparison!(ge, _MM_CMPINT_NLT);
+
+            #[inline(always)]
+            #[must_use]
+            pub fn zero() -> Self {
+                unsafe { Self(_mm512_setzero_si512()) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn splat(v: $type) -> Self {
+                unsafe { Self(intrinsic!(_mm512_set1)(v as _)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_array(array: [$type; $lanes]) -> Self {
+                unsafe { Self(_mm512_loadu_si512(array.as_ptr() as *const _)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_array(self) -> [$type; $lanes] {
+                unsafe {
+                    let mut array: MaybeUninit<[$type; $lanes]> = MaybeUninit::uninit();
+                    _mm512_storeu_si512(array.as_mut_ptr() as *mut _, self.0);
+                    array.assume_init()
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; 512 / 8]) -> Self {
+                unsafe { Self(_mm512_loadu_si512(bytes.as_ptr() as *const _)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_bytes(self) -> [u8; 512 / 8] {
+                unsafe {
+                    let mut bytes: MaybeUninit<[u8; 512 / 8]> = MaybeUninit::uninit();
+                    _mm512_storeu_si512(bytes.as_mut_ptr() as *mut _, self.0);
+                    bytes.assume_init()
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn as_raw(self) -> __m512i {
+                self.0
+            }
+
+            /// Set each bit of mask based on the most significant bit of the corresponding lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn mask(self) -> $mask {
+                unsafe {
+                    paste! {
+                        [<_mm512_cmplt_ $postfix _mask>](self.0, _mm512_setzero_si512())
+                    }
+                }
+            }
+
+            /// Returns true if all bits are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                unsafe { _mm512_test_epi64_mask(self.0, self.0) == 0 }
+            }
+
+            /// Same as `is_zero`, named to pair with `is_all_ones`.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            /// Returns true if all bits are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_ones(self) -> bool {
+                (!self).is_zero()
+            }
+
+            /// Returns true if any bits of `self` selected by `mask` are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn test(self, mask: Self) -> bool {
+                unsafe { _mm512_test_epi64_mask(self.0, mask.0) != 0 }
+            }
+
+            /// Takes lanes of `rhs` where the corresponding bit of `mask` is set and lanes of
+            /// `self` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn blend_mask(self, rhs: Self, mask: $mask) -> Self {
+                unsafe { Self(intrinsic!(_mm512_mask_blend)(mask, self.0, rhs.0)) }
+            }
+
+            /// ~self & rhs
+            #[inline(always)]
+            #[must_use]
+            pub fn andnot(self, rhs: Self) -> Self {
+                unsafe { Self(_mm512_andnot_si512(self.0, rhs.0)) }
+            }
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                unsafe { Self(_mm512_ternarylogic_epi64::<0xca>(mask.0, if_set.0, if_clear.0)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_min_ $compare_postfix>](self.0, rhs.0)) }
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_max_ $compare_postfix>](self.0, rhs.0)) }
+                }
+            }
+
+            /// Same as `self.max(min).min(max)`, lanes where `min` is greater than `max` become
+            /// `max`.
+            #[inline(always)]
+            #[must_use]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
+            /// Interleaves lanes from the lower halves of every 128-bit lane of `self` and
+            /// `rhs`: [a0, b0, a1, b1, ...].
+            #[inline(always)]
+            #[must_use]
+            pub fn interleave_lo(self, rhs: Self) -> Self {
+                unsafe { Self(intrinsic!(_mm512_unpacklo)(self.0, rhs.0)) }
+            }
+
+            /// Same as `interleave_lo`, but with the upper halves of every 128-bit lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn interleave_hi(self, rhs: Self) -> Self {
+                unsafe { Self(intrinsic!(_mm512_unpackhi)(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn convert<T>(self) -> T
+            where
+                Self: VectorConvertInto<T>,
+            {
+                <Self as VectorConvertInto<T>>::convert_vector(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn transmute<T>(self) -> T
+            where
+                Self: VectorTransmuteInto<T>,
+            {
+                <Self as VectorTransmuteInto<T>>::transmute_vector(self)
+            }
+        }
+
+        impl_operator! { $name, Add, add,
+            fn add(self, rhs: Self) -> Self::Output {
+                unsafe { Self(intrinsic!(_mm512_add)(self.0, rhs.0)) }
+            }
+        }
+
+        impl_operator! { $name, Sub, sub,
+            fn sub(self, rhs: Self) -> Self::Output {
+                unsafe { Self(intrinsic!(_mm512_sub)(self.0, rhs.0)) }
+            }
+        }
+
+        impl_operator! { $name, BitAnd, bitand,
+            fn bitand(self, rhs: Self) -> Self::Output {
+                unsafe { Self(_mm512_and_si512(self.0, rhs.0)) }
+            }
+        }
+
+        impl_operator! { $name, BitOr, bitor,
+            fn bitor(self, rhs: Self) -> Self::Output {
+                unsafe { Self(_mm512_or_si512(self.0, rhs.0)) }
+            }
+        }
+
+        impl_operator! { $name, BitXor, bitxor,
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                unsafe { Self(_mm512_xor_si512(self.0, rhs.0)) }
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                unsafe { Self(_mm512_xor_si512(self.0, _mm512_set1_epi8(-1))) }
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                <[$type; $lanes] as fmt::Debug>::fmt(&self.to_array(), f)
+            }
+        }
+    };
+}
+
+make_vector_type!(Int8x64, i8, 64, u64, epi8, epi8);
+make_vector_type!(Uint8x64, u8, 64, u64, epi8, epu8);
+
+make_vector_type!(Int16x32, i16, 32, u32, epi16, epi16);
+make_vector_type!(Uint16x32, u16, 32, u32, epi16, epu16);
+
+make_vector_type!(Int32x16, i32, 16, u16, epi32, epi32);
+make_vector_type!(Uint32x16, u32, 16, u16, epi32, epu32);
+
+make_vector_type!(Int64x8, i64, 8, u8, epi64, epi64);
+make_vector_type!(Uint64x8, u64, 8, u8, epi64, epu64);
+
+macro_rules! impl_shift_operator {
+    ($name: ident, $op: ident, $op_function: ident, $method: ident) => {
+        impl ops::$op<u32> for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: u32) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+
+        paste! {
+            impl ops::[<$op Assign>]<u32> for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: u32) {
+                    *self = self.$method(rhs);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_shifts {
+    ($signed: ident, $unsigned: ident, $postfix: ident) => {
+        impl_shifts!($signed, $postfix);
+        impl_shifts!($unsigned, $postfix);
+
+        impl $signed {
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a<const N: i32>(self) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_sra_ $postfix>](self.0, _mm_cvtsi32_si128(N))) }
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a_by(self, n: u32) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_sra_ $postfix>](self.0, _mm_cvtsi32_si128(n as i32))) }
+                }
+            }
+        }
+
+        impl_shift_operator!($signed, Shr, shr, shr_a_by);
+        impl_shift_operator!($unsigned, Shr, shr, shr_l_by);
+    };
+
+    ($name: ident, $postfix: ident) => {
+        // Shifts by a constant count in a register, as immediate shift intrinsics take unsigned
+        // counts. Counts past the element width clear the lane (or fill it with the sign bit).
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn shl<const N: i32>(self) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_sll_ $postfix>](self.0, _mm_cvtsi32_si128(N))) }
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l<const N: i32>(self) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_srl_ $postfix>](self.0, _mm_cvtsi32_si128(N))) }
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shl_by(self, n: u32) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_sll_ $postfix>](self.0, _mm_cvtsi32_si128(n as i32))) }
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l_by(self, n: u32) -> Self {
+                unsafe {
+                    paste! { Self([<_mm512_srl_ $postfix>](self.0, _mm_cvtsi32_si128(n as i32))) }
+                }
+            }
+        }
+
+        impl_shift_operator!($name, Shl, shl, shl_by);
+    };
+}
+
+impl_shifts!(Int16x32, Uint16x32, epi16);
+impl_shifts!(Int32x16, Uint32x16, epi32);
+impl_shifts!(Int64x8, Uint64x8, epi64);
+
+macro_rules! impl_shuffle {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                /// Every 2 bits of PATTERN select the source element for the corresponding
+                /// destination element. The same selection is applied to every 128-bit lane.
+                #[inline(always)]
+                #[must_use]
+                pub fn shuffle<const PATTERN: i32>(self) -> Self {
+                    unsafe { Self(_mm512_shuffle_epi32::<PATTERN>(self.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_shuffle!(Int32x16, Uint32x16);
+
+// Same as the 256-bit byte shifts, lanes are shifted in 16-bit lanes and masked.
+macro_rules! impl_byte_shifts {
+    ($signed: ident, $unsigned: ident) => {
+        impl_byte_shifts!($signed);
+        impl_byte_shifts!($unsigned);
+
+        impl $signed {
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a<const N: i32>(self) -> Self {
+                self.shr_a_by(N as u32)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a_by(self, n: u32) -> Self {
+                let n = n.min(7);
+                let sign = Self::splat((0x80u8 >> n) as _);
+
+                (self.shr_l_by(n) ^ sign) - sign
+            }
+        }
+
+        impl_shift_operator!($signed, Shr, shr, shr_a_by);
+        impl_shift_operator!($unsigned, Shr, shr, shr_l_by);
+    };
+
+    ($name: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn shl<const N: i32>(self) -> Self {
+                self.shl_by(N as u32)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l<const N: i32>(self) -> Self {
+                self.shr_l_by(N as u32)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shl_by(self, n: u32) -> Self {
+                let shifted = self.transmute::<Uint16x32>().shl_by(n).transmute::<Self>();
+
+                shifted & Self::splat(0xffu8.checked_shl(n).unwrap_or(0) as _)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l_by(self, n: u32) -> Self {
+                let shifted = self.transmute::<Uint16x32>().shr_l_by(n).transmute::<Self>();
+
+                shifted & Self::splat(0xffu8.checked_shr(n).unwrap_or(0) as _)
+            }
+        }
+
+        impl_shift_operator!($name, Shl, shl, shl_by);
+    };
+}
+
+impl_byte_shifts!(Int8x64, Uint8x64);
+
+macro_rules! impl_abs {
+    ($($name: ident => $abs: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn abs(self) -> Self {
+                    unsafe { Self($abs(self.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_abs!(
+    Int8x64 => _mm512_abs_epi8,
+    Int16x32 => _mm512_abs_epi16,
+    Int32x16 => _mm512_abs_epi32,
+    Int64x8 => _mm512_abs_epi64
+);
+
+macro_rules! impl_mul {
+    ($($name: ident => $mul: ident),*) => {
+        $(
+            impl_operator! { $name, Mul, mul,
+                fn mul(self, rhs: Self) -> Self {
+                    unsafe { Self($mul(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+/// There is no 8-bit multiplication, even and odd lanes are multiplied in 16-bit lanes.
+#[inline(always)]
+unsafe fn mullo_epi8(a: __m512i, b: __m512i) -> __m512i {
+    let even = _mm512_mullo_epi16(a, b);
+    let odd = _mm512_mullo_epi16(_mm512_srli_epi16::<8>(a), _mm512_srli_epi16::<8>(b));
+
+    _mm512_mask_mov_epi8(_mm512_slli_epi16::<8>(odd), 0x5555_5555_5555_5555, even)
+}
+
+// Low half of the products, which is the same for signed and unsigned lanes.
+impl_mul!(
+    Int8x64 => mullo_epi8,
+    Uint8x64 => mullo_epi8,
+    Int16x32 => _mm512_mullo_epi16,
+    Uint16x32 => _mm512_mullo_epi16,
+    Int32x16 => _mm512_mullo_epi32,
+    Uint32x16 => _mm512_mullo_epi32,
+    Int64x8 => _mm512_mullox_epi64,
+    Uint64x8 => _mm512_mullox_epi64
+);
+
+impl Int16x32 {
+    /// High 16 bits of the 32-bit products.
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        unsafe { Self(_mm512_mulhi_epi16(self.0, rhs.0)) }
+    }
+
+    /// Fixed-point Q15 multiplication: (self * rhs + 0x4000) >> 15
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hrs(self, rhs: Self) -> Self {
+        unsafe { Self(_mm512_mulhrs_epi16(self.0, rhs.0)) }
+    }
+
+    /// Multiplies lanes into 32-bit products and adds adjacent pairs of them.
+    #[inline(always)]
+    #[must_use]
+    pub fn madd(self, rhs: Self) -> Int32x16 {
+        unsafe { Int32x16(_mm512_madd_epi16(self.0, rhs.0)) }
+    }
+}
+
+impl Uint8x64 {
+    /// Multiplies unsigned lanes of `self` by signed lanes of `rhs` and adds adjacent pairs of the
+    /// products with signed saturation.
+    #[inline(always)]
+    #[must_use]
+    pub fn maddubs(self, rhs: Int8x64) -> Int16x32 {
+        unsafe { Int16x32(_mm512_maddubs_epi16(self.0, rhs.0)) }
+    }
+}
+
+impl Uint16x32 {
+    /// High 16 bits of the 32-bit products.
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        unsafe { Self(_mm512_mulhi_epu16(self.0, rhs.0)) }
+    }
+}
+
+macro_rules! impl_saturating_arithmetic {
+    ($($name: ident => $adds: ident, $subs: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn saturating_add(self, rhs: Self) -> Self {
+                    unsafe { Self($adds(self.0, rhs.0)) }
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn saturating_sub(self, rhs: Self) -> Self {
+                    unsafe { Self($subs(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_arithmetic!(
+    Int8x64 => _mm512_adds_epi8, _mm512_subs_epi8,
+    Uint8x64 => _mm512_adds_epu8, _mm512_subs_epu8,
+    Int16x32 => _mm512_adds_epi16, _mm512_subs_epi16,
+    Uint16x32 => _mm512_adds_epu16, _mm512_subs_epu16
+);
+
+macro_rules! impl_average {
+    ($($name: ident => $avg: ident),*) => {
+        $(
+            impl $name {
+                /// (self + rhs + 1) >> 1, computed without overflow.
+                #[inline(always)]
+                #[must_use]
+                pub fn avg(self, rhs: Self) -> Self {
+                    unsafe { Self($avg(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_average!(Uint8x64 => _mm512_avg_epu8, Uint16x32 => _mm512_avg_epu16);
+
+macro_rules! impl_pack {
+    ($($name: ident => $signed: ident, $unsigned: ident, $packs: ident, $packus: ident),*) => {
+        $(
+            impl $name {
+                /// Narrows lanes with signed saturation. Within every 128-bit lane the lower half
+                /// of the result comes from `self` and the upper half from `rhs`.
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating(self, rhs: Self) -> $signed {
+                    unsafe { $signed($packs(self.0, rhs.0)) }
+                }
+
+                /// Same as `pack_saturating`, but with unsigned saturation.
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating_unsigned(self, rhs: Self) -> $unsigned {
+                    unsafe { $unsigned($packus(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_pack!(
+    Int16x32 => Int8x64, Uint8x64, _mm512_packs_epi16, _mm512_packus_epi16,
+    Int32x16 => Int16x32, Uint16x32, _mm512_packs_epi32, _mm512_packus_epi32
+);
+
+macro_rules! impl_signedness_casts {
+    ($signed: ident, $unsigned: ident) => {
+        impl From<$signed> for $unsigned {
+            #[inline(always)]
+            fn from(x: $signed) -> Self {
+                Self(x.0)
+            }
+        }
+
+        impl From<$unsigned> for $signed {
+            #[inline(always)]
+            fn from(x: $unsigned) -> Self {
+                Self(x.0)
+            }
+        }
+
+        impl VectorConvertInto<$signed> for $unsigned {
+            #[inline(always)]
+            fn convert_vector(self) -> $signed {
+                $signed(self.0)
+            }
+        }
+
+        impl VectorConvertInto<$unsigned> for $signed {
+            #[inline(always)]
+            fn convert_vector(self) -> $unsigned {
+                $unsigned(self.0)
+            }
+        }
+    };
+}
+
+impl_signedness_casts!(Int8x64, Uint8x64);
+impl_signedness_casts!(Int16x32, Uint16x32);
+impl_signedness_casts!(Int32x16, Uint32x16);
+impl_signedness_casts!(Int64x8, Uint64x8);
+
+impl VectorConvertInto<super::Float32x16> for Int32x16 {
+    #[inline(always)]
+    fn convert_vector(self) -> super::Float32x16 {
+        unsafe { super::Float32x16(_mm512_cvtepi32_ps(self.0)) }
+    }
+}
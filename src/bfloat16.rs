@@ -0,0 +1,88 @@
+use core::fmt;
+
+use crate::{Float32x8, Int32x8, Uint16x16, Uint32x8, Uint64x4};
+
+/// Sixteen bfloat16 values, which are `f32` values with the low 16 bits of the mantissa dropped.
+/// It is only a storage format, arithmetic is done after converting to [`Float32x8`].
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Bfloat16x16(Uint16x16);
+
+/// Upper 16 bits of every lane of `low` followed by ones of `high`. Shifted lanes fit in 16 bits,
+/// so the saturating pack is exact, but it interleaves 128-bit lanes of both inputs.
+#[inline(always)]
+fn narrow(low: Uint32x8, high: Uint32x8) -> Uint16x16 {
+    let low = low.shr_l::<16>().transmute::<Int32x8>();
+    let high = high.shr_l::<16>().transmute::<Int32x8>();
+    let packed = low.pack_saturating_unsigned(high);
+
+    packed.transmute::<Uint64x4>().permute4x64::<0b11_01_10_00>().transmute()
+}
+
+/// Rounds `x` to nearest even bfloat16, in the upper 16 bits. NaNs get their quiet bit set, so
+/// they don't become infinities when the low bits are dropped.
+#[inline(always)]
+fn round(x: Float32x8) -> Uint32x8 {
+    let bits = x.transmute::<Uint32x8>();
+    let odd = bits.shr_l::<16>() & Uint32x8::splat(1);
+    let rounded = bits + Uint32x8::splat(0x7fff) + odd;
+    let ordered = x.eq(x).transmute::<Uint32x8>();
+
+    (rounded & ordered) | ordered.andnot(bits | Uint32x8::splat(0x0040_0000))
+}
+
+impl Bfloat16x16 {
+    #[inline(always)]
+    #[must_use]
+    pub fn zero() -> Self {
+        Self(Uint16x16::zero())
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn from_bits(bits: Uint16x16) -> Self {
+        Self(bits)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn to_bits(self) -> Uint16x16 {
+        self.0
+    }
+
+    /// Converts lanes of `low` and `high` into the first and the last eight lanes, rounding to
+    /// nearest even.
+    #[inline(always)]
+    #[must_use]
+    pub fn from_f32(low: Float32x8, high: Float32x8) -> Self {
+        Self(narrow(round(low), round(high)))
+    }
+
+    /// Same as `from_f32`, but rounds towards zero by dropping the low bits. NaNs with only low
+    /// mantissa bits set become infinities.
+    #[inline(always)]
+    #[must_use]
+    pub fn from_f32_truncate(low: Float32x8, high: Float32x8) -> Self {
+        Self(narrow(low.transmute(), high.transmute()))
+    }
+
+    /// Exact conversion of the first and the last eight lanes.
+    #[inline(always)]
+    #[must_use]
+    pub fn to_f32(self) -> (Float32x8, Float32x8) {
+        let widen = |x: crate::Uint16x8| x.convert::<Uint32x8>().shl::<16>().transmute();
+
+        (widen(self.0.low()), widen(self.0.high()))
+    }
+}
+
+impl fmt::Debug for Bfloat16x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (low, high) = self.to_f32();
+
+        f.debug_list()
+            .entries(low.to_array())
+            .entries(high.to_array())
+            .finish()
+    }
+}
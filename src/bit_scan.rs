@@ -0,0 +1,60 @@
+macro_rules! impl_bit_scan {
+    ($name: ty, $type: ty, $($shift: literal),*) => {
+        impl $name {
+            /// Number of leading zero bits in every lane. The highest set bit is smeared into all
+            /// lower bits and the remaining zeros are counted.
+            #[inline(always)]
+            #[must_use]
+            pub fn leading_zeros(self) -> Self {
+                let mut x = self;
+                $(
+                    x |= x.shr_l::<$shift>();
+                )*
+                Self::splat(<$type>::BITS as $type) - x.count_ones()
+            }
+
+            /// Number of trailing zero bits in every lane, the set bits of `!x & (x - 1)`.
+            #[inline(always)]
+            #[must_use]
+            pub fn trailing_zeros(self) -> Self {
+                self.andnot(self - Self::splat(1)).count_ones()
+            }
+        }
+    };
+}
+
+macro_rules! impl_bit_scan_for_backend {
+    ($($backend: ident)::*) => {
+        impl_bit_scan!($($backend)::*::Int8x16, i8, 1, 2, 4);
+        impl_bit_scan!($($backend)::*::Uint8x16, u8, 1, 2, 4);
+        impl_bit_scan!($($backend)::*::Int16x8, i16, 1, 2, 4, 8);
+        impl_bit_scan!($($backend)::*::Uint16x8, u16, 1, 2, 4, 8);
+        impl_bit_scan!($($backend)::*::Int32x4, i32, 1, 2, 4, 8, 16);
+        impl_bit_scan!($($backend)::*::Uint32x4, u32, 1, 2, 4, 8, 16);
+        impl_bit_scan!($($backend)::*::Int64x2, i64, 1, 2, 4, 8, 16, 32);
+        impl_bit_scan!($($backend)::*::Uint64x2, u64, 1, 2, 4, 8, 16, 32);
+
+        impl_bit_scan!($($backend)::*::Int8x32, i8, 1, 2, 4);
+        impl_bit_scan!($($backend)::*::Uint8x32, u8, 1, 2, 4);
+        impl_bit_scan!($($backend)::*::Int16x16, i16, 1, 2, 4, 8);
+        impl_bit_scan!($($backend)::*::Uint16x16, u16, 1, 2, 4, 8);
+        impl_bit_scan!($($backend)::*::Int32x8, i32, 1, 2, 4, 8, 16);
+        impl_bit_scan!($($backend)::*::Uint32x8, u32, 1, 2, 4, 8, 16);
+        impl_bit_scan!($($backend)::*::Int64x4, i64, 1, 2, 4, 8, 16, 32);
+        impl_bit_scan!($($backend)::*::Uint64x4, u64, 1, 2, 4, 8, 16, 32);
+    };
+}
+
+impl_bit_scan_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_bit_scan_for_backend!(crate::reference);
+
+impl_bit_scan!(crate::Int8x64, i8, 1, 2, 4);
+impl_bit_scan!(crate::Uint8x64, u8, 1, 2, 4);
+impl_bit_scan!(crate::Int16x32, i16, 1, 2, 4, 8);
+impl_bit_scan!(crate::Uint16x32, u16, 1, 2, 4, 8);
+impl_bit_scan!(crate::Int32x16, i32, 1, 2, 4, 8, 16);
+impl_bit_scan!(crate::Uint32x16, u32, 1, 2, 4, 8, 16);
+impl_bit_scan!(crate::Int64x8, i64, 1, 2, 4, 8, 16, 32);
+impl_bit_scan!(crate::Uint64x8, u64, 1, 2, 4, 8, 16, 32);
@@ -0,0 +1,244 @@
+use core::{fmt, ops};
+
+/// Fixed-size bitmap holding one bit per element of a virtual vector consisting of multiple
+/// SIMD vectors. Bits are stored in 64-bit words, least significant bit first.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Bitmask<const WORDS: usize>([u64; WORDS]);
+
+pub type Bitmask64 = Bitmask<1>;
+pub type Bitmask128 = Bitmask<2>;
+pub type Bitmask256 = Bitmask<4>;
+
+impl<const WORDS: usize> Bitmask<WORDS> {
+    pub const BITS: usize = WORDS * 64;
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn zero() -> Self {
+        Self([0; WORDS])
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn all_ones() -> Self {
+        Self([!0; WORDS])
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn from_words(words: [u64; WORDS]) -> Self {
+        Self(words)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn to_words(self) -> [u64; WORDS] {
+        self.0
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&self, index: usize) -> bool {
+        (self.0[index / 64] >> (index % 64)) & 1 != 0
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, index: usize, value: bool) {
+        let bit = 1 << (index % 64);
+        let word = &mut self.0[index / 64];
+
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+    }
+
+    /// Returns the mask of vector number `vector` of a virtual vector made of vectors with
+    /// `lanes` lanes each, in the format returned by `mask()` of floating-point vectors.
+    #[inline(always)]
+    #[must_use]
+    pub fn vector_mask(&self, vector: usize, lanes: usize) -> u32 {
+        assert!(
+            lanes.is_power_of_two() && lanes <= 32,
+            "Invalid lane count."
+        );
+
+        let start = vector * lanes;
+        let word = self.0[start / 64] >> (start % 64);
+
+        (word & (u64::MAX >> (64 - lanes))) as u32
+    }
+
+    /// Replaces bits of vector number `vector` of a virtual vector made of vectors with `lanes`
+    /// lanes each. `mask` has the format returned by `mask()` of floating-point vectors.
+    #[inline(always)]
+    pub fn set_vector_mask(&mut self, vector: usize, lanes: usize, mask: u32) {
+        assert!(
+            lanes.is_power_of_two() && lanes <= 32,
+            "Invalid lane count."
+        );
+
+        let start = vector * lanes;
+        let selected = u64::MAX >> (64 - lanes);
+        let word = &mut self.0[start / 64];
+
+        *word &= !(selected << (start % 64));
+        *word |= (mask as u64 & selected) << (start % 64);
+    }
+
+    /// Same as `vector_mask`, but in the format returned by `mask()` of integer vectors, which
+    /// has one bit per byte. Every bit is repeated `size` times, the lane width in bytes.
+    #[inline(always)]
+    #[must_use]
+    pub fn vector_mask_bytes(&self, vector: usize, lanes: usize, size: usize) -> u32 {
+        assert!(
+            size.is_power_of_two() && lanes * size <= 32,
+            "Invalid lane size."
+        );
+
+        let mask = self.vector_mask(vector, lanes);
+        let lane = u32::MAX >> (32 - size);
+
+        (0..lanes)
+            .filter(|i| (mask >> i) & 1 != 0)
+            .fold(0, |bytes, i| bytes | lane << (i * size))
+    }
+
+    /// Same as `set_vector_mask`, but `mask` has the format returned by `mask()` of integer
+    /// vectors with `size`-byte lanes. The bit of the most significant byte of every lane is used.
+    #[inline(always)]
+    pub fn set_vector_mask_bytes(&mut self, vector: usize, lanes: usize, size: usize, mask: u32) {
+        assert!(
+            size.is_power_of_two() && lanes * size <= 32,
+            "Invalid lane size."
+        );
+
+        let lanes_mask = (0..lanes).fold(0, |bits, i| {
+            bits | ((mask >> (i * size + size - 1)) & 1) << i
+        });
+
+        self.set_vector_mask(vector, lanes, lanes_mask);
+    }
+
+    /// ~self & rhs
+    #[inline(always)]
+    #[must_use]
+    pub fn andnot(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| !self.0[i] & rhs.0[i]))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|word| word.count_ones()).sum()
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn is_all_ones(&self) -> bool {
+        self.0.iter().all(|&word| word == !0)
+    }
+
+    /// Returns an iterator over indices of set bits in ascending order.
+    #[inline(always)]
+    pub fn iter_ones(&self) -> BitmaskOnes<WORDS> {
+        BitmaskOnes {
+            words: self.0,
+            index: 0,
+        }
+    }
+}
+
+impl<const WORDS: usize> Default for Bitmask<WORDS> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+macro_rules! impl_bitmask_operator {
+    ($op: ident, $op_function: ident, $op_assign: ident, $op_assign_function: ident, $operator: tt) => {
+        impl<const WORDS: usize> ops::$op for Bitmask<WORDS> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: Self) -> Self {
+                Self(core::array::from_fn(|i| self.0[i] $operator rhs.0[i]))
+            }
+        }
+
+        impl<const WORDS: usize> ops::$op_assign for Bitmask<WORDS> {
+            #[inline(always)]
+            fn $op_assign_function(&mut self, rhs: Self) {
+                *self = ops::$op::$op_function(*self, rhs);
+            }
+        }
+    };
+}
+
+impl_bitmask_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_bitmask_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_bitmask_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl<const WORDS: usize> ops::Not for Bitmask<WORDS> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        Self(self.0.map(|word| !word))
+    }
+}
+
+impl<const WORDS: usize> fmt::Debug for Bitmask<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter_ones()).finish()
+    }
+}
+
+/// Iterator returned by [`Bitmask::iter_ones`].
+#[derive(Clone)]
+pub struct BitmaskOnes<const WORDS: usize> {
+    words: [u64; WORDS],
+    index: usize,
+}
+
+impl<const WORDS: usize> Iterator for BitmaskOnes<WORDS> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.index < WORDS {
+            let word = &mut self.words[self.index];
+
+            if *word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                *word &= *word - 1;
+
+                return Some(self.index * 64 + bit);
+            }
+
+            self.index += 1;
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.words[self.index.min(WORDS)..]
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+
+        (count, Some(count))
+    }
+}
+
+impl<const WORDS: usize> ExactSizeIterator for BitmaskOnes<WORDS> {}
@@ -0,0 +1,180 @@
+macro_rules! impl_add_with_carry {
+    ($name: ty, $sign_shift: literal) => {
+        impl $name {
+            /// Computes self + rhs + carry_in for multi-precision arithmetic. Carries are lane
+            /// masks: all bits set for a carry of 1 and all bits clear for 0. Returns the wrapped
+            /// sum and the carry out mask.
+            #[inline(always)]
+            #[must_use]
+            pub fn add_with_carry(self, rhs: Self, carry_in: Self) -> (Self, Self) {
+                let sum = self + rhs - carry_in;
+
+                // Carry out of the most significant bit of a full adder.
+                let carry = (self & rhs) | sum.andnot(self | rhs);
+
+                (sum, Self::zero() - carry.shr_l::<$sign_shift>())
+            }
+        }
+    };
+}
+
+macro_rules! impl_overflowing {
+    (unsigned: $name: ty) => {
+        impl $name {
+            /// Wrapping sum and a mask of lanes which overflowed.
+            #[inline(always)]
+            #[must_use]
+            pub fn overflowing_add(self, rhs: Self) -> (Self, Self) {
+                let sum = self + rhs;
+
+                (sum, self.gt(sum))
+            }
+
+            /// Wrapping difference and a mask of lanes which overflowed.
+            #[inline(always)]
+            #[must_use]
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, Self) {
+                (self - rhs, rhs.gt(self))
+            }
+
+            /// Computes self + rhs + carry, where `carry` is a lane mask like the returned carry
+            /// out mask.
+            #[inline(always)]
+            #[must_use]
+            pub fn carrying_add(self, rhs: Self, carry: Self) -> (Self, Self) {
+                let (sum, first) = self.overflowing_add(rhs);
+                let sum = sum - carry;
+
+                (sum, first | (carry & sum.eq(Self::zero())))
+            }
+        }
+    };
+
+    (signed: $name: ty, $min: expr) => {
+        impl $name {
+            /// Wrapping sum and a mask of lanes which overflowed.
+            #[inline(always)]
+            #[must_use]
+            pub fn overflowing_add(self, rhs: Self) -> (Self, Self) {
+                let sum = self + rhs;
+
+                // Overflow happens when both operands have a different sign than the sum.
+                (sum, Self::zero().gt((sum ^ self) & (sum ^ rhs)))
+            }
+
+            /// Wrapping difference and a mask of lanes which overflowed.
+            #[inline(always)]
+            #[must_use]
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, Self) {
+                let difference = self - rhs;
+
+                (difference, Self::zero().gt((self ^ rhs) & (self ^ difference)))
+            }
+
+            /// Computes self + rhs + carry, where `carry` is a lane mask like the returned overflow
+            /// mask.
+            #[inline(always)]
+            #[must_use]
+            pub fn carrying_add(self, rhs: Self, carry: Self) -> (Self, Self) {
+                let (sum, first) = self.overflowing_add(rhs);
+                let sum = sum - carry;
+
+                (sum, first ^ (carry & sum.eq(Self::splat($min))))
+            }
+        }
+    };
+}
+
+// 8-bit and 16-bit lanes have saturating instructions, wider ones saturate lanes which overflowed.
+macro_rules! impl_saturating_arithmetic {
+    (unsigned: $name: ty) => {
+        impl $name {
+            /// Sums saturated at the maximum of the element type.
+            #[inline(always)]
+            #[must_use]
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                let (sum, overflow) = self.overflowing_add(rhs);
+
+                sum | overflow
+            }
+
+            /// Differences saturated at zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                let (difference, overflow) = self.overflowing_sub(rhs);
+
+                overflow.andnot(difference)
+            }
+        }
+    };
+
+    (signed: $name: ty, $min: expr, $max: expr) => {
+        impl $name {
+            /// Sums saturated at the bounds of the element type.
+            #[inline(always)]
+            #[must_use]
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                let (sum, overflow) = self.overflowing_add(rhs);
+
+                Self::bitselect(overflow, self.saturation_bound(), sum)
+            }
+
+            /// Differences saturated at the bounds of the element type.
+            #[inline(always)]
+            #[must_use]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                let (difference, overflow) = self.overflowing_sub(rhs);
+
+                Self::bitselect(overflow, self.saturation_bound(), difference)
+            }
+
+            /// Bound which overflowing results saturate to, it has the sign of `self` in both
+            /// sums and differences.
+            #[inline(always)]
+            fn saturation_bound(self) -> Self {
+                Self::bitselect(Self::zero().gt(self), Self::splat($min), Self::splat($max))
+            }
+        }
+    };
+}
+
+macro_rules! impl_add_with_carry_for_backend {
+    ($($backend: ident)::*) => {
+        impl_add_with_carry!($($backend)::*::Uint32x8, 31);
+        impl_add_with_carry!($($backend)::*::Uint64x4, 63);
+
+        impl_overflowing!(unsigned: $($backend)::*::Uint8x16);
+        impl_overflowing!(unsigned: $($backend)::*::Uint16x8);
+        impl_overflowing!(unsigned: $($backend)::*::Uint32x4);
+        impl_overflowing!(unsigned: $($backend)::*::Uint64x2);
+        impl_overflowing!(unsigned: $($backend)::*::Uint8x32);
+        impl_overflowing!(unsigned: $($backend)::*::Uint16x16);
+        impl_overflowing!(unsigned: $($backend)::*::Uint32x8);
+        impl_overflowing!(unsigned: $($backend)::*::Uint64x4);
+
+        impl_overflowing!(signed: $($backend)::*::Int8x16, i8::MIN);
+        impl_overflowing!(signed: $($backend)::*::Int16x8, i16::MIN);
+        impl_overflowing!(signed: $($backend)::*::Int32x4, i32::MIN);
+        impl_overflowing!(signed: $($backend)::*::Int64x2, i64::MIN);
+        impl_overflowing!(signed: $($backend)::*::Int8x32, i8::MIN);
+        impl_overflowing!(signed: $($backend)::*::Int16x16, i16::MIN);
+        impl_overflowing!(signed: $($backend)::*::Int32x8, i32::MIN);
+        impl_overflowing!(signed: $($backend)::*::Int64x4, i64::MIN);
+
+        impl_saturating_arithmetic!(unsigned: $($backend)::*::Uint32x4);
+        impl_saturating_arithmetic!(unsigned: $($backend)::*::Uint64x2);
+        impl_saturating_arithmetic!(unsigned: $($backend)::*::Uint32x8);
+        impl_saturating_arithmetic!(unsigned: $($backend)::*::Uint64x4);
+
+        impl_saturating_arithmetic!(signed: $($backend)::*::Int32x4, i32::MIN, i32::MAX);
+        impl_saturating_arithmetic!(signed: $($backend)::*::Int64x2, i64::MIN, i64::MAX);
+        impl_saturating_arithmetic!(signed: $($backend)::*::Int32x8, i32::MIN, i32::MAX);
+        impl_saturating_arithmetic!(signed: $($backend)::*::Int64x4, i64::MIN, i64::MAX);
+    };
+}
+
+impl_add_with_carry_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_add_with_carry_for_backend!(crate::reference);
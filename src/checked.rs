@@ -0,0 +1,138 @@
+use core::ops;
+
+/// Vector wrapper which panics when an arithmetic operation produces a NaN or infinite lane
+/// from finite operands, or when an integer operation overflows. Meant for debugging numeric
+/// issues in SIMD kernels, every operation is followed by a check.
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(transparent)]
+pub struct Checked<T>(pub T);
+
+macro_rules! impl_checked_float_operator {
+    ($name: ty, $op: ident, $op_function: ident, $op_assign: ident, $op_assign_function: ident,
+     $symbol: literal) => {
+        impl ops::$op for Checked<$name> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: Self) -> Self {
+                let result = ops::$op::$op_function(self.0, rhs.0);
+                let (a, b, r) = (self.0.to_array(), rhs.0.to_array(), result.to_array());
+
+                for lane in 0..a.len() {
+                    if a[lane].is_finite() && b[lane].is_finite() && !r[lane].is_finite() {
+                        panic!(
+                            "Lane {}: {:?} {} {:?} produced {:?}.",
+                            lane, a[lane], $symbol, b[lane], r[lane]
+                        );
+                    }
+                }
+
+                Checked(result)
+            }
+        }
+
+        impl ops::$op_assign for Checked<$name> {
+            #[inline(always)]
+            fn $op_assign_function(&mut self, rhs: Self) {
+                *self = ops::$op::$op_function(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_integer_operator {
+    ($name: ty, $op: ident, $op_function: ident, $op_assign: ident, $op_assign_function: ident,
+     $checked_function: ident, $symbol: literal) => {
+        impl ops::$op for Checked<$name> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: Self) -> Self {
+                let result = ops::$op::$op_function(self.0, rhs.0);
+                let (a, b) = (self.0.to_array(), rhs.0.to_array());
+
+                for lane in 0..a.len() {
+                    if a[lane].$checked_function(b[lane]).is_none() {
+                        panic!(
+                            "Lane {}: {:?} {} {:?} overflowed.",
+                            lane, a[lane], $symbol, b[lane]
+                        );
+                    }
+                }
+
+                Checked(result)
+            }
+        }
+
+        impl ops::$op_assign for Checked<$name> {
+            #[inline(always)]
+            fn $op_assign_function(&mut self, rhs: Self) {
+                *self = ops::$op::$op_function(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_neg {
+    ($name: ty) => {
+        impl ops::Neg for Checked<$name> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                let a = self.0.to_array();
+
+                for lane in 0..a.len() {
+                    if a[lane].checked_neg().is_none() {
+                        panic!("Lane {}: -{:?} overflowed.", lane, a[lane]);
+                    }
+                }
+
+                Checked(-self.0)
+            }
+        }
+    };
+}
+
+macro_rules! impl_checked_float {
+    ($name: ty) => {
+        impl_checked_float_operator!($name, Add, add, AddAssign, add_assign, "+");
+        impl_checked_float_operator!($name, Sub, sub, SubAssign, sub_assign, "-");
+        impl_checked_float_operator!($name, Mul, mul, MulAssign, mul_assign, "*");
+        impl_checked_float_operator!($name, Div, div, DivAssign, div_assign, "/");
+    };
+}
+
+macro_rules! impl_checked_integer {
+    ($name: ty) => {
+        impl_checked_integer_operator!($name, Add, add, AddAssign, add_assign, checked_add, "+");
+        impl_checked_integer_operator!($name, Sub, sub, SubAssign, sub_assign, checked_sub, "-");
+        impl_checked_integer_operator!($name, Mul, mul, MulAssign, mul_assign, checked_mul, "*");
+    };
+
+    (signed: $name: ty) => {
+        impl_checked_integer!($name);
+        impl_checked_neg!($name);
+    };
+}
+
+macro_rules! impl_checked_for_backend {
+    ($($backend: ident)::*) => {
+        impl_checked_float!($($backend)::*::Float32x8);
+        impl_checked_float!($($backend)::*::Float64x4);
+
+        impl_checked_integer!(signed: $($backend)::*::Int8x32);
+        impl_checked_integer!($($backend)::*::Uint8x32);
+        impl_checked_integer!(signed: $($backend)::*::Int16x16);
+        impl_checked_integer!($($backend)::*::Uint16x16);
+        impl_checked_integer!(signed: $($backend)::*::Int32x8);
+        impl_checked_integer!($($backend)::*::Uint32x8);
+        impl_checked_integer!(signed: $($backend)::*::Int64x4);
+        impl_checked_integer!($($backend)::*::Uint64x4);
+    };
+}
+
+impl_checked_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_checked_for_backend!(crate::reference);
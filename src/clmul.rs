@@ -0,0 +1,107 @@
+//! Carry-less multiplication of 64-bit lanes, used for CRC folding and GHASH. With the `pclmulqdq`
+//! feature on a target supporting it the products use `pclmulqdq`, and `vpclmulqdq` for 256-bit
+//! vectors if available. Otherwise they are computed one by one.
+
+#[cfg(any(
+    feature = "reference",
+    not(all(
+        feature = "pclmulqdq",
+        target_feature = "pclmulqdq",
+        target_feature = "sse4.1",
+        not(feature = "soft")
+    ))
+))]
+macro_rules! impl_clmul {
+    ($name: ty, $lanes: literal) => {
+        impl $name {
+            /// Carry-less product of one 64-bit lane of `self` and one of `rhs` in every 128-bit
+            /// lane. Bit 0 of `N` picks the lane of `self` and bit 4 the lane of `rhs`, like
+            /// `_mm_clmulepi64_si128`. The product is stored low half first.
+            #[inline(always)]
+            #[must_use]
+            pub fn clmul<const N: i32>(self, rhs: Self) -> Self {
+                let clmul = |a: u64, b: u64| {
+                    (0..64)
+                        .filter(|i| (b >> i) & 1 != 0)
+                        .fold(0u128, |product, i| product ^ ((a as u128) << i))
+                };
+
+                let (a, b) = (self.to_array(), rhs.to_array());
+                let (x, y) = ((N & 1) as usize, ((N >> 4) & 1) as usize);
+
+                let mut result = [0; $lanes];
+                for i in (0..$lanes).step_by(2) {
+                    let product = clmul(a[i + x], b[i + y]);
+
+                    result[i] = product as u64;
+                    result[i + 1] = (product >> 64) as u64;
+                }
+
+                Self::from_array(result)
+            }
+        }
+    };
+}
+
+#[cfg(not(all(
+    feature = "pclmulqdq",
+    target_feature = "pclmulqdq",
+    target_feature = "sse4.1",
+    not(feature = "soft")
+)))]
+impl_clmul!(crate::Uint64x2, 2);
+#[cfg(not(all(
+    feature = "pclmulqdq",
+    target_feature = "pclmulqdq",
+    target_feature = "sse4.1",
+    not(feature = "soft")
+)))]
+impl_clmul!(crate::Uint64x4, 4);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_clmul!(crate::reference::Uint64x2, 2);
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_clmul!(crate::reference::Uint64x4, 4);
+
+#[cfg(all(
+    feature = "pclmulqdq",
+    target_feature = "pclmulqdq",
+    target_feature = "sse4.1",
+    not(feature = "soft")
+))]
+impl crate::Uint64x2 {
+    /// Carry-less product of one 64-bit lane of `self` and one of `rhs`. Bit 0 of `N` picks the
+    /// lane of `self` and bit 4 the lane of `rhs`, like `_mm_clmulepi64_si128`. The product is
+    /// stored low half first.
+    #[inline(always)]
+    #[must_use]
+    pub fn clmul<const N: i32>(self, rhs: Self) -> Self {
+        unsafe { crate::arch::_mm_clmulepi64_si128::<N>(self.into(), rhs.into()).into() }
+    }
+}
+
+#[cfg(all(
+    feature = "pclmulqdq",
+    target_feature = "pclmulqdq",
+    target_feature = "sse4.1",
+    not(feature = "soft")
+))]
+impl crate::Uint64x4 {
+    /// Carry-less product of one 64-bit lane of `self` and one of `rhs` in every 128-bit lane.
+    /// Bit 0 of `N` picks the lane of `self` and bit 4 the lane of `rhs`, like
+    /// `_mm256_clmulepi64_epi128`. The product is stored low half first.
+    #[inline(always)]
+    #[must_use]
+    pub fn clmul<const N: i32>(self, rhs: Self) -> Self {
+        #[cfg(all(target_feature = "vpclmulqdq", target_feature = "avx2"))]
+        return unsafe {
+            crate::arch::_mm256_clmulepi64_epi128::<N>(self.into(), rhs.into()).into()
+        };
+
+        #[cfg(not(all(target_feature = "vpclmulqdq", target_feature = "avx2")))]
+        return Self::combine(
+            self.low().clmul::<N>(rhs.low()),
+            self.high().clmul::<N>(rhs.high()),
+        );
+    }
+}
@@ -5,3 +5,19 @@ pub trait VectorConvertInto<T> {
 pub trait VectorTransmuteInto<T> {
     fn transmute_vector(self) -> T;
 }
+
+/// Raw bit representation shared by all vector types of the same backend and width. Any two
+/// types with the same representation can be transmuted into each other.
+pub(crate) trait VectorBits {
+    type Bits;
+
+    fn from_bits(x: Self::Bits) -> Self;
+    fn to_bits(self) -> Self::Bits;
+}
+
+impl<ToV: VectorBits, FromV: VectorBits<Bits = ToV::Bits>> VectorTransmuteInto<ToV> for FromV {
+    #[inline(always)]
+    fn transmute_vector(self) -> ToV {
+        ToV::from_bits(self.to_bits())
+    }
+}
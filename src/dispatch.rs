@@ -0,0 +1,127 @@
+//! Runtime selection of the best implementation of a kernel. Functions defined with the
+//! [`dispatch!`](crate::dispatch!) macro are compiled once for every supported [`Level`] and the
+//! best one for the executing CPU is picked on every call using a cached feature check. This is
+//! most useful when the crate is compiled without the `avx2` target feature: the scalar vector
+//! types inline into every copy and get compiled into instructions of its instruction set.
+
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Instruction sets which kernels are compiled for, ordered from the least capable one.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Scalar = 1,
+    Sse41 = 2,
+    Avx2 = 3,
+    Avx2Fma = 4,
+}
+
+/// Detected level, 0 until the first call to `level`.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+impl Level {
+    #[inline]
+    fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        {
+            if std::is_x86_feature_detected!("avx2") {
+                if std::is_x86_feature_detected!("fma") {
+                    return Self::Avx2Fma;
+                }
+
+                return Self::Avx2;
+            }
+
+            if std::is_x86_feature_detected!("sse4.1") {
+                return Self::Sse41;
+            }
+        }
+
+        Self::Scalar
+    }
+}
+
+/// Returns the best level supported by the executing CPU. Detection happens only on first call.
+#[inline]
+#[must_use]
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        1 => Level::Scalar,
+        2 => Level::Sse41,
+        3 => Level::Avx2,
+        4 => Level::Avx2Fma,
+        _ => {
+            let level = Level::detect();
+            LEVEL.store(level as u8, Ordering::Relaxed);
+
+            level
+        }
+    }
+}
+
+/// Calls `f` from a function compiled with the `avx2` and `fma` target features if the CPU
+/// supports them and returns its result, otherwise returns `None`. Unlike [`dispatch!`] this
+/// works with closures and generic code, but it relies on `f` being inlined into the wrapper.
+#[inline]
+pub fn with_avx2<R>(f: impl FnOnce() -> R) -> Option<R> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        #[target_feature(enable = "avx2,fma")]
+        unsafe fn run<R>(f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        if level() == Level::Avx2Fma {
+            // The CPU supports all features `run` is compiled with.
+            return Some(unsafe { run(f) });
+        }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = f;
+
+    None
+}
+
+/// Defines functions which are compiled separately for every [`Level`](crate::dispatch::Level)
+/// and dispatch to the best one supported by the CPU at runtime. Takes any number of free
+/// function definitions, for example `dispatch! { pub fn sum(values: &[f32]) -> f32 { ... } }`.
+/// Generic functions and methods are not supported.
+#[macro_export]
+macro_rules! dispatch {
+    ($(
+        $(#[$attribute: meta])*
+        $visibility: vis fn $name: ident($($argument: ident: $argument_type: ty),* $(,)?)
+            $(-> $return_type: ty)?
+        $body: block
+    )*) => {$(
+        $(#[$attribute])*
+        #[inline]
+        $visibility fn $name($($argument: $argument_type),*) $(-> $return_type)? {
+            #[cfg(target_arch = "x86_64")]
+            {
+                #[target_feature(enable = "avx2,fma")]
+                unsafe fn avx2_fma($($argument: $argument_type),*) $(-> $return_type)? $body
+
+                #[target_feature(enable = "avx2")]
+                unsafe fn avx2($($argument: $argument_type),*) $(-> $return_type)? $body
+
+                #[target_feature(enable = "sse4.1")]
+                unsafe fn sse41($($argument: $argument_type),*) $(-> $return_type)? $body
+
+                // Each variant is called only if the CPU supports its features.
+                match $crate::dispatch::level() {
+                    $crate::dispatch::Level::Avx2Fma => {
+                        return unsafe { avx2_fma($($argument),*) };
+                    }
+                    $crate::dispatch::Level::Avx2 => return unsafe { avx2($($argument),*) },
+                    $crate::dispatch::Level::Sse41 => return unsafe { sse41($($argument),*) },
+                    $crate::dispatch::Level::Scalar => {}
+                }
+            }
+
+            fn scalar($($argument: $argument_type),*) $(-> $return_type)? $body
+
+            scalar($($argument),*)
+        }
+    )*};
+}
@@ -0,0 +1,324 @@
+//! Element-wise integer division. There are no integer division instructions, so 32-bit lanes are
+//! divided as `f64` and narrower lanes are extended to 32 bits and divided as `f32`, which is exact
+//! for these ranges. 64-bit lanes are divided one by one. Like `wrapping_div`, `MIN / -1` is `MIN`.
+//! Lanes divided by zero become zero and their remainder is the dividend.
+
+use core::ops;
+
+use crate::{Int32x8, Int64x4};
+
+macro_rules! impl_operators {
+    ($name: ty) => {
+        impl ops::Div for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn div(self, rhs: Self) -> Self {
+                self.div_rem(rhs).0
+            }
+        }
+
+        impl ops::Rem for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn rem(self, rhs: Self) -> Self {
+                self.div_rem(rhs).1
+            }
+        }
+
+        impl ops::DivAssign for $name {
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl ops::RemAssign for $name {
+            #[inline(always)]
+            fn rem_assign(&mut self, rhs: Self) {
+                *self = *self % rhs;
+            }
+        }
+    };
+}
+
+macro_rules! impl_division {
+    // Lanes divided by zero are cleared after a truncated float division.
+    (@masked $name: ty, $divide: expr) => {
+        impl $name {
+            #[inline(always)]
+            fn quotient(self, rhs: Self) -> Self {
+                $divide(self, rhs)
+            }
+
+            #[inline(always)]
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                let quotient = rhs.eq(Self::zero()).andnot(self.quotient(rhs));
+
+                (quotient, self - quotient * rhs)
+            }
+        }
+
+        impl_operators!($name);
+    };
+
+    (32: $name: ty, $float: ty) => {
+        impl_division!(@masked $name, |a: $name, b: $name| {
+            (a.convert::<$float>() / b.convert::<$float>()).trunc().convert::<$name>()
+        });
+    };
+
+    // Unsigned lanes are biased into the signed range for the conversions.
+    (u32: $name: ty, $signed: ty, $float: ty) => {
+        impl_division!(@masked $name, |a: $name, b: $name| {
+            let bias = <$name>::splat(1 << 31);
+            let to_float = |x: $name| {
+                <$signed>::from(x ^ bias).convert::<$float>() + <$float>::splat(2147483648.0)
+            };
+            let quotient = (to_float(a) / to_float(b)).trunc() - <$float>::splat(2147483648.0);
+
+            <$name>::from(quotient.convert::<$signed>()) ^ bias
+        });
+    };
+
+    // 256-bit vectors of 32-bit lanes are divided in 128-bit halves.
+    (halves: $name: ty) => {
+        impl_division!(@masked $name, |a: $name, b: $name| {
+            <$name>::combine(a.low().quotient(b.low()), a.high().quotient(b.high()))
+        });
+    };
+
+    // Even and odd 16-bit lanes are extended into 32-bit lanes and divided as `f32`.
+    (16: $name: ty, $wide: ty, $float: ty, $even: expr, $odd: expr) => {
+        impl_division!(@masked $name, |a: $name, b: $name| {
+            let (a, b) = (a.transmute::<$wide>(), b.transmute::<$wide>());
+            let divide = |a: $wide, b: $wide| {
+                (a.convert::<$float>() / b.convert::<$float>()).trunc().convert::<$wide>()
+            };
+
+            let even = divide($even(a), $even(b)) & <$wide>::splat(0xffff);
+            let odd = divide($odd(a), $odd(b)).shl::<16>();
+
+            (even | odd).transmute::<$name>()
+        });
+    };
+
+    // Even and odd 8-bit lanes are extended into 16-bit lanes, which also gives the remainders.
+    (8: $name: ty, $wide: ty, $even: expr, $odd: expr) => {
+        impl $name {
+            #[inline(always)]
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                let (a, b) = (self.transmute::<$wide>(), rhs.transmute::<$wide>());
+                let (even_quotient, even_remainder) = $even(a).div_rem($even(b));
+                let (odd_quotient, odd_remainder) = $odd(a).div_rem($odd(b));
+
+                let merge = |even: $wide, odd: $wide| {
+                    ((even & <$wide>::splat(0xff)) | odd.shl::<8>()).transmute::<Self>()
+                };
+
+                (merge(even_quotient, odd_quotient), merge(even_remainder, odd_remainder))
+            }
+        }
+
+        impl_operators!($name);
+    };
+
+    (64: $name: ty) => {
+        impl $name {
+            #[inline(always)]
+            fn div_rem(self, rhs: Self) -> (Self, Self) {
+                let (a, b) = (self.to_array(), rhs.to_array());
+                let quotient = core::array::from_fn(|i| match b[i] {
+                    0 => 0,
+                    _ => a[i].wrapping_div(b[i]),
+                });
+                let remainder = core::array::from_fn(|i| match b[i] {
+                    0 => a[i],
+                    _ => a[i].wrapping_rem(b[i]),
+                });
+
+                (Self::from_array(quotient), Self::from_array(remainder))
+            }
+        }
+
+        impl_operators!($name);
+    };
+}
+
+macro_rules! impl_division_for_backend {
+    ($($backend: ident)::*) => {
+        impl_division!(32: $($backend)::*::Int32x4, $($backend)::*::Float64x4);
+        impl_division!(
+            u32: $($backend)::*::Uint32x4, $($backend)::*::Int32x4, $($backend)::*::Float64x4
+        );
+        impl_division!(halves: $($backend)::*::Int32x8);
+        impl_division!(halves: $($backend)::*::Uint32x8);
+
+        impl_division!(
+            16: $($backend)::*::Int16x8, $($backend)::*::Int32x4, $($backend)::*::Float32x4,
+            |x: $($backend)::*::Int32x4| x.shl::<16>().shr_a::<16>(),
+            |x: $($backend)::*::Int32x4| x.shr_a::<16>()
+        );
+        impl_division!(
+            16: $($backend)::*::Uint16x8, $($backend)::*::Int32x4, $($backend)::*::Float32x4,
+            |x: $($backend)::*::Int32x4| x & $($backend)::*::Int32x4::splat(0xffff),
+            |x: $($backend)::*::Int32x4| x.shr_l::<16>()
+        );
+        impl_division!(
+            16: $($backend)::*::Int16x16, $($backend)::*::Int32x8, $($backend)::*::Float32x8,
+            |x: $($backend)::*::Int32x8| x.shl::<16>().shr_a::<16>(),
+            |x: $($backend)::*::Int32x8| x.shr_a::<16>()
+        );
+        impl_division!(
+            16: $($backend)::*::Uint16x16, $($backend)::*::Int32x8, $($backend)::*::Float32x8,
+            |x: $($backend)::*::Int32x8| x & $($backend)::*::Int32x8::splat(0xffff),
+            |x: $($backend)::*::Int32x8| x.shr_l::<16>()
+        );
+
+        impl_division!(
+            8: $($backend)::*::Int8x16, $($backend)::*::Int16x8,
+            |x: $($backend)::*::Int16x8| x.shl::<8>().shr_a::<8>(),
+            |x: $($backend)::*::Int16x8| x.shr_a::<8>()
+        );
+        impl_division!(
+            8: $($backend)::*::Uint8x16, $($backend)::*::Uint16x8,
+            |x: $($backend)::*::Uint16x8| x & $($backend)::*::Uint16x8::splat(0xff),
+            |x: $($backend)::*::Uint16x8| x.shr_l::<8>()
+        );
+        impl_division!(
+            8: $($backend)::*::Int8x32, $($backend)::*::Int16x16,
+            |x: $($backend)::*::Int16x16| x.shl::<8>().shr_a::<8>(),
+            |x: $($backend)::*::Int16x16| x.shr_a::<8>()
+        );
+        impl_division!(
+            8: $($backend)::*::Uint8x32, $($backend)::*::Uint16x16,
+            |x: $($backend)::*::Uint16x16| x & $($backend)::*::Uint16x16::splat(0xff),
+            |x: $($backend)::*::Uint16x16| x.shr_l::<8>()
+        );
+
+        impl_division!(64: $($backend)::*::Int64x2);
+        impl_division!(64: $($backend)::*::Uint64x2);
+        impl_division!(64: $($backend)::*::Int64x4);
+        impl_division!(64: $($backend)::*::Uint64x4);
+    };
+}
+
+impl_division_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_division_for_backend!(crate::reference);
+
+/// Divisor of every lane of an `Int32x8` prepared for repeated division, which then needs only
+/// multiplications and shifts. Uses the branchless algorithm from libdivide.
+#[derive(Copy, Clone)]
+pub struct DivisorInt32x8 {
+    divisor: Int32x8,
+    zero: Int32x8,
+    magic: Int32x8,
+    correction: Int32x8,
+    shift: Int32x8,
+    sign: Int32x8,
+}
+
+impl DivisorInt32x8 {
+    #[must_use]
+    pub fn new(divisor: Int32x8) -> Self {
+        let constants = divisor.to_array().map(|d| {
+            let absolute = d.unsigned_abs();
+            let shift = 31 - absolute.leading_zeros().min(31);
+
+            // Powers of two only need rounding towards zero before the shift.
+            let (magic, correction) = if absolute & absolute.wrapping_sub(1) == 0 {
+                (0, (1u32 << shift) - 1)
+            } else {
+                (((1 << (32 + shift)) / absolute as u64 + 1) as u32, 1u32 << shift)
+            };
+
+            [magic as i32, correction as i32, shift as i32, d >> 31]
+        });
+        let lanes = |i: usize| Int32x8::from_array(constants.map(|x| x[i]));
+
+        Self {
+            divisor,
+            zero: divisor.eq(Int32x8::zero()),
+            magic: lanes(0),
+            correction: lanes(1),
+            shift: lanes(2),
+            sign: lanes(3),
+        }
+    }
+
+    #[must_use]
+    pub fn splat(divisor: i32) -> Self {
+        Self::new(Int32x8::splat(divisor))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn divisor(self) -> Int32x8 {
+        self.divisor
+    }
+}
+
+/// High 32 bits of the signed 64-bit products.
+#[inline(always)]
+fn mul_hi(a: Int32x8, b: Int32x8) -> Int32x8 {
+    let odd = |x: Int32x8| x.transmute::<Int64x4>().shr_l::<32>().transmute::<Int32x8>();
+
+    let even = a.mul_even_widening(b).shr_l::<32>();
+    let odd = odd(a).mul_even_widening(odd(b)) & Int64x4::splat(!0 << 32);
+
+    (even | odd).transmute()
+}
+
+/// Arithmetic right shift of every lane by the count in the same lane of `counts`.
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+#[inline(always)]
+fn shr_a_lanes(x: Int32x8, counts: Int32x8) -> Int32x8 {
+    unsafe { crate::arch::_mm256_srav_epi32(x.into(), counts.into()).into() }
+}
+
+#[cfg(any(not(target_feature = "sse4.1"), feature = "soft"))]
+#[inline(always)]
+fn shr_a_lanes(x: Int32x8, counts: Int32x8) -> Int32x8 {
+    let (x, counts) = (x.to_array(), counts.to_array());
+
+    Int32x8::from_array(core::array::from_fn(|i| x[i] >> (counts[i] as u32).min(31)))
+}
+
+impl ops::Div<DivisorInt32x8> for Int32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: DivisorInt32x8) -> Self {
+        let mut quotient = mul_hi(self, rhs.magic) + self;
+        quotient += quotient.shr_a::<31>() & rhs.correction;
+        quotient = shr_a_lanes(quotient, rhs.shift);
+
+        rhs.zero.andnot((quotient ^ rhs.sign) - rhs.sign)
+    }
+}
+
+impl ops::Rem<DivisorInt32x8> for Int32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn rem(self, rhs: DivisorInt32x8) -> Self {
+        self - self / rhs * rhs.divisor
+    }
+}
+
+impl ops::DivAssign<DivisorInt32x8> for Int32x8 {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: DivisorInt32x8) {
+        *self = *self / rhs;
+    }
+}
+
+impl ops::RemAssign<DivisorInt32x8> for Int32x8 {
+    #[inline(always)]
+    fn rem_assign(&mut self, rhs: DivisorInt32x8) {
+        *self = *self % rhs;
+    }
+}
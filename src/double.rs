@@ -0,0 +1,935 @@
+//! Double-pumped vector types made of two 256-bit vectors. Every operation is done separately on
+//! both halves, which gives out-of-order CPUs two independent dependency chains to work on.
+
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::slice_assume_init_mut;
+use crate::{
+    Float32x8, Float64x4, Int16x16, Int32x8, Int64x4, Int8x32, Uint16x16, Uint32x8, Uint64x4,
+    Uint8x32,
+};
+
+macro_rules! impl_operator {
+    ($name: ident, $($op: ident, $op_function: ident),*) => {
+        $(
+            impl ops::$op for $name {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $op_function(self, rhs: Self) -> Self::Output {
+                    Self(
+                        ops::$op::$op_function(self.0, rhs.0),
+                        ops::$op::$op_function(self.1, rhs.1),
+                    )
+                }
+            }
+
+            paste! {
+                impl ops::[<$op Assign>] for $name {
+                    #[inline(always)]
+                    fn [<$op_function _assign>](&mut self, rhs: Self) {
+                        *self = <Self as ops::$op>::$op_function(*self, rhs);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! unary {
+    ($($method: ident),*) => {
+        $(
+            #[inline(always)]
+            #[must_use]
+            pub fn $method(self) -> Self {
+                Self(self.0.$method(), self.1.$method())
+            }
+        )*
+    };
+}
+
+macro_rules! binary {
+    ($($method: ident),*) => {
+        $(
+            #[inline(always)]
+            #[must_use]
+            pub fn $method(self, rhs: Self) -> Self {
+                Self(self.0.$method(rhs.0), self.1.$method(rhs.1))
+            }
+        )*
+    };
+}
+
+macro_rules! unary_immediate {
+    ($($method: ident),*) => {
+        $(
+            #[inline(always)]
+            #[must_use]
+            pub fn $method<const N: i32>(self) -> Self {
+                Self(self.0.$method::<N>(), self.1.$method::<N>())
+            }
+        )*
+    };
+}
+
+macro_rules! unary_count {
+    ($($method: ident),*) => {
+        $(
+            #[inline(always)]
+            #[must_use]
+            pub fn $method(self, n: u32) -> Self {
+                Self(self.0.$method(n), self.1.$method(n))
+            }
+        )*
+    };
+}
+
+macro_rules! impl_shift_operator {
+    ($name: ident, $($op: ident, $op_function: ident),*) => {
+        $(
+            impl ops::$op<u32> for $name {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $op_function(self, rhs: u32) -> Self::Output {
+                    Self(ops::$op::$op_function(self.0, rhs), ops::$op::$op_function(self.1, rhs))
+                }
+            }
+
+            paste! {
+                impl ops::[<$op Assign>]<u32> for $name {
+                    #[inline(always)]
+                    fn [<$op_function _assign>](&mut self, rhs: u32) {
+                        *self = <Self as ops::$op<u32>>::$op_function(*self, rhs);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! make_vector_type {
+    ($name: ident, $half: ident, $type: ty, $lanes: expr, $half_mask_bits: expr) => {
+        #[derive(Copy, Clone)]
+        pub struct $name(pub(crate) $half, pub(crate) $half);
+
+        impl VectorBits for $name {
+            type Bits = [Uint8x32; 2];
+
+            #[inline(always)]
+            fn from_bits(x: [Uint8x32; 2]) -> Self {
+                Self(x[0].transmute(), x[1].transmute())
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> [Uint8x32; 2] {
+                [self.0.transmute(), self.1.transmute()]
+            }
+        }
+
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn from_halves(low: $half, high: $half) -> Self {
+                Self(low, high)
+            }
+
+            /// Returns the vectors holding the low and the high half of the lanes.
+            #[inline(always)]
+            #[must_use]
+            pub fn to_halves(self) -> ($half, $half) {
+                (self.0, self.1)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn zero() -> Self {
+                Self($half::zero(), $half::zero())
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn splat(v: $type) -> Self {
+                let half = $half::splat(v);
+                Self(half, half)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_from(v: &$type) -> Self {
+                Self::splat(*v)
+            }
+
+            /// Load 128 bits from memory into all four 128-bit lanes.
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_128_from(array: &[$type; $lanes / 4]) -> Self {
+                let half = $half::broadcast_128_from(array);
+                Self(half, half)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_array(array: [$type; $lanes]) -> Self {
+                let [low, high] = unsafe {
+                    core::mem::transmute::<[$type; $lanes], [[$type; $lanes / 2]; 2]>(array)
+                };
+
+                Self($half::from_array(low), $half::from_array(high))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_array(self) -> [$type; $lanes] {
+                let halves = [self.0.to_array(), self.1.to_array()];
+                unsafe { core::mem::transmute::<[[$type; $lanes / 2]; 2], [$type; $lanes]>(halves) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; 512 / 8]) -> Self {
+                let [low, high] =
+                    unsafe { core::mem::transmute::<[u8; 512 / 8], [[u8; 256 / 8]; 2]>(bytes) };
+
+                Self($half::from_bytes(low), $half::from_bytes(high))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_bytes(self) -> [u8; 512 / 8] {
+                let halves = [self.0.to_bytes(), self.1.to_bytes()];
+                unsafe { core::mem::transmute::<[[u8; 256 / 8]; 2], [u8; 512 / 8]>(halves) }
+            }
+
+            /// Stores all lanes at the beginning of `slice` and returns the initialized part.
+            /// Panics if `slice` is shorter than the vector.
+            #[inline(always)]
+            pub fn store_into_uninit(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                let slice = &mut slice[..$lanes];
+
+                let (low, high) = slice.split_at_mut($lanes / 2);
+                self.0.store_into_uninit(low);
+                self.1.store_into_uninit(high);
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Stores as many lanes as fit in `slice` and returns the initialized part.
+            #[inline(always)]
+            pub fn write_to_uninit_slice(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                if slice.len() >= $lanes {
+                    return self.store_into_uninit(slice);
+                }
+
+                for (element, x) in slice.iter_mut().zip(self.to_array()) {
+                    element.write(x);
+                }
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Initializes every element of `slice` to `v`.
+            #[inline(always)]
+            pub fn fill_uninit(slice: &mut [MaybeUninit<$type>], v: $type) -> &mut [$type] {
+                let vector = Self::splat(v);
+
+                let mut chunks = slice.chunks_exact_mut($lanes);
+                for chunk in &mut chunks {
+                    vector.store_into_uninit(chunk);
+                }
+                vector.write_to_uninit_slice(chunks.into_remainder());
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Loads a vector from `ptr` using a volatile read, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for reads and aligned to the element size.
+            #[inline(always)]
+            #[must_use]
+            pub unsafe fn load_volatile(ptr: *const [$type; $lanes]) -> Self {
+                Self::from_array(ptr.read_volatile())
+            }
+
+            /// Stores the vector to `ptr` using a volatile write, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for writes and aligned to the element size.
+            #[inline(always)]
+            pub unsafe fn store_volatile(self, ptr: *mut [$type; $lanes]) {
+                ptr.write_volatile(self.to_array());
+            }
+
+            /// Same as `mask` of the halves, with bits of the high half above the low half ones.
+            #[inline(always)]
+            #[must_use]
+            pub fn mask(self) -> u64 {
+                self.0.mask() as u64 | (self.1.mask() as u64) << $half_mask_bits
+            }
+
+            /// Returns true if all bits are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                (self.0 | self.1).is_zero()
+            }
+
+            /// Returns true if all bits are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_ones(self) -> bool {
+                (self.0 & self.1).is_all_ones()
+            }
+
+            /// Same as `is_zero`, named to pair with `is_all_ones`.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            /// Returns true if any bits of `self` selected by `mask` are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn test(self, mask: Self) -> bool {
+                self.0.test(mask.0) || self.1.test(mask.1)
+            }
+
+            /// True if bits of `self` selected by `mask` are neither all zeros nor all ones.
+            #[inline(always)]
+            #[must_use]
+            pub fn test_mixed(self, mask: Self) -> bool {
+                !(self & mask).is_zero() && !self.andnot(mask).is_zero()
+            }
+
+            binary!(eq, andnot);
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                (mask & if_set) | mask.andnot(if_clear)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn convert<T>(self) -> T
+            where
+                Self: VectorConvertInto<T>,
+            {
+                <Self as VectorConvertInto<T>>::convert_vector(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn transmute<T>(self) -> T
+            where
+                Self: VectorTransmuteInto<T>,
+            {
+                <Self as VectorTransmuteInto<T>>::transmute_vector(self)
+            }
+        }
+
+        impl_operator!($name, Add, add, Sub, sub, BitAnd, bitand, BitOr, bitor, BitXor, bitxor);
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                Self(!self.0, !self.1)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                <[$type; $lanes] as fmt::Debug>::fmt(&self.to_array(), f)
+            }
+        }
+    };
+}
+
+make_vector_type!(Float32x16, Float32x8, f32, 16, 8);
+make_vector_type!(Float64x8, Float64x4, f64, 8, 4);
+
+make_vector_type!(Int8x64, Int8x32, i8, 64, 32);
+make_vector_type!(Uint8x64, Uint8x32, u8, 64, 32);
+
+make_vector_type!(Int16x32, Int16x16, i16, 32, 32);
+make_vector_type!(Uint16x32, Uint16x16, u16, 32, 32);
+
+make_vector_type!(Int32x16, Int32x8, i32, 16, 32);
+make_vector_type!(Uint32x16, Uint32x8, u32, 16, 32);
+
+make_vector_type!(Int64x8, Int64x4, i64, 8, 32);
+make_vector_type!(Uint64x8, Uint64x4, u64, 8, 32);
+
+macro_rules! impl_float {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                binary!(ne, gt, lt, ge, le, min, max);
+                unary!(floor, ceil, trunc, round, sqrt, dup_even, dup_odd);
+
+                /// Applies the same 8-bit pattern to both halves.
+                #[inline(always)]
+                #[must_use]
+                pub fn blend<const I: i32>(self, rhs: Self) -> Self {
+                    Self(self.0.blend::<I>(rhs.0), self.1.blend::<I>(rhs.1))
+                }
+
+                /// (self * b) + c
+                #[cfg(any(
+                    target_feature = "fma",
+                    not(any(target_arch = "x86", target_arch = "x86_64"))
+                ))]
+                #[inline(always)]
+                #[must_use]
+                pub fn fmadd(self, b: Self, c: Self) -> Self {
+                    Self(self.0.fmadd(b.0, c.0), self.1.fmadd(b.1, c.1))
+                }
+
+                /// (self * b) - c
+                #[cfg(any(
+                    target_feature = "fma",
+                    not(any(target_arch = "x86", target_arch = "x86_64"))
+                ))]
+                #[inline(always)]
+                #[must_use]
+                pub fn fmsub(self, b: Self, c: Self) -> Self {
+                    Self(self.0.fmsub(b.0, c.0), self.1.fmsub(b.1, c.1))
+                }
+            }
+
+            impl_operator!($name, Mul, mul, Div, div);
+        )*
+    };
+}
+
+impl_float!(Float32x16, Float64x8);
+
+impl Float32x16 {
+    unary!(rsqrt);
+    unary_immediate!(shuffle);
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        Self(self.0.swap_bytes(), self.1.swap_bytes())
+    }
+}
+
+impl Float64x8 {
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        Self(self.0.swap_bytes(), self.1.swap_bytes())
+    }
+}
+
+macro_rules! impl_integer {
+    ($($name: ident, $type: ty, $lanes: expr);*) => {
+        $(
+            impl $name {
+                binary!(ne, gt, lt, le, ge);
+                unary!(count_ones, reverse_bits);
+
+                #[inline(always)]
+                #[must_use]
+                pub fn clamp(self, min: Self, max: Self) -> Self {
+                    Self(self.0.clamp(min.0, max.0), self.1.clamp(min.1, max.1))
+                }
+
+                /// Replaces lane `I`. Unlike the 256-bit version this goes through memory.
+                #[inline(always)]
+                #[must_use]
+                pub fn insert<const I: i32>(self, value: $type) -> Self {
+                    const { assert!(I >= 0 && (I as usize) < $lanes) };
+
+                    let mut array = self.to_array();
+                    array[I as usize] = value;
+
+                    Self::from_array(array)
+                }
+
+                /// Applies `align_right` to both halves, which keeps it within 128-bit lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn align_right<const N: i32>(self, rhs: Self) -> Self {
+                    Self(self.0.align_right::<N>(rhs.0), self.1.align_right::<N>(rhs.1))
+                }
+
+                /// Wrapping sum of all lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn reduce_add(self) -> $type {
+                    (self.0 + self.1).reduce_add()
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn reduce_min(self) -> $type {
+                    self.0.min(self.1).reduce_min()
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn reduce_max(self) -> $type {
+                    self.0.max(self.1).reduce_max()
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn reduce_and(self) -> $type {
+                    (self.0 & self.1).reduce_and()
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn reduce_or(self) -> $type {
+                    (self.0 | self.1).reduce_or()
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn reduce_xor(self) -> $type {
+                    (self.0 ^ self.1).reduce_xor()
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(
+    Int8x64, i8, 64; Uint8x64, u8, 64; Int16x32, i16, 32; Uint16x32, u16, 32;
+    Int32x16, i32, 16; Uint32x16, u32, 16; Int64x8, i64, 8; Uint64x8, u64, 8
+);
+
+impl Int8x64 {
+    binary!(min, max, mul_lo, saturating_add, saturating_sub);
+    unary!(abs, wrapping_neg);
+    unary_immediate!(shl, shr_l, shr_a);
+    unary_count!(shl_by, shr_l_by, shr_a_by);
+}
+
+impl Uint8x64 {
+    binary!(min, max, mul_lo, saturating_add, saturating_sub, avg);
+    unary!(low_nibbles, high_nibbles);
+    unary_immediate!(shl, shr_l);
+    unary_count!(shl_by, shr_l_by);
+
+    /// Replaces every element with `table[element]`. Elements which are out of range of the
+    /// table become zero.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup16(self, table: [u8; 16]) -> Self {
+        Self(self.0.lookup16(table), self.1.lookup16(table))
+    }
+
+    /// Same as `lookup16`, but with a 32-entry table.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup32(self, table: [u8; 32]) -> Self {
+        Self(self.0.lookup32(table), self.1.lookup32(table))
+    }
+
+    /// Same as `lookup16`, but with a 64-entry table.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup64(self, table: [u8; 64]) -> Self {
+        Self(self.0.lookup64(table), self.1.lookup64(table))
+    }
+
+    /// Multiplies unsigned lanes of `self` by signed lanes of `rhs` and adds adjacent pairs of the
+    /// products with signed saturation.
+    #[inline(always)]
+    #[must_use]
+    pub fn maddubs(self, rhs: Int8x64) -> Int16x32 {
+        Int16x32(self.0.maddubs(rhs.0), self.1.maddubs(rhs.1))
+    }
+
+    /// Multiplies unsigned lanes of `self` by signed lanes of `rhs` and adds the four products in
+    /// every 32-bit lane to `acc`, without intermediate saturation.
+    #[inline(always)]
+    #[must_use]
+    pub fn dot_accumulate(self, rhs: Int8x64, acc: Int32x16) -> Int32x16 {
+        Int32x16(self.0.dot_accumulate(rhs.0, acc.0), self.1.dot_accumulate(rhs.1, acc.1))
+    }
+
+    /// Applies the same pattern to both halves.
+    #[inline(always)]
+    #[must_use]
+    pub fn mpsadbw<const N: i32>(self, rhs: Self) -> Uint16x32 {
+        Uint16x32(self.0.mpsadbw::<N>(rhs.0), self.1.mpsadbw::<N>(rhs.1))
+    }
+}
+
+impl Int16x32 {
+    binary!(min, max, mul_lo, mul_hi, mul_hrs, saturating_add, saturating_sub);
+    binary!(hadd, hsub, saturating_hadd, saturating_hsub);
+    unary!(abs, wrapping_neg, swap_bytes);
+    unary_immediate!(
+        shl,
+        shr_l,
+        shr_a,
+        shuffle_lo_within_lanes,
+        shuffle_hi_within_lanes
+    );
+    unary_count!(shl_by, shr_l_by, shr_a_by);
+
+    /// Multiplies lanes into 32-bit products and adds adjacent pairs of them.
+    #[inline(always)]
+    #[must_use]
+    pub fn madd(self, rhs: Self) -> Int32x16 {
+        Int32x16(self.0.madd(rhs.0), self.1.madd(rhs.1))
+    }
+}
+
+impl Uint16x32 {
+    binary!(min, max, mul_lo, mul_hi, saturating_add, saturating_sub, avg, hadd, hsub);
+    unary!(swap_bytes);
+    unary_immediate!(shl, shr_l, shuffle_lo_within_lanes, shuffle_hi_within_lanes);
+    unary_count!(shl_by, shr_l_by);
+}
+
+impl Int32x16 {
+    binary!(min, max, mul_lo, hadd, hsub, extract_bits, deposit_bits);
+    unary!(abs, wrapping_neg, swap_bytes);
+    unary_immediate!(shl, shr_l, shr_a, shuffle_within_lanes);
+    unary_count!(shl_by, shr_l_by, shr_a_by);
+}
+
+impl Uint32x16 {
+    binary!(min, max, mul_lo, hadd, hsub, extract_bits, deposit_bits);
+    unary!(swap_bytes);
+    unary_immediate!(shl, shr_l, shuffle_within_lanes);
+    unary_count!(shl_by, shr_l_by);
+}
+
+impl Int64x8 {
+    binary!(min, max, mul_lo, extract_bits, deposit_bits);
+    unary!(abs, wrapping_neg, swap_bytes);
+    unary_immediate!(shl, shr_l, shr_a);
+    unary_count!(shl_by, shr_l_by, shr_a_by);
+}
+
+impl Uint64x8 {
+    binary!(min, max, mul_lo, extract_bits, deposit_bits);
+    unary!(swap_bytes);
+    unary_immediate!(shl, shr_l);
+    unary_count!(shl_by, shr_l_by);
+
+    /// Full 128-bit products, split into the low and the high 64 bits.
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_wide(self, rhs: Self) -> (Self, Self) {
+        let ((low_0, high_0), (low_1, high_1)) = (self.0.mul_wide(rhs.0), self.1.mul_wide(rhs.1));
+
+        (Self(low_0, low_1), Self(high_0, high_1))
+    }
+}
+
+macro_rules! impl_permute {
+    ($($name: ident => $half: ident),*) => {
+        $(
+            impl $name {
+                /// Sets lane `i` to the lane of `self` selected by the low 4 bits of lane `i` of
+                /// `indices`. Bit 3 selects the half, which costs a permute of both halves.
+                #[inline(always)]
+                #[must_use]
+                pub fn permute(self, indices: Int32x16) -> Self {
+                    let permute = |indices: Int32x8| {
+                        let high = (indices & Int32x8::splat(8)).eq(Int32x8::splat(8));
+
+                        $half::bitselect(
+                            high.transmute(),
+                            self.1.permute(indices),
+                            self.0.permute(indices),
+                        )
+                    };
+
+                    Self(permute(indices.0), permute(indices.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_permute!(Int32x16 => Int32x8, Uint32x16 => Uint32x8, Float32x16 => Float32x8);
+
+macro_rules! impl_shuffle_bytes {
+    ($($name: ident => $half: ident),*) => {
+        $(
+            impl $name {
+                /// Sets byte `i` to the byte of the same 128-bit lane selected by the low 4 bits of
+                /// byte `i` of `indices`, or to zero if its most significant bit is set.
+                #[inline(always)]
+                #[must_use]
+                pub fn shuffle_bytes(self, indices: Uint8x64) -> Self {
+                    Self(self.0.shuffle_bytes(indices.0), self.1.shuffle_bytes(indices.1))
+                }
+
+                /// Same as `shuffle_bytes`, but the low 6 bits of the indices select bytes from
+                /// the whole vector. Bit 5 selects the half, which costs a shuffle of both halves.
+                #[inline(always)]
+                #[must_use]
+                pub fn shuffle_bytes_crossing(self, indices: Uint8x64) -> Self {
+                    let shuffle = |indices: Uint8x32| {
+                        let high = (indices & Uint8x32::splat(32)).eq(Uint8x32::splat(32));
+
+                        $half::bitselect(
+                            high.transmute(),
+                            self.1.shuffle_bytes_crossing(indices),
+                            self.0.shuffle_bytes_crossing(indices),
+                        )
+                    };
+
+                    Self(shuffle(indices.0), shuffle(indices.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_shuffle_bytes!(Int8x64 => Int8x32, Uint8x64 => Uint8x32);
+
+macro_rules! impl_mul_even_widening {
+    ($($name: ident => $wide: ident),*) => {
+        $(
+            impl $name {
+                /// Full 64-bit products of the even lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn mul_even_widening(self, rhs: Self) -> $wide {
+                    $wide(self.0.mul_even_widening(rhs.0), self.1.mul_even_widening(rhs.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_mul_even_widening!(Int32x16 => Int64x8, Uint32x16 => Uint64x8);
+
+macro_rules! impl_blend {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                /// Applies the same pattern to both halves.
+                #[inline(always)]
+                #[must_use]
+                pub fn blend<const N: i32>(self, rhs: Self) -> Self {
+                    Self(self.0.blend::<N>(rhs.0), self.1.blend::<N>(rhs.1))
+                }
+            }
+        )*
+    };
+}
+
+impl_blend!(
+    Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8
+);
+
+macro_rules! impl_pack {
+    ($($name: ident => $signed: ident, $unsigned: ident),*) => {
+        $(
+            impl $name {
+                /// Packs the low halves of `self` and `rhs` into the low half of the result and
+                /// the high halves into the high half.
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating(self, rhs: Self) -> $signed {
+                    $signed(self.0.pack_saturating(rhs.0), self.1.pack_saturating(rhs.1))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating_unsigned(self, rhs: Self) -> $unsigned {
+                    $unsigned(
+                        self.0.pack_saturating_unsigned(rhs.0),
+                        self.1.pack_saturating_unsigned(rhs.1),
+                    )
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn narrow_saturating(self, rhs: Self) -> $signed {
+                    $signed(self.0.narrow_saturating(self.1), rhs.0.narrow_saturating(rhs.1))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn narrow_saturating_unsigned(self, rhs: Self) -> $unsigned {
+                    $unsigned(
+                        self.0.narrow_saturating_unsigned(self.1),
+                        rhs.0.narrow_saturating_unsigned(rhs.1),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_pack!(Int16x32 => Int8x64, Uint8x64, Int32x16 => Int16x32, Uint16x32);
+
+macro_rules! impl_interleave {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                binary!(interleave_lo, interleave_hi);
+
+                /// Interleaves all lanes of `self` and `rhs`, the first half of the result is
+                /// [a0, b0, a1, b1, ...] and the second half continues from the middle lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn interleave(self, rhs: Self) -> (Self, Self) {
+                    let (a, b) = self.0.interleave(rhs.0);
+                    let (c, d) = self.1.interleave(rhs.1);
+
+                    (Self(a, b), Self(c, d))
+                }
+            }
+        )*
+    };
+}
+
+impl_interleave!(
+    Float32x16, Float64x8, Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8,
+    Uint64x8
+);
+
+impl_operator!(Int8x64, Mul, mul);
+impl_operator!(Uint8x64, Mul, mul);
+impl_operator!(Int16x32, Mul, mul);
+impl_operator!(Uint16x32, Mul, mul);
+impl_operator!(Int32x16, Mul, mul);
+impl_operator!(Uint32x16, Mul, mul);
+impl_operator!(Int64x8, Mul, mul);
+impl_operator!(Uint64x8, Mul, mul);
+
+impl_shift_operator!(Int8x64, Shl, shl, Shr, shr);
+impl_shift_operator!(Uint8x64, Shl, shl, Shr, shr);
+impl_shift_operator!(Int16x32, Shl, shl, Shr, shr);
+impl_shift_operator!(Uint16x32, Shl, shl, Shr, shr);
+impl_shift_operator!(Int32x16, Shl, shl, Shr, shr);
+impl_shift_operator!(Uint32x16, Shl, shl, Shr, shr);
+impl_shift_operator!(Int64x8, Shl, shl, Shr, shr);
+impl_shift_operator!(Uint64x8, Shl, shl, Shr, shr);
+
+impl_operator!(Int8x64, Div, div, Rem, rem);
+impl_operator!(Uint8x64, Div, div, Rem, rem);
+impl_operator!(Int16x32, Div, div, Rem, rem);
+impl_operator!(Uint16x32, Div, div, Rem, rem);
+impl_operator!(Int32x16, Div, div, Rem, rem);
+impl_operator!(Uint32x16, Div, div, Rem, rem);
+impl_operator!(Int64x8, Div, div, Rem, rem);
+impl_operator!(Uint64x8, Div, div, Rem, rem);
+
+macro_rules! impl_neg {
+    ($($name: ident),*) => {
+        $(
+            impl ops::Neg for $name {
+                type Output = Self;
+
+                #[inline(always)]
+                fn neg(self) -> Self {
+                    self.wrapping_neg()
+                }
+            }
+        )*
+    };
+}
+
+impl_neg!(Int8x64, Int16x32, Int32x16, Int64x8);
+
+macro_rules! impl_abs_diff {
+    ($($signed: ident => $unsigned: ident),*) => {
+        $(
+            impl $signed {
+                #[inline(always)]
+                #[must_use]
+                pub fn abs_diff(self, rhs: Self) -> $unsigned {
+                    $unsigned(self.0.abs_diff(rhs.0), self.1.abs_diff(rhs.1))
+                }
+            }
+
+            impl $unsigned {
+                binary!(abs_diff);
+            }
+        )*
+    };
+}
+
+impl_abs_diff!(
+    Int8x64 => Uint8x64,
+    Int16x32 => Uint16x32,
+    Int32x16 => Uint32x16,
+    Int64x8 => Uint64x8
+);
+
+impl Uint8x64 {
+    /// Sums of absolute differences of every eight consecutive lanes.
+    #[inline(always)]
+    #[must_use]
+    pub fn sad(self, rhs: Self) -> Uint64x8 {
+        Uint64x8(self.0.sad(rhs.0), self.1.sad(rhs.1))
+    }
+}
+
+macro_rules! impl_conversion {
+    ($($from: ident => $to: ident),*) => {
+        $(
+            impl VectorConvertInto<$to> for $from {
+                #[inline(always)]
+                fn convert_vector(self) -> $to {
+                    $to(self.0.convert(), self.1.convert())
+                }
+            }
+        )*
+    };
+}
+
+impl_conversion!(
+    Int8x64 => Uint8x64,
+    Uint8x64 => Int8x64,
+    Int16x32 => Uint16x32,
+    Uint16x32 => Int16x32,
+    Int32x16 => Uint32x16,
+    Uint32x16 => Int32x16,
+    Int64x8 => Uint64x8,
+    Uint64x8 => Int64x8,
+    Float32x16 => Int32x16,
+    Int32x16 => Float32x16
+);
+
+macro_rules! impl_signedness_casts {
+    ($($signed: ident, $unsigned: ident);*) => {
+        $(
+            impl From<$signed> for $unsigned {
+                #[inline(always)]
+                fn from(x: $signed) -> Self {
+                    x.convert()
+                }
+            }
+
+            impl From<$unsigned> for $signed {
+                #[inline(always)]
+                fn from(x: $unsigned) -> Self {
+                    x.convert()
+                }
+            }
+        )*
+    };
+}
+
+impl_signedness_casts!(
+    Int8x64, Uint8x64; Int16x32, Uint16x32; Int32x16, Uint32x16; Int64x8, Uint64x8
+);
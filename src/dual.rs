@@ -0,0 +1,138 @@
+use core::ops;
+
+use crate::util::select;
+use crate::Float32x8;
+
+/// Eight dual numbers `value + derivative * ε` (where ε² = 0) for forward-mode automatic
+/// differentiation. Evaluating a formula on [`DualF32x8::variable`] inputs computes its
+/// derivative alongside the value.
+#[derive(Copy, Clone, Debug)]
+pub struct DualF32x8 {
+    pub value: Float32x8,
+    pub derivative: Float32x8,
+}
+
+impl DualF32x8 {
+    #[inline(always)]
+    #[must_use]
+    pub fn new(value: Float32x8, derivative: Float32x8) -> Self {
+        Self { value, derivative }
+    }
+
+    /// Values which don't depend on the differentiation variable.
+    #[inline(always)]
+    #[must_use]
+    pub fn constant(value: Float32x8) -> Self {
+        Self::new(value, Float32x8::zero())
+    }
+
+    /// Values of the differentiation variable itself.
+    #[inline(always)]
+    #[must_use]
+    pub fn variable(value: Float32x8) -> Self {
+        Self::new(value, Float32x8::splat(1.0))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        let value = self.value.sqrt();
+
+        Self::new(value, self.derivative / (value + value))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn recip(self) -> Self {
+        let value = Float32x8::splat(1.0) / self.value;
+
+        Self::new(value, Float32x8::zero() - self.derivative * value * value)
+    }
+
+    /// Derivative at 0 is taken from the positive side.
+    #[inline(always)]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        let sign = Float32x8::splat(-0.0);
+        let negative = self.value.lt(Float32x8::zero());
+
+        Self::new(sign.andnot(self.value), self.derivative ^ (negative & sign))
+    }
+
+    /// Derivative is taken from `self` when values are equal.
+    #[inline(always)]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        let pick_self = self.value.le(rhs.value);
+
+        Self::new(
+            self.value.min(rhs.value),
+            select(pick_self, self.derivative, rhs.derivative),
+        )
+    }
+
+    /// Derivative is taken from `self` when values are equal.
+    #[inline(always)]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        let pick_self = self.value.ge(rhs.value);
+
+        Self::new(
+            self.value.max(rhs.value),
+            select(pick_self, self.derivative, rhs.derivative),
+        )
+    }
+}
+
+impl ops::Add for DualF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl ops::Sub for DualF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl ops::Mul for DualF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.value * rhs.value,
+            self.derivative * rhs.value + self.value * rhs.derivative,
+        )
+    }
+}
+
+impl ops::Div for DualF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self {
+        let value = self.value / rhs.value;
+
+        Self::new(
+            value,
+            (self.derivative - value * rhs.derivative) / rhs.value,
+        )
+    }
+}
+
+impl ops::Mul<Float32x8> for DualF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Float32x8) -> Self {
+        Self::new(self.value * rhs, self.derivative * rhs)
+    }
+}
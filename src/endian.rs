@@ -0,0 +1,149 @@
+macro_rules! impl_endian_loads {
+    ($name: ty, $width: literal) => {
+        impl $name {
+            /// Loads elements stored in little-endian byte order. Panics if `bytes` is shorter
+            /// than the vector.
+            #[inline(always)]
+            #[must_use]
+            pub fn from_slice_le(bytes: &[u8]) -> Self {
+                let x = Self::from_bytes(bytes[..$width / 8].try_into().unwrap());
+
+                if cfg!(target_endian = "little") {
+                    x
+                } else {
+                    x.swap_bytes()
+                }
+            }
+
+            /// Loads elements stored in big-endian byte order. Panics if `bytes` is shorter
+            /// than the vector.
+            #[inline(always)]
+            #[must_use]
+            pub fn from_slice_be(bytes: &[u8]) -> Self {
+                let x = Self::from_bytes(bytes[..$width / 8].try_into().unwrap());
+
+                if cfg!(target_endian = "big") {
+                    x
+                } else {
+                    x.swap_bytes()
+                }
+            }
+
+            /// Stores elements in little-endian byte order. Panics if `bytes` is shorter than
+            /// the vector.
+            #[inline(always)]
+            pub fn write_to_slice_le(self, bytes: &mut [u8]) {
+                let x = if cfg!(target_endian = "little") {
+                    self
+                } else {
+                    self.swap_bytes()
+                };
+
+                bytes[..$width / 8].copy_from_slice(&x.to_bytes());
+            }
+
+            /// Stores elements in big-endian byte order. Panics if `bytes` is shorter than
+            /// the vector.
+            #[inline(always)]
+            pub fn write_to_slice_be(self, bytes: &mut [u8]) {
+                let x = if cfg!(target_endian = "big") {
+                    self
+                } else {
+                    self.swap_bytes()
+                };
+
+                bytes[..$width / 8].copy_from_slice(&x.to_bytes());
+            }
+        }
+    };
+}
+
+macro_rules! impl_endian_conversions {
+    ($name: ty) => {
+        impl $name {
+            /// Converts every lane to big-endian byte order, which swaps its bytes on
+            /// little-endian targets.
+            #[inline(always)]
+            #[must_use]
+            pub fn to_be(self) -> Self {
+                if cfg!(target_endian = "big") {
+                    self
+                } else {
+                    self.swap_bytes()
+                }
+            }
+
+            /// Converts every lane to little-endian byte order, which swaps its bytes on
+            /// big-endian targets.
+            #[inline(always)]
+            #[must_use]
+            pub fn to_le(self) -> Self {
+                if cfg!(target_endian = "little") {
+                    self
+                } else {
+                    self.swap_bytes()
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_endian_loads_for_backend {
+    ($($backend: ident)::*) => {
+        impl_endian_loads!($($backend)::*::Float32x4, 128);
+        impl_endian_loads!($($backend)::*::Float64x2, 128);
+
+        impl_endian_loads!($($backend)::*::Int16x8, 128);
+        impl_endian_loads!($($backend)::*::Uint16x8, 128);
+        impl_endian_loads!($($backend)::*::Int32x4, 128);
+        impl_endian_loads!($($backend)::*::Uint32x4, 128);
+        impl_endian_loads!($($backend)::*::Int64x2, 128);
+        impl_endian_loads!($($backend)::*::Uint64x2, 128);
+
+        impl_endian_loads!($($backend)::*::Float32x8, 256);
+        impl_endian_loads!($($backend)::*::Float64x4, 256);
+
+        impl_endian_loads!($($backend)::*::Int16x16, 256);
+        impl_endian_loads!($($backend)::*::Uint16x16, 256);
+        impl_endian_loads!($($backend)::*::Int32x8, 256);
+        impl_endian_loads!($($backend)::*::Uint32x8, 256);
+        impl_endian_loads!($($backend)::*::Int64x4, 256);
+        impl_endian_loads!($($backend)::*::Uint64x4, 256);
+
+        impl_endian_conversions!($($backend)::*::Int16x8);
+        impl_endian_conversions!($($backend)::*::Uint16x8);
+        impl_endian_conversions!($($backend)::*::Int32x4);
+        impl_endian_conversions!($($backend)::*::Uint32x4);
+        impl_endian_conversions!($($backend)::*::Int64x2);
+        impl_endian_conversions!($($backend)::*::Uint64x2);
+
+        impl_endian_conversions!($($backend)::*::Int16x16);
+        impl_endian_conversions!($($backend)::*::Uint16x16);
+        impl_endian_conversions!($($backend)::*::Int32x8);
+        impl_endian_conversions!($($backend)::*::Uint32x8);
+        impl_endian_conversions!($($backend)::*::Int64x4);
+        impl_endian_conversions!($($backend)::*::Uint64x4);
+    };
+}
+
+impl_endian_loads_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_endian_loads_for_backend!(crate::reference);
+
+impl_endian_loads!(crate::Float32x16, 512);
+impl_endian_loads!(crate::Float64x8, 512);
+
+impl_endian_loads!(crate::Int16x32, 512);
+impl_endian_loads!(crate::Uint16x32, 512);
+impl_endian_loads!(crate::Int32x16, 512);
+impl_endian_loads!(crate::Uint32x16, 512);
+impl_endian_loads!(crate::Int64x8, 512);
+impl_endian_loads!(crate::Uint64x8, 512);
+
+impl_endian_conversions!(crate::Int16x32);
+impl_endian_conversions!(crate::Uint16x32);
+impl_endian_conversions!(crate::Int32x16);
+impl_endian_conversions!(crate::Uint32x16);
+impl_endian_conversions!(crate::Int64x8);
+impl_endian_conversions!(crate::Uint64x8);
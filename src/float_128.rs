@@ -0,0 +1,139 @@
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+use crate::arch::*;
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::slice_assume_init_mut;
+
+make_vector_type!(
+    Float32x4,
+    f32,
+    4,
+    __m128,
+    __m128i,
+    128,
+    _mm,
+    si128,
+    ps,
+    _mm_castsi128_ps,
+    _mm_castps_si128
+);
+make_vector_type!(
+    Float64x2,
+    f64,
+    2,
+    __m128d,
+    __m128i,
+    128,
+    _mm,
+    si128,
+    pd,
+    _mm_castsi128_pd,
+    _mm_castpd_si128
+);
+
+impl_interleave!(128:
+    Float32x4 => _mm_unpacklo_ps, _mm_unpackhi_ps,
+    Float64x2 => _mm_unpacklo_pd, _mm_unpackhi_pd
+);
+
+impl Float32x4 {
+    #[inline(always)]
+    #[must_use]
+    pub fn broadcast_from(v: &f32) -> Self {
+        Self::splat(*v)
+    }
+
+    /// Approximate reciprocal square root, see `Float32x8::rsqrt`.
+    pub fn rsqrt(self) -> Self {
+        unsafe { Self(_mm_rsqrt_ps(self.0)) }
+    }
+
+    /// Every 2 bits of PATTERN select the source element for the corresponding destination
+    /// element. The same selection is applied to every 128-bit lane.
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle<const PATTERN: i32>(self) -> Self {
+        unsafe { Self(_mm_shuffle_ps::<PATTERN>(self.0, self.0)) }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        self.transmute::<crate::Uint32x4>().swap_bytes().transmute()
+    }
+
+    /// [a0, a0, a2, a2]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_even(self) -> Self {
+        unsafe { Self(_mm_moveldup_ps(self.0)) }
+    }
+
+    /// [a1, a1, a3, a3]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_odd(self) -> Self {
+        unsafe { Self(_mm_movehdup_ps(self.0)) }
+    }
+}
+
+impl Float64x2 {
+    #[inline(always)]
+    #[must_use]
+    pub fn broadcast_from(v: &f64) -> Self {
+        Self::splat(*v)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        self.transmute::<crate::Uint64x2>().swap_bytes().transmute()
+    }
+
+    /// [a0, a0]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_even(self) -> Self {
+        unsafe { Self(_mm_movedup_pd(self.0)) }
+    }
+
+    /// [a1, a1]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_odd(self) -> Self {
+        unsafe { Self(_mm_unpackhi_pd(self.0, self.0)) }
+    }
+}
+
+impl VectorConvertInto<crate::Int32x4> for Float32x4 {
+    #[inline(always)]
+    fn convert_vector(self) -> crate::Int32x4 {
+        unsafe { crate::Int32x4(_mm_cvtps_epi32(self.0)) }
+    }
+}
+
+impl VectorConvertInto<crate::Float64x4> for Float32x4 {
+    #[inline(always)]
+    fn convert_vector(self) -> crate::Float64x4 {
+        unsafe { crate::Float64x4(_mm256_cvtps_pd(self.0)) }
+    }
+}
+
+impl VectorConvertInto<Float32x4> for crate::Float64x4 {
+    /// Rounds to nearest even.
+    #[inline(always)]
+    fn convert_vector(self) -> Float32x4 {
+        unsafe { Float32x4(_mm256_cvtpd_ps(self.0)) }
+    }
+}
+
+impl VectorConvertInto<crate::Int32x4> for crate::Float64x4 {
+    /// Rounds to nearest even. Lanes which are NaN or out of range become `i32::MIN`.
+    #[inline(always)]
+    fn convert_vector(self) -> crate::Int32x4 {
+        unsafe { crate::Int32x4(_mm256_cvtpd_epi32(self.0)) }
+    }
+}
@@ -1,10 +1,11 @@
-use std::arch::x86_64::*;
-use std::mem::MaybeUninit;
-use std::{fmt, ops};
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
 
 use paste::paste;
 
-use crate::conversion::{VectorConvertInto, VectorTransmuteInto};
+use crate::arch::*;
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::slice_assume_init_mut;
 
 macro_rules! impl_operator {
     ($name: ident, $op: ident, $op_function: ident, $function: item) => {
@@ -12,7 +13,6 @@ macro_rules! impl_operator {
             type Output = Self;
 
             #[inline(always)]
-            #[must_use]
             $function
         }
 
@@ -28,14 +28,17 @@ macro_rules! impl_operator {
 }
 
 macro_rules! make_vector_type {
-    ($name: ident, $type: ty, $lanes: expr, $avx_type: ty, $postfix: ident) => {
+    (
+        $name: ident, $type: ty, $lanes: expr, $avx_type: ty, $integer_type: ty, $width: expr,
+        $prefix: ident, $si: ident, $postfix: ident, $from_integer: ident, $to_integer: ident
+    ) => {
         #[derive(Copy, Clone)]
         #[repr(transparent)]
         pub struct $name(pub(crate) $avx_type);
 
         macro_rules! intrinsic {
             ($function: ident) => {
-                paste! { [< $function _ $postfix>] }
+                paste! { [<$prefix $function _ $postfix>] }
             };
         }
 
@@ -46,17 +49,45 @@ macro_rules! make_vector_type {
                 pub fn $comparison_name(self, rhs: Self) -> Self {
                     unsafe {
                         paste! {
-                            Self([<_mm256_cmp _ $postfix>]::<$comparison_constant>(self.0, rhs.0))
+                            Self([<$prefix _cmp_ $postfix>]::<$comparison_constant>(self.0, rhs.0))
                         }
                     }
                 }
             };
         }
 
+        impl VectorBits for $name {
+            type Bits = $integer_type;
+
+            #[inline(always)]
+            fn from_bits(x: $integer_type) -> Self {
+                unsafe { Self($from_integer(x)) }
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> $integer_type {
+                unsafe { $to_integer(self.0) }
+            }
+        }
+
+        impl From<$avx_type> for $name {
+            #[inline(always)]
+            fn from(x: $avx_type) -> Self {
+                Self(x)
+            }
+        }
+
+        impl From<$name> for $avx_type {
+            #[inline(always)]
+            fn from(x: $name) -> Self {
+                x.0
+            }
+        }
+
         impl $name {
             fn _size_check() {
                 unsafe {
-                    std::mem::transmute::<[$type; $lanes], [u8; 256 / 8]>([0.0; $lanes]);
+                    core::mem::transmute::<[$type; $lanes], [u8; $width / 8]>([0.0; $lanes]);
                 }
             }
 
@@ -72,19 +103,19 @@ macro_rules! make_vector_type {
             #[inline(always)]
             #[must_use]
             pub fn zero() -> Self {
-                unsafe { Self(intrinsic!(_mm256_setzero)()) }
+                unsafe { Self(intrinsic!(_setzero)()) }
             }
 
             #[inline(always)]
             #[must_use]
             pub fn splat(v: $type) -> Self {
-                unsafe { Self(intrinsic!(_mm256_set1)(v)) }
+                unsafe { Self(intrinsic!(_set1)(v)) }
             }
 
             #[inline(always)]
             #[must_use]
             pub fn from_array(array: [$type; $lanes]) -> Self {
-                unsafe { Self(intrinsic!(_mm256_loadu)(array.as_ptr() as *const _)) }
+                unsafe { Self(intrinsic!(_loadu)(array.as_ptr() as *const _)) }
             }
 
             #[inline(always)]
@@ -92,36 +123,167 @@ macro_rules! make_vector_type {
             pub fn to_array(self) -> [$type; $lanes] {
                 unsafe {
                     let mut array: MaybeUninit<[$type; $lanes]> = MaybeUninit::uninit();
-                    intrinsic!(_mm256_storeu)(array.as_mut_ptr() as *mut _, self.0);
+                    intrinsic!(_storeu)(array.as_mut_ptr() as *mut _, self.0);
                     array.assume_init()
                 }
             }
 
+            #[inline(always)]
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; $width / 8]) -> Self {
+                unsafe { Self(intrinsic!(_loadu)(bytes.as_ptr() as *const _)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_bytes(self) -> [u8; $width / 8] {
+                unsafe {
+                    let mut bytes: MaybeUninit<[u8; $width / 8]> = MaybeUninit::uninit();
+                    intrinsic!(_storeu)(bytes.as_mut_ptr() as *mut _, self.0);
+                    bytes.assume_init()
+                }
+            }
+
+            /// Stores all lanes at the beginning of `slice` and returns the initialized part.
+            /// Panics if `slice` is shorter than the vector.
+            #[inline(always)]
+            pub fn store_into_uninit(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                let slice = &mut slice[..$lanes];
+
+                unsafe {
+                    intrinsic!(_storeu)(slice.as_mut_ptr() as *mut _, self.0);
+                    slice_assume_init_mut(slice)
+                }
+            }
+
+            /// Stores as many lanes as fit in `slice` and returns the initialized part.
+            #[inline(always)]
+            pub fn write_to_uninit_slice(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                if slice.len() >= $lanes {
+                    return self.store_into_uninit(slice);
+                }
+
+                for (element, x) in slice.iter_mut().zip(self.to_array()) {
+                    element.write(x);
+                }
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Initializes every element of `slice` to `v`.
+            #[inline(always)]
+            pub fn fill_uninit(slice: &mut [MaybeUninit<$type>], v: $type) -> &mut [$type] {
+                let vector = Self::splat(v);
+
+                let mut chunks = slice.chunks_exact_mut($lanes);
+                for chunk in &mut chunks {
+                    vector.store_into_uninit(chunk);
+                }
+                vector.write_to_uninit_slice(chunks.into_remainder());
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Loads a vector from `ptr` using a volatile read, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for reads and aligned to the element size.
+            #[inline(always)]
+            #[must_use]
+            pub unsafe fn load_volatile(ptr: *const [$type; $lanes]) -> Self {
+                Self::from_array(ptr.read_volatile())
+            }
+
+            /// Stores the vector to `ptr` using a volatile write, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for writes and aligned to the element size.
+            #[inline(always)]
+            pub unsafe fn store_volatile(self, ptr: *mut [$type; $lanes]) {
+                ptr.write_volatile(self.to_array());
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn as_raw(self) -> $avx_type {
+                self.0
+            }
+
             /// Set each bit of mask based on the most significant bit of the corresponding packed
             /// floating-point element.
             #[inline(always)]
             #[must_use]
             pub fn mask(self) -> u32 {
-                unsafe { intrinsic!(_mm256_movemask)(self.0) as u32 }
+                unsafe { intrinsic!(_movemask)(self.0) as u32 }
+            }
+
+            /// Returns true if all bits are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                unsafe { paste! { [<$prefix _testz_ $si>](self.to_bits(), self.to_bits()) != 0 } }
+            }
+
+            /// Returns true if all bits are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_ones(self) -> bool {
+                unsafe {
+                    paste! {
+                        [<$prefix _testc_ $si>](self.to_bits(), [<$prefix _set1_epi8>](-1)) != 0
+                    }
+                }
+            }
+
+            /// Same as `is_zero`, named to pair with `is_all_ones`.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            /// Returns true if any bits of `self` selected by `mask` are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn test(self, mask: Self) -> bool {
+                unsafe { paste! { [<$prefix _testz_ $si>](self.to_bits(), mask.to_bits()) == 0 } }
+            }
+
+            /// Returns true if bits of `self` selected by `mask` are neither all zeros nor all ones.
+            #[inline(always)]
+            #[must_use]
+            pub fn test_mixed(self, mask: Self) -> bool {
+                unsafe { paste! { [<$prefix _testnzc_ $si>](self.to_bits(), mask.to_bits()) != 0 } }
             }
 
             /// ~self & rhs
             #[inline(always)]
             #[must_use]
             pub fn andnot(self, rhs: Self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_andnot)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_andnot)(self.0, rhs.0)) }
+            }
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                (mask & if_set) | mask.andnot(if_clear)
             }
 
             #[inline(always)]
             #[must_use]
             pub fn min(self, rhs: Self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_min)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_min)(self.0, rhs.0)) }
             }
 
             #[inline(always)]
             #[must_use]
             pub fn max(self, rhs: Self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_max)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_max)(self.0, rhs.0)) }
             }
 
             #[inline(always)]
@@ -129,7 +291,7 @@ macro_rules! make_vector_type {
             pub fn blend<const I: i32>(self, rhs: Self) -> Self {
                 unsafe {
                     paste! {
-                        Self([<_mm256_blend _ $postfix>]::<I>(self.0, rhs.0))
+                        Self([<$prefix _blend_ $postfix>]::<I>(self.0, rhs.0))
                     }
                 }
             }
@@ -137,13 +299,13 @@ macro_rules! make_vector_type {
             #[inline(always)]
             #[must_use]
             pub fn floor(self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_floor)(self.0)) }
+                unsafe { Self(intrinsic!(_floor)(self.0)) }
             }
 
             #[inline(always)]
             #[must_use]
             pub fn ceil(self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_ceil)(self.0)) }
+                unsafe { Self(intrinsic!(_ceil)(self.0)) }
             }
 
             #[inline(always)]
@@ -152,7 +314,7 @@ macro_rules! make_vector_type {
                 // _MM_FROUND_TO_ZERO |_MM_FROUND_NO_EXC
                 unsafe {
                     paste! {
-                        Self([<_mm256_round _ $postfix>]::<0x0b>(self.0))
+                        Self([<$prefix _round_ $postfix>]::<0x0b>(self.0))
                     }
                 }
             }
@@ -163,7 +325,7 @@ macro_rules! make_vector_type {
                 // _MM_FROUND_TO_NEAREST_INT |_MM_FROUND_NO_EXC
                 unsafe {
                     paste! {
-                        Self([<_mm256_round _ $postfix>]::<0x08>(self.0))
+                        Self([<$prefix _round_ $postfix>]::<0x08>(self.0))
                     }
                 }
             }
@@ -171,7 +333,7 @@ macro_rules! make_vector_type {
             #[inline(always)]
             #[must_use]
             pub fn sqrt(self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_sqrt)(self.0)) }
+                unsafe { Self(intrinsic!(_sqrt)(self.0)) }
             }
 
             /// (self * b) + c
@@ -179,7 +341,7 @@ macro_rules! make_vector_type {
             #[inline(always)]
             #[must_use]
             pub fn fmadd(self, b: Self, c: Self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_fmadd)(self.0, b.0, c.0)) }
+                unsafe { Self(intrinsic!(_fmadd)(self.0, b.0, c.0)) }
             }
 
             /// (self * b) - c
@@ -187,7 +349,7 @@ macro_rules! make_vector_type {
             #[inline(always)]
             #[must_use]
             pub fn fmsub(self, b: Self, c: Self) -> Self {
-                unsafe { Self(intrinsic!(_mm256_fmsub)(self.0, b.0, c.0)) }
+                unsafe { Self(intrinsic!(_fmsub)(self.0, b.0, c.0)) }
             }
 
             #[inline(always)]
@@ -211,43 +373,58 @@ macro_rules! make_vector_type {
 
         impl_operator! { $name, Add, add,
             fn add(self, rhs: Self) -> Self::Output {
-                unsafe { Self(intrinsic!(_mm256_add)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_add)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, Sub, sub,
             fn sub(self, rhs: Self) -> Self::Output {
-                unsafe { Self(intrinsic!(_mm256_sub)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_sub)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, Mul, mul,
             fn mul(self, rhs: Self) -> Self::Output {
-                unsafe { Self(intrinsic!(_mm256_mul)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_mul)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, Div, div,
             fn div(self, rhs: Self) -> Self::Output {
-                unsafe { Self(intrinsic!(_mm256_div)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_div)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, BitAnd, bitand,
             fn bitand(self, rhs: Self) -> Self::Output {
-                unsafe { Self(intrinsic!(_mm256_and)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_and)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, BitOr, bitor,
             fn bitor(self, rhs: Self) -> Self::Output {
-                unsafe { Self(intrinsic!(_mm256_or)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_or)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, BitXor, bitxor,
             fn bitxor(self, rhs: Self) -> Self::Output {
-                unsafe { Self(intrinsic!(_mm256_xor)(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_xor)(self.0, rhs.0)) }
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                unsafe {
+                    paste! {
+                        let ones = [<$prefix _set1_epi8>](-1);
+
+                        Self::from_bits([<$prefix _xor_ $si>](self.to_bits(), ones))
+                    }
+                }
             }
         }
 
@@ -259,18 +436,275 @@ macro_rules! make_vector_type {
     };
 }
 
-make_vector_type!(Float32x8, f32, 8, __m256, ps);
-make_vector_type!(Float64x4, f64, 4, __m256d, pd);
+macro_rules! impl_broadcasts {
+    (
+        $name: ident, $type: ty, $lanes: expr,
+        $broadcast: ident, $broadcast_128: ident, $load_128: ident
+    ) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_from(v: &$type) -> Self {
+                unsafe { Self($broadcast(v)) }
+            }
+
+            /// Load 128 bits from memory into both 128-bit lanes.
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_128_from(array: &[$type; $lanes / 2]) -> Self {
+                unsafe { Self($broadcast_128(&$load_128(array.as_ptr()))) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_halves {
+    (
+        $name: ident, $half: ident,
+        $cast: ident, $cast_back: ident, $extract: ident, $insert: ident, $permute: ident
+    ) => {
+        impl $name {
+            /// Lower 128 bits.
+            #[inline(always)]
+            #[must_use]
+            pub fn low(self) -> crate::$half {
+                unsafe { crate::$half($cast(self.0)) }
+            }
+
+            /// Upper 128 bits.
+            #[inline(always)]
+            #[must_use]
+            pub fn high(self) -> crate::$half {
+                unsafe { crate::$half($extract::<1>(self.0)) }
+            }
+
+            /// Concatenates two 128-bit vectors, `low` goes into the lower 128 bits.
+            #[inline(always)]
+            #[must_use]
+            pub fn combine(low: crate::$half, high: crate::$half) -> Self {
+                unsafe { Self($insert::<1>($cast_back(low.0), high.0)) }
+            }
+
+            /// Swaps the lower and the upper 128 bits.
+            #[inline(always)]
+            #[must_use]
+            pub fn swap_halves(self) -> Self {
+                unsafe { Self($permute::<0x01>(self.0, self.0)) }
+            }
+
+            /// Copies the lower 128 bits into the upper 128 bits.
+            #[inline(always)]
+            #[must_use]
+            pub fn duplicate_low_half(self) -> Self {
+                unsafe { Self($permute::<0x00>(self.0, self.0)) }
+            }
+
+            /// Copies the upper 128 bits into the lower 128 bits.
+            #[inline(always)]
+            #[must_use]
+            pub fn duplicate_high_half(self) -> Self {
+                unsafe { Self($permute::<0x11>(self.0, self.0)) }
+            }
+        }
+    };
+}
+
+make_vector_type!(
+    Float32x8,
+    f32,
+    8,
+    __m256,
+    __m256i,
+    256,
+    _mm256,
+    si256,
+    ps,
+    _mm256_castsi256_ps,
+    _mm256_castps_si256
+);
+make_vector_type!(
+    Float64x4,
+    f64,
+    4,
+    __m256d,
+    __m256i,
+    256,
+    _mm256,
+    si256,
+    pd,
+    _mm256_castsi256_pd,
+    _mm256_castpd_si256
+);
+
+impl_broadcasts!(Float32x8, f32, 8, _mm256_broadcast_ss, _mm256_broadcast_ps, _mm_loadu_ps);
+impl_broadcasts!(Float64x4, f64, 4, _mm256_broadcast_sd, _mm256_broadcast_pd, _mm_loadu_pd);
+
+impl_halves!(
+    Float32x8,
+    Float32x4,
+    _mm256_castps256_ps128,
+    _mm256_castps128_ps256,
+    _mm256_extractf128_ps,
+    _mm256_insertf128_ps,
+    _mm256_permute2f128_ps
+);
+impl_halves!(
+    Float64x4,
+    Float64x2,
+    _mm256_castpd256_pd128,
+    _mm256_castpd128_pd256,
+    _mm256_extractf128_pd,
+    _mm256_insertf128_pd,
+    _mm256_permute2f128_pd
+);
+
+macro_rules! impl_interleave {
+    (256: $($name: ident => $lo: ident, $hi: ident),*) => {
+        $(
+            impl_interleave!(@lanes $name, $lo, $hi);
+
+            impl $name {
+                /// Interleaves all lanes of `self` and `rhs`, the first half of the result is
+                /// [a0, b0, a1, b1, ...] and the second half continues from the middle lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn interleave(self, rhs: Self) -> (Self, Self) {
+                    let (lo, hi) = (self.interleave_lo(rhs), self.interleave_hi(rhs));
+
+                    (Self::combine(lo.low(), hi.low()), Self::combine(lo.high(), hi.high()))
+                }
+            }
+        )*
+    };
+
+    (128: $($name: ident => $lo: ident, $hi: ident),*) => {
+        $(
+            impl_interleave!(@lanes $name, $lo, $hi);
+
+            impl $name {
+                /// Interleaves all lanes of `self` and `rhs`, the first half of the result is
+                /// [a0, b0, a1, b1, ...] and the second half continues from the middle lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn interleave(self, rhs: Self) -> (Self, Self) {
+                    (self.interleave_lo(rhs), self.interleave_hi(rhs))
+                }
+            }
+        )*
+    };
+
+    (@lanes $name: ident, $lo: ident, $hi: ident) => {
+        impl $name {
+            /// Interleaves lanes from the lower halves of every 128-bit lane of `self` and
+            /// `rhs`: [a0, b0, a1, b1, ...].
+            #[inline(always)]
+            #[must_use]
+            pub fn interleave_lo(self, rhs: Self) -> Self {
+                unsafe { Self($lo(self.0, rhs.0)) }
+            }
+
+            /// Same as `interleave_lo`, but with the upper halves of every 128-bit lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn interleave_hi(self, rhs: Self) -> Self {
+                unsafe { Self($hi(self.0, rhs.0)) }
+            }
+        }
+    };
+}
+
+impl_interleave!(256:
+    Float32x8 => _mm256_unpacklo_ps, _mm256_unpackhi_ps,
+    Float64x4 => _mm256_unpacklo_pd, _mm256_unpackhi_pd
+);
 
 impl Float32x8 {
+    /// Approximate reciprocal square root with relative error of at most 1.5 * 2^-12. The exact
+    /// result depends on the CPU and denormal inputs are treated as zero, so unlike other methods
+    /// it differs from the scalar implementation, which computes `1 / sqrt(x)` exactly.
     pub fn rsqrt(self) -> Self {
         unsafe { Self(_mm256_rsqrt_ps(self.0)) }
     }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        self.transmute::<crate::Uint32x8>().swap_bytes().transmute()
+    }
+
+    /// Every 2 bits of PATTERN select the source element for the corresponding destination
+    /// element. The same selection is applied to every 128-bit lane.
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle<const PATTERN: i32>(self) -> Self {
+        unsafe { Self(_mm256_shuffle_ps::<PATTERN>(self.0, self.0)) }
+    }
+
+    /// Sets lane `i` to the lane of `self` selected by the low 3 bits of lane `i` of `indices`.
+    #[inline(always)]
+    #[must_use]
+    pub fn permute(self, indices: crate::Int32x8) -> Self {
+        unsafe { Self(_mm256_permutevar8x32_ps(self.0, indices.0)) }
+    }
+
+    /// [a0, a0, a2, a2, a4, a4, a6, a6]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_even(self) -> Self {
+        unsafe { Self(_mm256_moveldup_ps(self.0)) }
+    }
+
+    /// [a1, a1, a3, a3, a5, a5, a7, a7]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_odd(self) -> Self {
+        unsafe { Self(_mm256_movehdup_ps(self.0)) }
+    }
+
+    /// Loads `table[indices[i]]` into every lane `i`.
+    ///
+    /// # Safety
+    ///
+    /// All indices must be in bounds of `table`.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) unsafe fn gather_unchecked(table: &[f32], indices: crate::Int32x8) -> Self {
+        Self(_mm256_i32gather_ps::<4>(table.as_ptr(), indices.0))
+    }
 }
 
-impl VectorConvertInto<crate::Int32x8> for Float32x8 {
+impl Float64x4 {
+    /// Every 2 bits of PATTERN select the source element for the corresponding destination
+    /// element from the whole vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn permute4x64<const PATTERN: i32>(self) -> Self {
+        unsafe { Self(_mm256_permute4x64_pd::<PATTERN>(self.0)) }
+    }
+
     #[inline(always)]
     #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        self.transmute::<crate::Uint64x4>().swap_bytes().transmute()
+    }
+
+    /// [a0, a0, a2, a2]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_even(self) -> Self {
+        unsafe { Self(_mm256_movedup_pd(self.0)) }
+    }
+
+    /// [a1, a1, a3, a3]
+    #[inline(always)]
+    #[must_use]
+    pub fn dup_odd(self) -> Self {
+        unsafe { Self(_mm256_unpackhi_pd(self.0, self.0)) }
+    }
+}
+
+impl VectorConvertInto<crate::Int32x8> for Float32x8 {
+    #[inline(always)]
     fn convert_vector(self) -> crate::Int32x8 {
         unsafe { crate::Int32x8(_mm256_cvtps_epi32(self.0)) }
     }
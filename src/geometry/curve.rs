@@ -0,0 +1,47 @@
+use crate::util::mul_add;
+use crate::Float32x8;
+
+/// Evaluates a cubic polynomial with power basis coefficients `c` for every dimension.
+#[inline(always)]
+fn evaluate_cubic<const D: usize>(c: [[f32; D]; 4], t: Float32x8) -> [Float32x8; D] {
+    core::array::from_fn(|i| {
+        let p = mul_add(Float32x8::splat(c[3][i]), t, Float32x8::splat(c[2][i]));
+        let p = mul_add(p, t, Float32x8::splat(c[1][i]));
+
+        mul_add(p, t, Float32x8::splat(c[0][i]))
+    })
+}
+
+/// Evaluates a cubic Bézier curve with control points `p` at eight parameter values `t`.
+/// Returns one vector per dimension.
+#[inline(always)]
+#[must_use]
+pub fn cubic_bezier8<const D: usize>(p: [[f32; D]; 4], t: Float32x8) -> [Float32x8; D] {
+    let mut c = [[0.0; D]; 4];
+
+    for i in 0..D {
+        c[0][i] = p[0][i];
+        c[1][i] = 3.0 * (p[1][i] - p[0][i]);
+        c[2][i] = 3.0 * (p[0][i] - 2.0 * p[1][i] + p[2][i]);
+        c[3][i] = p[3][i] - p[0][i] + 3.0 * (p[1][i] - p[2][i]);
+    }
+
+    evaluate_cubic(c, t)
+}
+
+/// Evaluates a uniform Catmull-Rom spline segment between `p[1]` and `p[2]` at eight parameter
+/// values `t`. Returns one vector per dimension.
+#[inline(always)]
+#[must_use]
+pub fn catmull_rom8<const D: usize>(p: [[f32; D]; 4], t: Float32x8) -> [Float32x8; D] {
+    let mut c = [[0.0; D]; 4];
+
+    for i in 0..D {
+        c[0][i] = p[1][i];
+        c[1][i] = 0.5 * (p[2][i] - p[0][i]);
+        c[2][i] = 0.5 * (2.0 * p[0][i] - 5.0 * p[1][i] + 4.0 * p[2][i] - p[3][i]);
+        c[3][i] = 0.5 * (3.0 * (p[1][i] - p[2][i]) + p[3][i] - p[0][i]);
+    }
+
+    evaluate_cubic(c, t)
+}
@@ -0,0 +1,65 @@
+use super::Vec3x8;
+use crate::util::mul_add;
+use crate::Float32x8;
+
+/// View frustum described by 6 planes.
+#[derive(Copy, Clone, Debug)]
+pub struct Frustum {
+    planes: [[f32; 4]; 6],
+}
+
+impl Frustum {
+    /// Creates a frustum from `[a, b, c, d]` plane equations. Normals `[a, b, c]` must point
+    /// inside of the frustum, so that points `p` inside satisfy `a*p.x + b*p.y + c*p.z + d >= 0`.
+    /// Normals don't have to be normalized unless spheres are tested.
+    #[inline(always)]
+    #[must_use]
+    pub fn new(planes: [[f32; 4]; 6]) -> Self {
+        Self { planes }
+    }
+
+    #[inline(always)]
+    fn distance(plane: [f32; 4], p: Vec3x8) -> Float32x8 {
+        mul_add(
+            Float32x8::splat(plane[0]),
+            p.x,
+            mul_add(
+                Float32x8::splat(plane[1]),
+                p.y,
+                mul_add(Float32x8::splat(plane[2]), p.z, Float32x8::splat(plane[3])),
+            ),
+        )
+    }
+
+    /// Tests eight bounding spheres against the frustum, returning a mask of the visible ones.
+    #[inline(always)]
+    #[must_use]
+    pub fn visible_spheres8(&self, center: Vec3x8, radius: Float32x8) -> Float32x8 {
+        let neg_radius = Float32x8::zero() - radius;
+
+        self.planes
+            .iter()
+            .fold(Float32x8::splat(f32::from_bits(!0)), |visible, &plane| {
+                visible & Self::distance(plane, center).ge(neg_radius)
+            })
+    }
+
+    /// Tests eight axis-aligned boxes against the frustum, returning a mask of the visible ones.
+    /// The test is conservative: large boxes near frustum corners may be reported as visible.
+    #[inline(always)]
+    #[must_use]
+    pub fn visible_aabbs8(&self, min: Vec3x8, max: Vec3x8) -> Float32x8 {
+        self.planes
+            .iter()
+            .fold(Float32x8::splat(f32::from_bits(!0)), |visible, &plane| {
+                // Corner of the box which is furthest along the plane normal.
+                let corner = Vec3x8::new(
+                    if plane[0] >= 0.0 { max.x } else { min.x },
+                    if plane[1] >= 0.0 { max.y } else { min.y },
+                    if plane[2] >= 0.0 { max.z } else { min.z },
+                );
+
+                visible & Self::distance(plane, corner).ge(Float32x8::zero())
+            })
+    }
+}
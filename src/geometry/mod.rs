@@ -0,0 +1,21 @@
+//! Batch geometry kernels operating on eight primitives at once.
+
+mod curve;
+mod frustum;
+mod octahedral;
+mod predicates;
+mod raster;
+mod ray;
+mod sdf;
+mod vec2;
+mod vec3;
+
+pub use curve::*;
+pub use frustum::*;
+pub use octahedral::*;
+pub use predicates::*;
+pub use raster::*;
+pub use ray::*;
+pub use sdf::*;
+pub use vec2::*;
+pub use vec3::*;
@@ -0,0 +1,58 @@
+use super::Vec3x8;
+use crate::util::{abs, select};
+use crate::{Float32x8, Int32x8, Uint32x8};
+
+/// 1.0 with the sign of `x`.
+#[inline(always)]
+fn sign_not_zero(x: Float32x8) -> Float32x8 {
+    (x & Float32x8::splat(-0.0)) | Float32x8::splat(1.0)
+}
+
+/// Encodes eight unit vectors using octahedral mapping. Every lane holds two 16-bit signed
+/// normalized coordinates, u in the low half and v in the high half.
+#[inline(always)]
+#[must_use]
+pub fn octahedral_encode8(n: Vec3x8) -> Uint32x8 {
+    let inverse_l1 = Float32x8::splat(1.0) / (abs(n.x) + abs(n.y) + abs(n.z));
+    let (x, y) = (n.x * inverse_l1, n.y * inverse_l1);
+
+    // Fold the lower hemisphere over the diagonals.
+    let one = Float32x8::splat(1.0);
+    let lower = n.z.lt(Float32x8::zero());
+    let u = select(lower, (one - abs(y)) * sign_not_zero(x), x);
+    let v = select(lower, (one - abs(x)) * sign_not_zero(y), y);
+
+    let quantize = |x: Float32x8| -> Int32x8 {
+        let x = x.max(Float32x8::splat(-1.0)).min(one) * Float32x8::splat(32767.0);
+
+        x.round().convert()
+    };
+
+    let packed = (quantize(u) & Int32x8::splat(0xffff)) | quantize(v).shl::<16>();
+
+    packed.convert()
+}
+
+/// Decodes eight unit vectors encoded by [`octahedral_encode8`].
+#[inline(always)]
+#[must_use]
+pub fn octahedral_decode8(packed: Uint32x8) -> Vec3x8 {
+    let packed: Int32x8 = packed.convert();
+
+    let dequantize = |x: Int32x8| -> Float32x8 {
+        let x: Float32x8 = x.convert();
+
+        (x * Float32x8::splat(1.0 / 32767.0)).max(Float32x8::splat(-1.0))
+    };
+
+    let x = dequantize(packed.shl::<16>().shr_a::<16>());
+    let y = dequantize(packed.shr_a::<16>());
+    let z = Float32x8::splat(1.0) - abs(x) - abs(y);
+
+    // Unfold the lower hemisphere.
+    let t = (Float32x8::zero() - z).max(Float32x8::zero());
+    let x = x - t * sign_not_zero(x);
+    let y = y - t * sign_not_zero(y);
+
+    Vec3x8::new(x, y, z).normalize()
+}
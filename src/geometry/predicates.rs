@@ -0,0 +1,49 @@
+use super::Vec2x8;
+use crate::Float32x8;
+
+/// Twice the signed area of triangles `(a, b, p)`: positive when `p` lies to the left of the
+/// directed line from `a` to `b`, negative when it lies to the right and zero when the points
+/// are collinear. The predicate is evaluated in plain floating point, so results for nearly
+/// collinear points aren't exact.
+#[inline(always)]
+#[must_use]
+pub fn orient2d8(a: [f32; 2], b: [f32; 2], p: Vec2x8) -> Float32x8 {
+    let a_v = Vec2x8::splat(a);
+    let edge = Vec2x8::splat([b[0] - a[0], b[1] - a[1]]);
+
+    edge.cross(p - a_v)
+}
+
+/// Tests eight points against a triangle of any winding, returning a mask of points inside of
+/// it or on its boundary.
+#[inline(always)]
+#[must_use]
+pub fn point_in_triangle8(p: Vec2x8, triangle: [[f32; 2]; 3]) -> Float32x8 {
+    point_in_convex_polygon8(p, &triangle)
+}
+
+/// Tests eight points against a convex polygon of any winding, returning a mask of points
+/// inside of it or on its boundary. Polygons with fewer than 3 vertices contain no points.
+#[inline(always)]
+#[must_use]
+pub fn point_in_convex_polygon8(p: Vec2x8, polygon: &[[f32; 2]]) -> Float32x8 {
+    let zero = Float32x8::zero();
+
+    if polygon.len() < 3 {
+        return zero;
+    }
+
+    let all_ones = Float32x8::splat(f32::from_bits(!0));
+    let mut all_left = all_ones;
+    let mut all_right = all_ones;
+
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let orientation = orient2d8(a, b, p);
+
+        all_left &= orientation.ge(zero);
+        all_right &= orientation.le(zero);
+    }
+
+    all_left | all_right
+}
@@ -0,0 +1,44 @@
+use super::Vec2x8;
+use crate::util::mul_add;
+use crate::Float32x8;
+
+/// Edge function of the directed line from `a` to `b`, expressed as coefficients of
+/// `A*x + B*y + C`.
+#[inline(always)]
+fn edge_function(a: [f32; 2], b: [f32; 2]) -> [f32; 3] {
+    [a[1] - b[1], b[0] - a[0], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// Computes barycentric coordinates of eight points (typically pixel centers) with respect to
+/// a triangle of any winding. Returns a mask of points inside of the triangle or on its
+/// boundary and weights of the three vertices. Degenerate triangles contain no points.
+#[inline(always)]
+#[must_use]
+pub fn barycentric8(p: Vec2x8, triangle: [[f32; 2]; 3]) -> (Float32x8, [Float32x8; 3]) {
+    let [a, b, c] = triangle;
+
+    let area = {
+        let [ea, eb, ec] = edge_function(a, b);
+        ea * c[0] + eb * c[1] + ec
+    };
+
+    if area == 0.0 {
+        return (Float32x8::zero(), [Float32x8::zero(); 3]);
+    }
+
+    let inverse_area = 1.0 / area;
+
+    // Edges are prescaled by the inverse area, so that they evaluate directly to weights.
+    let weight = |from: [f32; 2], to: [f32; 2]| {
+        let [ea, eb, ec] = edge_function(from, to).map(|x| Float32x8::splat(x * inverse_area));
+
+        mul_add(ea, p.x, mul_add(eb, p.y, ec))
+    };
+
+    let weights = [weight(b, c), weight(c, a), weight(a, b)];
+
+    let zero = Float32x8::zero();
+    let inside = weights[0].ge(zero) & weights[1].ge(zero) & weights[2].ge(zero);
+
+    (inside, weights)
+}
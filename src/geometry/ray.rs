@@ -0,0 +1,145 @@
+use super::Vec3x8;
+use crate::util::{abs, select};
+use crate::Float32x8;
+
+/// Slab test of eight rays against eight axis-aligned boxes. Testing one ray against eight
+/// boxes or eight rays against one box is done by splatting the shared operand.
+///
+/// `inverse_direction` is the component-wise reciprocal of the ray direction. Only hits with
+/// distance in `[0, t_max]` are reported. Returns a hit mask and the entry distance (which is
+/// clamped to 0 for rays starting inside of the box).
+///
+/// Rays lying exactly on a slab boundary produce NaN distances. `min`/`max` return their second
+/// operand when the first one is NaN, so these never propagate into the result or cause hits.
+#[inline(always)]
+#[must_use]
+pub fn ray_aabb_intersect8(
+    origin: Vec3x8,
+    inverse_direction: Vec3x8,
+    box_min: Vec3x8,
+    box_max: Vec3x8,
+    t_max: Float32x8,
+) -> (Float32x8, Float32x8) {
+    let t1 = (box_min - origin) * inverse_direction;
+    let t2 = (box_max - origin) * inverse_direction;
+
+    let mut t_enter = Float32x8::zero();
+    let mut t_exit = t_max;
+
+    for (t1, t2) in [(t1.x, t2.x), (t1.y, t2.y), (t1.z, t2.z)] {
+        t_enter = t1.min(t2).max(t_enter);
+        t_exit = t1.max(t2).min(t_exit);
+    }
+
+    (t_enter.le(t_exit), t_enter)
+}
+
+/// Intersects eight rays with eight spheres. `direction` doesn't have to be normalized, returned
+/// distances are in units of its length. Returns a hit mask and the distance to the nearest
+/// intersection in `[0, t_max]`.
+#[inline(always)]
+#[must_use]
+pub fn ray_sphere_intersect8(
+    origin: Vec3x8,
+    direction: Vec3x8,
+    center: Vec3x8,
+    radius: Float32x8,
+    t_max: Float32x8,
+) -> (Float32x8, Float32x8) {
+    let offset = origin - center;
+
+    let a = direction.dot(direction);
+    let b = offset.dot(direction);
+    let c = offset.dot(offset) - radius * radius;
+
+    let discriminant = b * b - a * c;
+    let root = discriminant.max(Float32x8::zero()).sqrt();
+
+    let near = (Float32x8::zero() - b - root) / a;
+    let far = (root - b) / a;
+
+    let zero = Float32x8::zero();
+    let t = select(near.ge(zero), near, far);
+
+    (discriminant.ge(zero) & t.ge(zero) & t.le(t_max), t)
+}
+
+/// Watertight intersection of eight rays with eight triangles (Woop et al. 2013). Rays hitting
+/// an edge or a vertex shared by multiple triangles always hit at least one of them. Returns
+/// a hit mask and the distance to the intersection in `[0, t_max]`.
+///
+/// Unlike the original algorithm, edge functions which evaluate to exactly zero are not
+/// recomputed in double precision, so hits on edges are reported conservatively for both sides.
+#[inline(always)]
+#[must_use]
+pub fn ray_triangle_intersect8(
+    origin: Vec3x8,
+    direction: Vec3x8,
+    v0: Vec3x8,
+    v1: Vec3x8,
+    v2: Vec3x8,
+    t_max: Float32x8,
+) -> (Float32x8, Float32x8) {
+    let zero = Float32x8::zero();
+
+    // Make the dimension where the ray direction is maximal the z axis, swapping remaining
+    // axes to preserve winding.
+    let (ax, ay, az) = (abs(direction.x), abs(direction.y), abs(direction.z));
+    let z_is_x = ax.ge(ay) & ax.ge(az);
+    let z_is_y = z_is_x.andnot(ay.ge(az));
+
+    let permute = |v: Vec3x8| {
+        let x = select(z_is_x, v.y, select(z_is_y, v.z, v.x));
+        let y = select(z_is_x, v.z, select(z_is_y, v.x, v.y));
+        let z = select(z_is_x, v.x, select(z_is_y, v.y, v.z));
+
+        (x, y, z)
+    };
+
+    let (dx, dy, dz) = permute(direction);
+    let swap = dz.lt(zero);
+    let permute = |v: Vec3x8| {
+        let (x, y, z) = permute(v);
+
+        (select(swap, y, x), select(swap, x, y), z)
+    };
+    let (dx, dy) = (select(swap, dy, dx), select(swap, dx, dy));
+
+    // Shear constants.
+    let sz = Float32x8::splat(1.0) / dz;
+    let sx = dx * sz;
+    let sy = dy * sz;
+
+    let transform = |v: Vec3x8| {
+        let (x, y, z) = permute(v - origin);
+
+        (x - sx * z, y - sy * z, sz * z)
+    };
+
+    let (ax, ay, az) = transform(v0);
+    let (bx, by, bz) = transform(v1);
+    let (cx, cy, cz) = transform(v2);
+
+    // Scaled barycentric coordinates. Plain multiplications keep them exactly antisymmetric
+    // for triangles sharing an edge.
+    let u = cx * by - cy * bx;
+    let v = ax * cy - ay * cx;
+    let w = bx * ay - by * ax;
+
+    let any_negative = u.lt(zero) | v.lt(zero) | w.lt(zero);
+    let any_positive = u.gt(zero) | v.gt(zero) | w.gt(zero);
+
+    let determinant = u + v + w;
+    let t_scaled = u * az + v * bz + w * cz;
+
+    // Compare distance against the range without dividing by the determinant first.
+    let sign = determinant & Float32x8::splat(-0.0);
+    let t_scaled_abs = t_scaled ^ sign;
+    let determinant_abs = determinant ^ sign;
+
+    let hit = (any_negative & any_positive).andnot(determinant.ne(zero))
+        & t_scaled_abs.ge(zero)
+        & t_scaled_abs.le(t_max * determinant_abs);
+
+    (hit, t_scaled / determinant)
+}
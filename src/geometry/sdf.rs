@@ -0,0 +1,42 @@
+use super::Vec3x8;
+use crate::util::abs;
+use crate::Float32x8;
+
+/// Signed distance from eight points to a sphere centered at the origin.
+#[inline(always)]
+#[must_use]
+pub fn sdf_sphere8(p: Vec3x8, radius: Float32x8) -> Float32x8 {
+    p.length() - radius
+}
+
+/// Signed distance from eight points to a box centered at the origin.
+#[inline(always)]
+#[must_use]
+pub fn sdf_box8(p: Vec3x8, half_extents: Vec3x8) -> Float32x8 {
+    let q = Vec3x8::new(abs(p.x), abs(p.y), abs(p.z)) - half_extents;
+
+    let outside = q.max(Vec3x8::zero()).length();
+    let inside = q.x.max(q.y.max(q.z)).min(Float32x8::zero());
+
+    outside + inside
+}
+
+/// Polynomial smooth minimum (smooth union of two distance fields). `k` is the blending
+/// radius and must be positive.
+#[inline(always)]
+#[must_use]
+pub fn smooth_min8(a: Float32x8, b: Float32x8, k: Float32x8) -> Float32x8 {
+    let h = (k - abs(a - b)).max(Float32x8::zero()) / k;
+
+    a.min(b) - h * h * k * Float32x8::splat(0.25)
+}
+
+/// Polynomial smooth maximum (smooth intersection of two distance fields). `k` is the blending
+/// radius and must be positive.
+#[inline(always)]
+#[must_use]
+pub fn smooth_max8(a: Float32x8, b: Float32x8, k: Float32x8) -> Float32x8 {
+    let h = (k - abs(a - b)).max(Float32x8::zero()) / k;
+
+    a.max(b) + h * h * k * Float32x8::splat(0.25)
+}
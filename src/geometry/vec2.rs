@@ -0,0 +1,90 @@
+use core::ops;
+
+use crate::Float32x8;
+
+/// Eight 2D vectors in structure-of-arrays layout.
+#[derive(Copy, Clone, Debug)]
+pub struct Vec2x8 {
+    pub x: Float32x8,
+    pub y: Float32x8,
+}
+
+impl Vec2x8 {
+    #[inline(always)]
+    #[must_use]
+    pub fn new(x: Float32x8, y: Float32x8) -> Self {
+        Self { x, y }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::new(Float32x8::zero(), Float32x8::zero())
+    }
+
+    /// Replicates a single vector into all eight lanes.
+    #[inline(always)]
+    #[must_use]
+    pub fn splat(v: [f32; 2]) -> Self {
+        Self::new(Float32x8::splat(v[0]), Float32x8::splat(v[1]))
+    }
+
+    /// Converts eight vectors from array-of-structures layout.
+    #[inline(always)]
+    #[must_use]
+    pub fn from_array(array: [[f32; 2]; 8]) -> Self {
+        Self::new(
+            Float32x8::from_array(array.map(|v| v[0])),
+            Float32x8::from_array(array.map(|v| v[1])),
+        )
+    }
+
+    /// Converts eight vectors to array-of-structures layout.
+    #[inline(always)]
+    #[must_use]
+    pub fn to_array(self) -> [[f32; 2]; 8] {
+        let (x, y) = (self.x.to_array(), self.y.to_array());
+
+        core::array::from_fn(|i| [x[i], y[i]])
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> Float32x8 {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// Z component of the 3D cross product.
+    #[inline(always)]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Float32x8 {
+        self.x * rhs.y - self.y * rhs.x
+    }
+}
+
+impl ops::Add for Vec2x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl ops::Sub for Vec2x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl ops::Mul<Float32x8> for Vec2x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Float32x8) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
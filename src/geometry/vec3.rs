@@ -0,0 +1,143 @@
+use core::ops;
+
+use crate::util::mul_add;
+use crate::Float32x8;
+
+/// Eight 3D vectors in structure-of-arrays layout.
+#[derive(Copy, Clone, Debug)]
+pub struct Vec3x8 {
+    pub x: Float32x8,
+    pub y: Float32x8,
+    pub z: Float32x8,
+}
+
+impl Vec3x8 {
+    #[inline(always)]
+    #[must_use]
+    pub fn new(x: Float32x8, y: Float32x8, z: Float32x8) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn zero() -> Self {
+        Self::new(Float32x8::zero(), Float32x8::zero(), Float32x8::zero())
+    }
+
+    /// Replicates a single vector into all eight lanes.
+    #[inline(always)]
+    #[must_use]
+    pub fn splat(v: [f32; 3]) -> Self {
+        Self::new(
+            Float32x8::splat(v[0]),
+            Float32x8::splat(v[1]),
+            Float32x8::splat(v[2]),
+        )
+    }
+
+    /// Converts eight vectors from array-of-structures layout.
+    #[inline(always)]
+    #[must_use]
+    pub fn from_array(array: [[f32; 3]; 8]) -> Self {
+        Self::new(
+            Float32x8::from_array(array.map(|v| v[0])),
+            Float32x8::from_array(array.map(|v| v[1])),
+            Float32x8::from_array(array.map(|v| v[2])),
+        )
+    }
+
+    /// Converts eight vectors to array-of-structures layout.
+    #[inline(always)]
+    #[must_use]
+    pub fn to_array(self) -> [[f32; 3]; 8] {
+        let (x, y, z) = (self.x.to_array(), self.y.to_array(), self.z.to_array());
+
+        core::array::from_fn(|i| [x[i], y[i], z[i]])
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> Float32x8 {
+        mul_add(self.x, rhs.x, mul_add(self.y, rhs.y, self.z * rhs.z))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn length(self) -> Float32x8 {
+        self.dot(self).sqrt()
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self * (Float32x8::splat(1.0) / self.length())
+    }
+
+    /// Component-wise reciprocal, used to precompute inverse ray directions.
+    #[inline(always)]
+    #[must_use]
+    pub fn recip(self) -> Self {
+        let one = Float32x8::splat(1.0);
+
+        Self::new(one / self.x, one / self.y, one / self.z)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        Self::new(self.x.min(rhs.x), self.y.min(rhs.y), self.z.min(rhs.z))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        Self::new(self.x.max(rhs.x), self.y.max(rhs.y), self.z.max(rhs.z))
+    }
+}
+
+impl ops::Add for Vec3x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl ops::Sub for Vec3x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+/// Component-wise product.
+impl ops::Mul for Vec3x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+impl ops::Mul<Float32x8> for Vec3x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Float32x8) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
@@ -0,0 +1,174 @@
+//! Arithmetic on bytes as elements of GF(2^8) with the reduction polynomial
+//! x^8 + x^4 + x^3 + x + 1, used for bit matrix transforms and erasure coding. With the `gfni`
+//! feature on a target supporting it these use the GFNI instructions, otherwise every byte is
+//! computed on its own.
+
+#[cfg(any(
+    feature = "reference",
+    not(all(
+        feature = "gfni",
+        target_feature = "gfni",
+        target_feature = "sse4.1",
+        not(feature = "soft")
+    ))
+))]
+mod portable {
+    #[inline(always)]
+    pub(super) fn mul(mut a: u8, mut b: u8) -> u8 {
+        let mut product = 0;
+        while b != 0 {
+            if b & 1 != 0 {
+                product ^= a;
+            }
+            a = (a << 1) ^ if a & 0x80 != 0 { 0x1b } else { 0 };
+            b >>= 1;
+        }
+
+        product
+    }
+
+    /// `a^254`, which is the inverse of `a` and maps zero to zero.
+    #[inline(always)]
+    pub(super) fn inverse(a: u8) -> u8 {
+        let mut result = 1;
+        for _ in 0..254 {
+            result = mul(result, a);
+        }
+
+        result
+    }
+
+    /// Row `i` of the matrix is byte `7 - i` of `matrix`.
+    #[inline(always)]
+    pub(super) fn affine(x: u8, matrix: u64, b: u8) -> u8 {
+        (0..8).fold(b, |result, i| {
+            let row = (matrix >> ((7 - i) * 8)) as u8;
+
+            result ^ (((row & x).count_ones() as u8 & 1) << i)
+        })
+    }
+
+    macro_rules! impl_gf2p8 {
+        ($name: ty, $matrix: ty) => {
+            impl $name {
+                /// Multiplies every byte, as a vector of bits, by the 8x8 bit matrix in the same
+                /// 64-bit lane of `matrix` and xors the result with `B`. Row `i` of the matrix is
+                /// byte `7 - i` of its lane, like `_mm256_gf2p8affine_epi64_epi8`.
+                #[inline(always)]
+                #[must_use]
+                pub fn gf2p8_affine<const B: i32>(self, matrix: $matrix) -> Self {
+                    self.affine(matrix, |x, matrix| affine(x, matrix, B as u8))
+                }
+
+                /// Same as `gf2p8_affine`, but transforms the inverses of the bytes in GF(2^8).
+                /// Zero is its own inverse.
+                #[inline(always)]
+                #[must_use]
+                pub fn gf2p8_affine_inv<const B: i32>(self, matrix: $matrix) -> Self {
+                    self.affine(matrix, |x, matrix| affine(inverse(x), matrix, B as u8))
+                }
+
+                /// Multiplies bytes in GF(2^8).
+                #[inline(always)]
+                #[must_use]
+                pub fn gf2p8_mul(self, rhs: Self) -> Self {
+                    let (a, b) = (self.to_array(), rhs.to_array());
+
+                    Self::from_array(core::array::from_fn(|i| mul(a[i], b[i])))
+                }
+
+                #[inline(always)]
+                fn affine(self, matrix: $matrix, f: impl Fn(u8, u64) -> u8) -> Self {
+                    let (x, matrix) = (self.to_array(), matrix.to_array());
+
+                    Self::from_array(core::array::from_fn(|i| f(x[i], matrix[i / 8])))
+                }
+            }
+        };
+    }
+
+    #[cfg(not(all(
+        feature = "gfni",
+        target_feature = "gfni",
+        target_feature = "sse4.1",
+        not(feature = "soft")
+    )))]
+    impl_gf2p8!(crate::Uint8x32, crate::Uint64x4);
+
+    #[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+    impl_gf2p8!(crate::reference::Uint8x32, crate::reference::Uint64x4);
+}
+
+#[cfg(all(
+    feature = "gfni",
+    target_feature = "gfni",
+    target_feature = "sse4.1",
+    not(feature = "soft")
+))]
+mod native {
+    use crate::{Uint64x4, Uint8x32};
+
+    #[cfg(target_feature = "avx2")]
+    use core::arch::x86_64::*;
+
+    #[cfg(not(target_feature = "avx2"))]
+    use core::arch::x86_64::{
+        _mm_gf2p8affine_epi64_epi8, _mm_gf2p8affineinv_epi64_epi8, _mm_gf2p8mul_epi8, __m128i,
+    };
+
+    /// Applies a 128-bit intrinsic to both halves of the vectors.
+    #[cfg(not(target_feature = "avx2"))]
+    #[inline(always)]
+    fn halves(a: Uint8x32, b: Uint8x32, f: impl Fn(__m128i, __m128i) -> __m128i) -> Uint8x32 {
+        Uint8x32::combine(
+            f(a.low().into(), b.low().into()).into(),
+            f(a.high().into(), b.high().into()).into(),
+        )
+    }
+
+    impl Uint8x32 {
+        /// Multiplies every byte, as a vector of bits, by the 8x8 bit matrix in the same 64-bit
+        /// lane of `matrix` and xors the result with `B`. Row `i` of the matrix is byte `7 - i` of
+        /// its lane, like `_mm256_gf2p8affine_epi64_epi8`.
+        #[inline(always)]
+        #[must_use]
+        pub fn gf2p8_affine<const B: i32>(self, matrix: Uint64x4) -> Self {
+            #[cfg(target_feature = "avx2")]
+            return unsafe {
+                _mm256_gf2p8affine_epi64_epi8::<B>(self.into(), matrix.into()).into()
+            };
+
+            #[cfg(not(target_feature = "avx2"))]
+            return halves(self, matrix.transmute(), |x, matrix| unsafe {
+                _mm_gf2p8affine_epi64_epi8::<B>(x, matrix)
+            });
+        }
+
+        /// Same as `gf2p8_affine`, but transforms the inverses of the bytes in GF(2^8). Zero is
+        /// its own inverse.
+        #[inline(always)]
+        #[must_use]
+        pub fn gf2p8_affine_inv<const B: i32>(self, matrix: Uint64x4) -> Self {
+            #[cfg(target_feature = "avx2")]
+            return unsafe {
+                _mm256_gf2p8affineinv_epi64_epi8::<B>(self.into(), matrix.into()).into()
+            };
+
+            #[cfg(not(target_feature = "avx2"))]
+            return halves(self, matrix.transmute(), |x, matrix| unsafe {
+                _mm_gf2p8affineinv_epi64_epi8::<B>(x, matrix)
+            });
+        }
+
+        /// Multiplies bytes in GF(2^8).
+        #[inline(always)]
+        #[must_use]
+        pub fn gf2p8_mul(self, rhs: Self) -> Self {
+            #[cfg(target_feature = "avx2")]
+            return unsafe { _mm256_gf2p8mul_epi8(self.into(), rhs.into()).into() };
+
+            #[cfg(not(target_feature = "avx2"))]
+            return halves(self, rhs, |a, b| unsafe { _mm_gf2p8mul_epi8(a, b) });
+        }
+    }
+}
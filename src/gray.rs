@@ -0,0 +1,68 @@
+macro_rules! impl_gray {
+    ($name: ty, $($shift: literal),*) => {
+        impl $name {
+            /// Binary reflected Gray code of every lane: x ^ (x >> 1)
+            #[inline(always)]
+            #[must_use]
+            pub fn to_gray(self) -> Self {
+                self ^ self.shr_l::<1>()
+            }
+
+            /// Inverse of `to_gray`.
+            #[inline(always)]
+            #[must_use]
+            pub fn from_gray(self) -> Self {
+                let mut x = self;
+                $(
+                    x ^= x.shr_l::<$shift>();
+                )*
+                x
+            }
+        }
+    };
+}
+
+macro_rules! impl_gray_for_backend {
+    ($($backend: ident)::*) => {
+        impl_gray!($($backend)::*::Int8x16, 1, 2, 4);
+        impl_gray!($($backend)::*::Uint8x16, 1, 2, 4);
+
+        impl_gray!($($backend)::*::Int16x8, 1, 2, 4, 8);
+        impl_gray!($($backend)::*::Uint16x8, 1, 2, 4, 8);
+
+        impl_gray!($($backend)::*::Int32x4, 1, 2, 4, 8, 16);
+        impl_gray!($($backend)::*::Uint32x4, 1, 2, 4, 8, 16);
+
+        impl_gray!($($backend)::*::Int64x2, 1, 2, 4, 8, 16, 32);
+        impl_gray!($($backend)::*::Uint64x2, 1, 2, 4, 8, 16, 32);
+
+        impl_gray!($($backend)::*::Int8x32, 1, 2, 4);
+        impl_gray!($($backend)::*::Uint8x32, 1, 2, 4);
+
+        impl_gray!($($backend)::*::Int16x16, 1, 2, 4, 8);
+        impl_gray!($($backend)::*::Uint16x16, 1, 2, 4, 8);
+
+        impl_gray!($($backend)::*::Int32x8, 1, 2, 4, 8, 16);
+        impl_gray!($($backend)::*::Uint32x8, 1, 2, 4, 8, 16);
+
+        impl_gray!($($backend)::*::Int64x4, 1, 2, 4, 8, 16, 32);
+        impl_gray!($($backend)::*::Uint64x4, 1, 2, 4, 8, 16, 32);
+    };
+}
+
+impl_gray_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_gray_for_backend!(crate::reference);
+
+impl_gray!(crate::Int8x64, 1, 2, 4);
+impl_gray!(crate::Uint8x64, 1, 2, 4);
+
+impl_gray!(crate::Int16x32, 1, 2, 4, 8);
+impl_gray!(crate::Uint16x32, 1, 2, 4, 8);
+
+impl_gray!(crate::Int32x16, 1, 2, 4, 8, 16);
+impl_gray!(crate::Uint32x16, 1, 2, 4, 8, 16);
+
+impl_gray!(crate::Int64x8, 1, 2, 4, 8, 16, 32);
+impl_gray!(crate::Uint64x8, 1, 2, 4, 8, 16, 32);
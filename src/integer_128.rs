@@ -0,0 +1,492 @@
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+use crate::arch::*;
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::integer_256::swap_bytes_indices;
+use crate::util::slice_assume_init_mut;
+
+make_vector_type!(Int8x16, i8, 16, __m128i, 128, _mm, si128);
+make_vector_type!(Uint8x16, u8, 16, __m128i, 128, _mm, si128);
+
+make_vector_type!(Int16x8, i16, 8, __m128i, 128, _mm, si128);
+make_vector_type!(Uint16x8, u16, 8, __m128i, 128, _mm, si128);
+
+make_vector_type!(Int32x4, i32, 4, __m128i, 128, _mm, si128);
+make_vector_type!(Uint32x4, u32, 4, __m128i, 128, _mm, si128);
+
+make_vector_type!(Int64x2, i64, 2, __m128i, 128, _mm, si128);
+make_vector_type!(Uint64x2, u64, 2, __m128i, 128, _mm, si128);
+
+impl_basic_operations!(
+    Int8x16,
+    i8,
+    Uint8x16,
+    u8,
+    _mm_set1_epi8,
+    _mm_add_epi8,
+    _mm_sub_epi8,
+    _mm_insert_epi8,
+    _mm_cmpeq_epi8,
+    _mm_cmpgt_epi8
+);
+
+impl_basic_operations!(
+    Int16x8,
+    i16,
+    Uint16x8,
+    u16,
+    _mm_set1_epi16,
+    _mm_add_epi16,
+    _mm_sub_epi16,
+    _mm_insert_epi16,
+    _mm_cmpeq_epi16,
+    _mm_cmpgt_epi16
+);
+
+impl_basic_operations!(
+    Int32x4,
+    i32,
+    Uint32x4,
+    u32,
+    _mm_set1_epi32,
+    _mm_add_epi32,
+    _mm_sub_epi32,
+    _mm_insert_epi32,
+    _mm_cmpeq_epi32,
+    _mm_cmpgt_epi32
+);
+
+impl_basic_operations!(
+    Int64x2,
+    i64,
+    Uint64x2,
+    u64,
+    _mm_set1_epi64x,
+    _mm_add_epi64,
+    _mm_sub_epi64,
+    _mm_insert_epi64,
+    _mm_cmpeq_epi64,
+    _mm_cmpgt_epi64
+);
+
+impl_interleave!(128:
+    Int8x16 => _mm_unpacklo_epi8, _mm_unpackhi_epi8,
+    Uint8x16 => _mm_unpacklo_epi8, _mm_unpackhi_epi8,
+    Int16x8 => _mm_unpacklo_epi16, _mm_unpackhi_epi16,
+    Uint16x8 => _mm_unpacklo_epi16, _mm_unpackhi_epi16,
+    Int32x4 => _mm_unpacklo_epi32, _mm_unpackhi_epi32,
+    Uint32x4 => _mm_unpacklo_epi32, _mm_unpackhi_epi32,
+    Int64x2 => _mm_unpacklo_epi64, _mm_unpackhi_epi64,
+    Uint64x2 => _mm_unpacklo_epi64, _mm_unpackhi_epi64
+);
+
+impl_logical_shifts!(
+    Int16x8,
+    Uint16x8,
+    _mm_slli_epi16,
+    _mm_srli_epi16,
+    _mm_sll_epi16,
+    _mm_srl_epi16
+);
+impl_logical_shifts!(
+    Int32x4,
+    Uint32x4,
+    _mm_slli_epi32,
+    _mm_srli_epi32,
+    _mm_sll_epi32,
+    _mm_srl_epi32
+);
+impl_logical_shifts!(
+    Int64x2,
+    Uint64x2,
+    _mm_slli_epi64,
+    _mm_srli_epi64,
+    _mm_sll_epi64,
+    _mm_srl_epi64
+);
+
+impl_arithmetic_shift!(Int16x8, _mm_srai_epi16, _mm_sra_epi16);
+impl_arithmetic_shift!(Int32x4, _mm_srai_epi32, _mm_sra_epi32);
+impl_arithmetic_shift!(emulated: Int64x2, i64, u64);
+
+impl_byte_shifts!(Int8x16, Uint8x16, Uint16x8);
+
+impl_comparisons!(
+    Int8x16,
+    Uint8x16,
+    _mm_max_epi8,
+    _mm_min_epi8,
+    _mm_max_epu8,
+    _mm_min_epu8,
+    _mm_abs_epi8
+);
+
+impl_comparisons!(
+    Int16x8,
+    Uint16x8,
+    _mm_max_epi16,
+    _mm_min_epi16,
+    _mm_max_epu16,
+    _mm_min_epu16,
+    _mm_abs_epi16
+);
+
+impl_comparisons!(
+    Int32x4,
+    Uint32x4,
+    _mm_max_epi32,
+    _mm_min_epi32,
+    _mm_max_epu32,
+    _mm_min_epu32,
+    _mm_abs_epi32
+);
+
+impl_comparisons_64!(Int64x2, Uint64x2, _mm_blendv_epi8);
+
+impl_blend!(Int16x8, Uint16x8, _mm_blend_epi16);
+impl_blend!(Int32x4, Uint32x4, _mm_blend_epi32);
+
+/// Same as the 256-bit version.
+#[inline(always)]
+unsafe fn blend_epi8<const N: i32>(a: __m128i, b: __m128i) -> __m128i {
+    let mask = const {
+        let mut mask = [0i8; 16];
+        let mut i = 0;
+        while i < 16 {
+            mask[i] = -((N >> i) & 1) as i8;
+            i += 1;
+        }
+        mask
+    };
+
+    _mm_blendv_epi8(a, b, _mm_loadu_si128(mask.as_ptr().cast()))
+}
+
+#[inline(always)]
+unsafe fn blend_epi64<const N: i32>(a: __m128i, b: __m128i) -> __m128i {
+    _mm_castpd_si128(_mm_blend_pd::<N>(_mm_castsi128_pd(a), _mm_castsi128_pd(b)))
+}
+
+impl_blend!(Int8x16, Uint8x16, blend_epi8);
+impl_blend!(Int64x2, Uint64x2, blend_epi64);
+
+impl_within_lane_shuffles!(
+    Int16x8,
+    Uint16x8,
+    shuffle_lo_within_lanes => _mm_shufflelo_epi16,
+    shuffle_hi_within_lanes => _mm_shufflehi_epi16
+);
+
+impl_within_lane_shuffles!(
+    Int32x4,
+    Uint32x4,
+    shuffle_within_lanes => _mm_shuffle_epi32
+);
+
+impl_shuffle_bytes!(Uint8x16, _mm_shuffle_epi8, Int8x16, Uint8x16);
+
+/// Same as the 256-bit version.
+#[inline(always)]
+unsafe fn mullo_epi8(a: __m128i, b: __m128i) -> __m128i {
+    let even = _mm_mullo_epi16(a, b);
+    let odd = _mm_mullo_epi16(_mm_srli_epi16::<8>(a), _mm_srli_epi16::<8>(b));
+
+    _mm_or_si128(_mm_and_si128(even, _mm_set1_epi16(0xff)), _mm_slli_epi16::<8>(odd))
+}
+
+/// Same as the 256-bit version.
+#[inline(always)]
+unsafe fn mullo_epi64(a: __m128i, b: __m128i) -> __m128i {
+    let cross = _mm_add_epi64(
+        _mm_mul_epu32(a, _mm_srli_epi64::<32>(b)),
+        _mm_mul_epu32(_mm_srli_epi64::<32>(a), b),
+    );
+
+    _mm_add_epi64(_mm_mul_epu32(a, b), _mm_slli_epi64::<32>(cross))
+}
+
+impl_mul_lo!(
+    Int8x16 => mullo_epi8,
+    Uint8x16 => mullo_epi8,
+    Int16x8 => _mm_mullo_epi16,
+    Uint16x8 => _mm_mullo_epi16,
+    Int32x4 => _mm_mullo_epi32,
+    Uint32x4 => _mm_mullo_epi32,
+    Int64x2 => mullo_epi64,
+    Uint64x2 => mullo_epi64
+);
+
+/// Same as the 256-bit version.
+#[cfg(not(all(target_feature = "avx512bitalg", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi8(x: __m128i) -> __m128i {
+    const COUNTS: [u8; 16] = [0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4];
+
+    let table = _mm_loadu_si128(COUNTS.as_ptr().cast());
+    let mask = _mm_set1_epi8(0x0f);
+
+    let low = _mm_shuffle_epi8(table, _mm_and_si128(x, mask));
+    let high = _mm_shuffle_epi8(table, _mm_and_si128(_mm_srli_epi16::<4>(x), mask));
+
+    _mm_add_epi8(low, high)
+}
+
+#[cfg(not(all(target_feature = "avx512bitalg", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi16(x: __m128i) -> __m128i {
+    _mm_maddubs_epi16(popcnt_epi8(x), _mm_set1_epi8(1))
+}
+
+#[cfg(not(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi32(x: __m128i) -> __m128i {
+    _mm_madd_epi16(popcnt_epi16(x), _mm_set1_epi16(1))
+}
+
+#[cfg(not(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi64(x: __m128i) -> __m128i {
+    _mm_sad_epu8(popcnt_epi8(x), _mm_setzero_si128())
+}
+
+#[cfg(all(target_feature = "avx512bitalg", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi8(x: __m128i) -> __m128i {
+    _mm_popcnt_epi8(x)
+}
+
+#[cfg(all(target_feature = "avx512bitalg", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi16(x: __m128i) -> __m128i {
+    _mm_popcnt_epi16(x)
+}
+
+#[cfg(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi32(x: __m128i) -> __m128i {
+    _mm_popcnt_epi32(x)
+}
+
+#[cfg(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi64(x: __m128i) -> __m128i {
+    _mm_popcnt_epi64(x)
+}
+
+impl_count_ones!(
+    Int8x16 => popcnt_epi8,
+    Uint8x16 => popcnt_epi8,
+    Int16x8 => popcnt_epi16,
+    Uint16x8 => popcnt_epi16,
+    Int32x4 => popcnt_epi32,
+    Uint32x4 => popcnt_epi32,
+    Int64x2 => popcnt_epi64,
+    Uint64x2 => popcnt_epi64
+);
+
+impl_mul_even_widening!(
+    Int32x4 => Int64x2, _mm_mul_epi32,
+    Uint32x4 => Uint64x2, _mm_mul_epu32
+);
+
+impl Int16x8 {
+    /// High 16 bits of the 32-bit products.
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        unsafe { Self(_mm_mulhi_epi16(self.0, rhs.0)) }
+    }
+
+    /// Fixed-point Q15 multiplication: (self * rhs + 0x4000) >> 15
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hrs(self, rhs: Self) -> Self {
+        unsafe { Self(_mm_mulhrs_epi16(self.0, rhs.0)) }
+    }
+
+    /// Multiplies lanes into 32-bit products and adds adjacent pairs of them.
+    #[inline(always)]
+    #[must_use]
+    pub fn madd(self, rhs: Self) -> Int32x4 {
+        unsafe { Int32x4(_mm_madd_epi16(self.0, rhs.0)) }
+    }
+}
+
+impl Uint8x16 {
+    /// Low four bits of every element.
+    #[inline(always)]
+    #[must_use]
+    pub fn low_nibbles(self) -> Self {
+        self & Self::splat(0x0f)
+    }
+
+    /// High four bits of every element, shifted into the low four.
+    #[inline(always)]
+    #[must_use]
+    pub fn high_nibbles(self) -> Self {
+        self.shr_l::<4>()
+    }
+
+    /// Replaces every element with `table[element]`. Elements which are out of range of the
+    /// table become zero.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup16(self, table: [u8; 16]) -> Self {
+        unsafe {
+            // Same saturating trick as the 256-bit version.
+            let indices = _mm_adds_epu8(self.0, _mm_set1_epi8(0x70));
+            Self(_mm_shuffle_epi8(Self::from_array(table).0, indices))
+        }
+    }
+
+    /// Same as `lookup16`, but with a 32-entry table.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup32(self, table: [u8; 32]) -> Self {
+        let [low, high]: [[u8; 16]; 2] = unsafe { core::mem::transmute(table) };
+
+        self.lookup16(low) | (self - Self::splat(16)).lookup16(high)
+    }
+
+    /// Same as `lookup16`, but with a 64-entry table.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup64(self, table: [u8; 64]) -> Self {
+        let [low, high]: [[u8; 32]; 2] = unsafe { core::mem::transmute(table) };
+
+        self.lookup32(low) | (self - Self::splat(32)).lookup32(high)
+    }
+
+    /// Multiplies unsigned lanes of `self` by signed lanes of `rhs` and adds adjacent pairs of the
+    /// products with signed saturation.
+    #[inline(always)]
+    #[must_use]
+    pub fn maddubs(self, rhs: Int8x16) -> Int16x8 {
+        unsafe { Int16x8(_mm_maddubs_epi16(self.0, rhs.0)) }
+    }
+
+    /// Sums of absolute differences between a four-byte group of `rhs` and eight sliding windows
+    /// of `self`. Bits 0-1 of `N` select the group and bit 2 the offset of the windows (0 or 4
+    /// bytes).
+    #[inline(always)]
+    #[must_use]
+    pub fn mpsadbw<const N: i32>(self, rhs: Self) -> Uint16x8 {
+        unsafe { Uint16x8(_mm_mpsadbw_epu8::<N>(self.0, rhs.0)) }
+    }
+}
+
+impl Uint16x8 {
+    /// High 16 bits of the 32-bit products.
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        unsafe { Self(_mm_mulhi_epu16(self.0, rhs.0)) }
+    }
+}
+
+impl_dot_accumulate!(Uint8x16, Int8x16, Int32x4, Int16x8, _mm_dpbusd_avx_epi32);
+
+impl_saturating_arithmetic!(Int8x16, _mm_adds_epi8, _mm_subs_epi8);
+impl_saturating_arithmetic!(Uint8x16, _mm_adds_epu8, _mm_subs_epu8);
+impl_saturating_arithmetic!(Int16x8, _mm_adds_epi16, _mm_subs_epi16);
+impl_saturating_arithmetic!(Uint16x8, _mm_adds_epu16, _mm_subs_epu16);
+
+impl_average!(Uint8x16 => _mm_avg_epu8, Uint16x8 => _mm_avg_epu16);
+
+impl_horizontal!(
+    Int16x8, hadd, hsub, _mm_hadd_epi16, _mm_hsub_epi16;
+    Uint16x8, hadd, hsub, _mm_hadd_epi16, _mm_hsub_epi16;
+    Int32x4, hadd, hsub, _mm_hadd_epi32, _mm_hsub_epi32;
+    Uint32x4, hadd, hsub, _mm_hadd_epi32, _mm_hsub_epi32;
+    Int16x8, saturating_hadd, saturating_hsub, _mm_hadds_epi16, _mm_hsubs_epi16;
+);
+
+impl_pack!(
+    Int16x8 => Int8x16, Uint8x16, _mm_packs_epi16, _mm_packus_epi16,
+    Int32x4 => Int16x8, Uint16x8, _mm_packs_epi32, _mm_packus_epi32
+);
+
+impl_abs_diff!(Int8x16, Uint8x16);
+impl_abs_diff!(Int16x8, Uint16x8);
+impl_abs_diff!(Int32x4, Uint32x4);
+impl_abs_diff!(Int64x2, Uint64x2, i64::MIN);
+
+impl Uint8x16 {
+    /// Sums of absolute differences of every eight consecutive lanes.
+    #[inline(always)]
+    #[must_use]
+    pub fn sad(self, rhs: Self) -> Uint64x2 {
+        unsafe { Uint64x2(_mm_sad_epu8(self.0, rhs.0)) }
+    }
+}
+
+impl_bit_extract_deposit!(Int32x4, Uint32x4, u32, _pext_u32, _pdep_u32);
+impl_bit_extract_deposit!(Int64x2, Uint64x2, u64, _pext_u64, _pdep_u64);
+
+impl_swap_bytes!(Int16x8, Uint16x8, 2, Uint8x16, _mm_shuffle_epi8);
+impl_swap_bytes!(Int32x4, Uint32x4, 4, Uint8x16, _mm_shuffle_epi8);
+impl_swap_bytes!(Int64x2, Uint64x2, 8, Uint8x16, _mm_shuffle_epi8);
+
+/// Same as the 256-bit version.
+#[inline(always)]
+unsafe fn reverse_bits_epi8(x: __m128i) -> __m128i {
+    const REVERSED: [u8; 16] = [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15];
+
+    let table = _mm_loadu_si128(REVERSED.as_ptr().cast());
+    let mask = _mm_set1_epi8(0x0f);
+
+    let low = _mm_shuffle_epi8(table, _mm_and_si128(x, mask));
+    let high = _mm_shuffle_epi8(table, _mm_and_si128(_mm_srli_epi16::<4>(x), mask));
+
+    _mm_or_si128(_mm_slli_epi16::<4>(low), high)
+}
+
+impl_reverse_bits!(
+    reverse_bits_epi8, Int8x16, Uint8x16;
+    Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2
+);
+
+impl_signedness_casts!(Int8x16, Uint8x16);
+impl_signedness_casts!(Int16x8, Uint16x8);
+impl_signedness_casts!(Int32x4, Uint32x4);
+impl_signedness_casts!(Int64x2, Uint64x2);
+
+impl VectorConvertInto<crate::Float32x4> for Int32x4 {
+    #[inline(always)]
+    fn convert_vector(self) -> crate::Float32x4 {
+        unsafe { crate::Float32x4(_mm_cvtepi32_ps(self.0)) }
+    }
+}
+
+impl VectorConvertInto<crate::Float64x4> for Int32x4 {
+    #[inline(always)]
+    fn convert_vector(self) -> crate::Float64x4 {
+        unsafe { crate::Float64x4(_mm256_cvtepi32_pd(self.0)) }
+    }
+}
+
+macro_rules! impl_widening_conversions {
+    ($($from: ident => $to: ident: $extend: ident),* $(,)?) => {
+        $(
+            impl VectorConvertInto<crate::$to> for $from {
+                /// Sign or zero extends every lane to twice its width.
+                #[inline(always)]
+                fn convert_vector(self) -> crate::$to {
+                    unsafe { crate::$to($extend(self.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_conversions!(
+    Int8x16 => Int16x16: _mm256_cvtepi8_epi16,
+    Uint8x16 => Uint16x16: _mm256_cvtepu8_epi16,
+    Int16x8 => Int32x8: _mm256_cvtepi16_epi32,
+    Uint16x8 => Uint32x8: _mm256_cvtepu16_epi32,
+    Int32x4 => Int64x4: _mm256_cvtepi32_epi64,
+    Uint32x4 => Uint64x4: _mm256_cvtepu32_epi64,
+);
@@ -1,18 +1,11 @@
-use std::arch::x86_64::*;
-use std::mem::MaybeUninit;
-use std::{fmt, ops};
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
 
 use paste::paste;
 
-use crate::conversion::{VectorConvertInto, VectorTransmuteInto};
-
-trait From256i {
-    fn from_256i(x: __m256i) -> Self;
-}
-
-trait To256i {
-    fn to_256i(self) -> __m256i;
-}
+use crate::arch::*;
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::slice_assume_init_mut;
 
 macro_rules! impl_operator {
     ($name: ident, $op: ident, $op_function: ident, $function: item) => {
@@ -20,7 +13,6 @@ macro_rules! impl_operator {
             type Output = Self;
 
             #[inline(always)]
-            #[must_use]
             $function
         }
 
@@ -36,70 +28,68 @@ macro_rules! impl_operator {
 }
 
 macro_rules! make_vector_type {
-    ($name: ident, $type: ty, $lanes: expr) => {
+    (
+        $name: ident, $type: ty, $lanes: expr, $raw: ty, $width: expr, $prefix: ident, $si: ident
+    ) => {
         #[derive(Copy, Clone)]
         #[repr(transparent)]
-        pub struct $name(pub(crate) __m256i);
-
-        impl VectorTransmuteInto<crate::Float32x8> for $name {
-            #[inline(always)]
-            fn transmute_vector(self) -> crate::Float32x8 {
-                unsafe { crate::Float32x8(_mm256_castsi256_ps(self.0) ) }
-            }
+        pub struct $name(pub(crate) $raw);
+
+        macro_rules! intrinsic {
+            ($function: ident) => {
+                paste! { [<$prefix $function _ $si>] }
+            };
+            ($function: ident, $postfix: ident) => {
+                paste! { [<$prefix $function _ $postfix>] }
+            };
         }
 
-        impl VectorTransmuteInto<crate::Float64x4> for $name {
-            #[inline(always)]
-            fn transmute_vector(self) -> crate::Float64x4 {
-                unsafe { crate::Float64x4(_mm256_castsi256_pd(self.0) ) }
-            }
-        }
+        impl VectorBits for $name {
+            type Bits = $raw;
 
-        impl VectorTransmuteInto<$name> for crate::Float32x8 {
             #[inline(always)]
-            fn transmute_vector(self) -> $name {
-                unsafe { $name(_mm256_castps_si256(self.0) ) }
+            fn from_bits(x: $raw) -> Self {
+                Self(x)
             }
-        }
 
-        impl VectorTransmuteInto<$name> for crate::Float64x4 {
             #[inline(always)]
-            fn transmute_vector(self) -> $name {
-                unsafe { $name(_mm256_castpd_si256(self.0) ) }
+            fn to_bits(self) -> $raw {
+                self.0
             }
         }
 
-        impl From256i for $name {
+        impl From<$raw> for $name {
             #[inline(always)]
-            fn from_256i(x: __m256i) -> Self {
+            fn from(x: $raw) -> Self {
                 Self(x)
             }
         }
 
-        impl To256i for $name {
+        impl From<$name> for $raw {
             #[inline(always)]
-            fn to_256i(self) -> __m256i {
-                self.0
+            fn from(x: $name) -> Self {
+                x.0
             }
         }
 
         impl $name {
+            #[allow(clippy::useless_transmute)]
             fn _size_check() {
                 unsafe {
-                    std::mem::transmute::<[$type; $lanes], [u8; 256 / 8]>([0; $lanes]);
+                    core::mem::transmute::<[$type; $lanes], [u8; $width / 8]>([0; $lanes]);
                 }
             }
 
             #[inline(always)]
             #[must_use]
             pub fn zero() -> Self {
-                unsafe { Self(_mm256_setzero_si256()) }
+                unsafe { Self(intrinsic!(_setzero)()) }
             }
 
             #[inline(always)]
             #[must_use]
             pub fn from_array(array: [$type; $lanes]) -> Self {
-                unsafe { Self(_mm256_loadu_si256(array.as_ptr() as *const _)) }
+                unsafe { Self(intrinsic!(_loadu)(array.as_ptr() as *const _)) }
             }
 
             #[inline(always)]
@@ -107,23 +97,158 @@ macro_rules! make_vector_type {
             pub fn to_array(self) -> [$type; $lanes] {
                 unsafe {
                     let mut array: MaybeUninit<[$type; $lanes]> = MaybeUninit::uninit();
-                    _mm256_storeu_si256(array.as_mut_ptr() as *mut _, self.0);
+                    intrinsic!(_storeu)(array.as_mut_ptr() as *mut _, self.0);
                     array.assume_init()
                 }
             }
 
+            #[inline(always)]
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; $width / 8]) -> Self {
+                unsafe { Self(intrinsic!(_loadu)(bytes.as_ptr() as *const _)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_bytes(self) -> [u8; $width / 8] {
+                unsafe {
+                    let mut bytes: MaybeUninit<[u8; $width / 8]> = MaybeUninit::uninit();
+                    intrinsic!(_storeu)(bytes.as_mut_ptr() as *mut _, self.0);
+                    bytes.assume_init()
+                }
+            }
+
+            /// Stores all lanes at the beginning of `slice` and returns the initialized part.
+            /// Panics if `slice` is shorter than the vector.
+            #[inline(always)]
+            pub fn store_into_uninit(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                let slice = &mut slice[..$lanes];
+
+                unsafe {
+                    intrinsic!(_storeu)(slice.as_mut_ptr() as *mut _, self.0);
+                    slice_assume_init_mut(slice)
+                }
+            }
+
+            /// Stores as many lanes as fit in `slice` and returns the initialized part.
+            #[inline(always)]
+            pub fn write_to_uninit_slice(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                if slice.len() >= $lanes {
+                    return self.store_into_uninit(slice);
+                }
+
+                for (element, x) in slice.iter_mut().zip(self.to_array()) {
+                    element.write(x);
+                }
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Initializes every element of `slice` to `v`.
+            #[inline(always)]
+            pub fn fill_uninit(slice: &mut [MaybeUninit<$type>], v: $type) -> &mut [$type] {
+                let vector = Self::splat(v);
+
+                let mut chunks = slice.chunks_exact_mut($lanes);
+                for chunk in &mut chunks {
+                    vector.store_into_uninit(chunk);
+                }
+                vector.write_to_uninit_slice(chunks.into_remainder());
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Loads a vector from `ptr` using a volatile read, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for reads and aligned to the element size.
+            #[inline(always)]
+            #[must_use]
+            pub unsafe fn load_volatile(ptr: *const [$type; $lanes]) -> Self {
+                Self::from_array(ptr.read_volatile())
+            }
+
+            /// Stores the vector to `ptr` using a volatile write, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for writes and aligned to the element size.
+            #[inline(always)]
+            pub unsafe fn store_volatile(self, ptr: *mut [$type; $lanes]) {
+                ptr.write_volatile(self.to_array());
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn as_raw(self) -> $raw {
+                self.0
+            }
+
             /// Create mask from the most significant bit of each 8-bit element.
             #[inline(always)]
             #[must_use]
             pub fn mask(self) -> u32 {
-                unsafe { _mm256_movemask_epi8(self.0) as u32 }
+                unsafe { intrinsic!(_movemask, epi8)(self.0) as u32 }
+            }
+
+            /// Returns true if all bits are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                unsafe { intrinsic!(_testz)(self.0, self.0) != 0 }
+            }
+
+            /// Returns true if all bits are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_ones(self) -> bool {
+                unsafe { intrinsic!(_testc)(self.0, intrinsic!(_set1, epi8)(-1)) != 0 }
+            }
+
+            /// Same as `is_zero`, named to pair with `is_all_ones`.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            /// Returns true if any bits of `self` selected by `mask` are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn test(self, mask: Self) -> bool {
+                unsafe { intrinsic!(_testz)(self.0, mask.0) == 0 }
+            }
+
+            /// Returns true if bits of `self` selected by `mask` are neither all zeros nor all ones.
+            #[inline(always)]
+            #[must_use]
+            pub fn test_mixed(self, mask: Self) -> bool {
+                unsafe { intrinsic!(_testnzc)(self.0, mask.0) != 0 }
             }
 
             /// ~self & rhs
             #[inline(always)]
             #[must_use]
             pub fn andnot(self, rhs: Self) -> Self {
-                unsafe { Self(_mm256_andnot_si256(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_andnot)(self.0, rhs.0)) }
+            }
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                (mask & if_set) | mask.andnot(if_clear)
+            }
+
+            /// Concatenates every 128-bit lane of `self` (upper) and `rhs` (lower), shifts the
+            /// result right by `N` bytes and keeps the lower 128 bits.
+            #[inline(always)]
+            #[must_use]
+            pub fn align_right<const N: i32>(self, rhs: Self) -> Self {
+                unsafe { Self(paste! { [<$prefix _alignr_epi8>]::<N>(self.0, rhs.0) }) }
             }
 
             #[inline(always)]
@@ -141,19 +266,28 @@ macro_rules! make_vector_type {
 
         impl_operator! { $name, BitAnd, bitand,
             fn bitand(self, rhs: Self) -> Self::Output {
-                unsafe { Self(_mm256_and_si256(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_and)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, BitOr, bitor,
             fn bitor(self, rhs: Self) -> Self::Output {
-                unsafe { Self(_mm256_or_si256(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_or)(self.0, rhs.0)) }
             }
         }
 
         impl_operator! { $name, BitXor, bitxor,
             fn bitxor(self, rhs: Self) -> Self::Output {
-                unsafe { Self(_mm256_xor_si256(self.0, rhs.0)) }
+                unsafe { Self(intrinsic!(_xor)(self.0, rhs.0)) }
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                unsafe { Self(intrinsic!(_xor)(self.0, intrinsic!(_set1, epi8)(-1))) }
             }
         }
 
@@ -165,17 +299,108 @@ macro_rules! make_vector_type {
     };
 }
 
-make_vector_type!(Int8x32, i8, 32);
-make_vector_type!(Uint8x32, u8, 32);
+make_vector_type!(Int8x32, i8, 32, __m256i, 256, _mm256, si256);
+make_vector_type!(Uint8x32, u8, 32, __m256i, 256, _mm256, si256);
+
+make_vector_type!(Int16x16, i16, 16, __m256i, 256, _mm256, si256);
+make_vector_type!(Uint16x16, u16, 16, __m256i, 256, _mm256, si256);
+
+make_vector_type!(Int32x8, i32, 8, __m256i, 256, _mm256, si256);
+make_vector_type!(Uint32x8, u32, 8, __m256i, 256, _mm256, si256);
+
+make_vector_type!(Int64x4, i64, 4, __m256i, 256, _mm256, si256);
+make_vector_type!(Uint64x4, u64, 4, __m256i, 256, _mm256, si256);
+
+macro_rules! impl_broadcast_128 {
+    ($($name: ident, $type: ty, $lanes: expr);*) => {
+        $(
+            impl $name {
+                /// Load 128 bits from memory into both 128-bit lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn broadcast_128_from(array: &[$type; $lanes / 2]) -> Self {
+                    unsafe {
+                        let lane = _mm_loadu_si128(array.as_ptr() as *const _);
+                        Self(_mm256_broadcastsi128_si256(lane))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_broadcast_128!(
+    Int8x32, i8, 32; Uint8x32, u8, 32; Int16x16, i16, 16; Uint16x16, u16, 16;
+    Int32x8, i32, 8; Uint32x8, u32, 8; Int64x4, i64, 4; Uint64x4, u64, 4
+);
+
+macro_rules! impl_halves {
+    ($($name: ident => $half: ident),*) => {
+        $(
+            impl $name {
+                /// Lower 128 bits.
+                #[inline(always)]
+                #[must_use]
+                pub fn low(self) -> crate::$half {
+                    unsafe { crate::$half(_mm256_castsi256_si128(self.0)) }
+                }
+
+                /// Upper 128 bits.
+                #[inline(always)]
+                #[must_use]
+                pub fn high(self) -> crate::$half {
+                    unsafe { crate::$half(_mm256_extracti128_si256::<1>(self.0)) }
+                }
+
+                /// Concatenates two 128-bit vectors, `low` goes into the lower 128 bits.
+                #[inline(always)]
+                #[must_use]
+                pub fn combine(low: crate::$half, high: crate::$half) -> Self {
+                    unsafe {
+                        Self(_mm256_inserti128_si256::<1>(_mm256_castsi128_si256(low.0), high.0))
+                    }
+                }
+
+                /// Swaps the lower and the upper 128 bits.
+                #[inline(always)]
+                #[must_use]
+                pub fn swap_halves(self) -> Self {
+                    unsafe { Self(_mm256_permute2x128_si256::<0x01>(self.0, self.0)) }
+                }
+
+                /// Copies the lower 128 bits into the upper 128 bits.
+                #[inline(always)]
+                #[must_use]
+                pub fn duplicate_low_half(self) -> Self {
+                    unsafe { Self(_mm256_permute2x128_si256::<0x00>(self.0, self.0)) }
+                }
 
-make_vector_type!(Int16x16, i16, 16);
-make_vector_type!(Uint16x16, u16, 16);
+                /// Copies the upper 128 bits into the lower 128 bits.
+                #[inline(always)]
+                #[must_use]
+                pub fn duplicate_high_half(self) -> Self {
+                    unsafe { Self(_mm256_permute2x128_si256::<0x11>(self.0, self.0)) }
+                }
+            }
+        )*
+    };
+}
 
-make_vector_type!(Int32x8, i32, 8);
-make_vector_type!(Uint32x8, u32, 8);
+impl_halves!(
+    Int8x32 => Int8x16, Uint8x32 => Uint8x16, Int16x16 => Int16x8, Uint16x16 => Uint16x8,
+    Int32x8 => Int32x4, Uint32x8 => Uint32x4, Int64x4 => Int64x2, Uint64x4 => Uint64x2
+);
 
-make_vector_type!(Int64x4, i64, 4);
-make_vector_type!(Uint64x4, u64, 4);
+impl_interleave!(256:
+    Int8x32 => _mm256_unpacklo_epi8, _mm256_unpackhi_epi8,
+    Uint8x32 => _mm256_unpacklo_epi8, _mm256_unpackhi_epi8,
+    Int16x16 => _mm256_unpacklo_epi16, _mm256_unpackhi_epi16,
+    Uint16x16 => _mm256_unpacklo_epi16, _mm256_unpackhi_epi16,
+    Int32x8 => _mm256_unpacklo_epi32, _mm256_unpackhi_epi32,
+    Uint32x8 => _mm256_unpacklo_epi32, _mm256_unpackhi_epi32,
+    Int64x4 => _mm256_unpacklo_epi64, _mm256_unpackhi_epi64,
+    Uint64x4 => _mm256_unpacklo_epi64, _mm256_unpackhi_epi64
+);
 
 macro_rules! impl_basic_operations {
     (
@@ -192,6 +417,32 @@ macro_rules! impl_basic_operations {
             pub fn gt(self, rhs: Self) -> Self {
                 unsafe { Self($cmp_gt(self.0, rhs.0)) }
             }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn wrapping_neg(self) -> Self {
+                Self::zero() - self
+            }
+        }
+
+        impl ops::Neg for $signed {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                self.wrapping_neg()
+            }
+        }
+
+        impl $unsigned {
+            /// Flips the sign bits to compare as signed lanes.
+            #[inline(always)]
+            #[must_use]
+            pub fn gt(self, rhs: Self) -> Self {
+                let bias = Self::splat(1 << ($unsigned_type::BITS - 1));
+
+                $signed::from(self ^ bias).gt($signed::from(rhs ^ bias)).into()
+            }
         }
     };
 
@@ -206,12 +457,50 @@ macro_rules! impl_basic_operations {
                 unsafe { Self($splat(v as _)) }
             }
 
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_from(v: &$type) -> Self {
+                Self::splat(*v)
+            }
+
             #[inline(always)]
             #[must_use]
             pub fn eq(self, rhs: Self) -> Self {
                 unsafe { Self($cmp_eq(self.0, rhs.0)) }
             }
 
+            #[inline(always)]
+            #[must_use]
+            pub fn ne(self, rhs: Self) -> Self {
+                !self.eq(rhs)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn lt(self, rhs: Self) -> Self {
+                rhs.gt(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn le(self, rhs: Self) -> Self {
+                !self.gt(rhs)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn ge(self, rhs: Self) -> Self {
+                !rhs.gt(self)
+            }
+
+            /// Same as `self.max(min).min(max)`, lanes where `min` is greater than `max` become
+            /// `max`.
+            #[inline(always)]
+            #[must_use]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
             #[inline(always)]
             #[must_use]
             pub fn insert<const I: i32>(self, value: $type) -> Self {
@@ -285,13 +574,45 @@ impl_basic_operations!(
     _mm256_cmpgt_epi64
 );
 
+macro_rules! impl_shift_operator {
+    ($name: ident, $op: ident, $op_function: ident, $method: ident) => {
+        impl ops::$op<u32> for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: u32) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+
+        paste! {
+            impl ops::[<$op Assign>]<u32> for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: u32) {
+                    *self = self.$method(rhs);
+                }
+            }
+        }
+    };
+}
+
 macro_rules! impl_logical_shifts {
-    ($signed: ident, $unsigned: ident, $left_shift: ident, $right_shift: ident) => {
-        impl_logical_shifts!($signed, $left_shift, $right_shift);
-        impl_logical_shifts!($unsigned, $left_shift, $right_shift);
+    (
+        $signed: ident, $unsigned: ident,
+        $left_shift: ident, $right_shift: ident,
+        $left_shift_by: ident, $right_shift_by: ident
+    ) => {
+        impl_logical_shifts!($signed, $left_shift, $right_shift, $left_shift_by, $right_shift_by);
+        impl_logical_shifts!($unsigned, $left_shift, $right_shift, $left_shift_by, $right_shift_by);
+
+        impl_shift_operator!($unsigned, Shr, shr, shr_l_by);
     };
 
-    ($name: ident, $left_shift: ident, $right_shift: ident) => {
+    (
+        $name: ident,
+        $left_shift: ident, $right_shift: ident,
+        $left_shift_by: ident, $right_shift_by: ident
+    ) => {
         impl $name {
             #[inline(always)]
             #[must_use]
@@ -304,33 +625,144 @@ macro_rules! impl_logical_shifts {
             pub fn shr_l<const N: i32>(self) -> Self {
                 unsafe { Self($right_shift::<N>(self.0)) }
             }
+
+            /// Shifts every lane by the same runtime count, counts past the lane width clear it.
+            #[inline(always)]
+            #[must_use]
+            pub fn shl_by(self, n: u32) -> Self {
+                unsafe { Self($left_shift_by(self.0, _mm_cvtsi32_si128(n as i32))) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l_by(self, n: u32) -> Self {
+                unsafe { Self($right_shift_by(self.0, _mm_cvtsi32_si128(n as i32))) }
+            }
         }
+
+        impl_shift_operator!($name, Shl, shl, shl_by);
     };
 }
 
-impl_logical_shifts!(Int16x16, Uint16x16, _mm256_slli_epi16, _mm256_srli_epi16);
-impl_logical_shifts!(Int32x8, Uint32x8, _mm256_slli_epi32, _mm256_srli_epi32);
-impl_logical_shifts!(Int64x4, Uint64x4, _mm256_slli_epi64, _mm256_srli_epi64);
+impl_logical_shifts!(
+    Int16x16,
+    Uint16x16,
+    _mm256_slli_epi16,
+    _mm256_srli_epi16,
+    _mm256_sll_epi16,
+    _mm256_srl_epi16
+);
+impl_logical_shifts!(
+    Int32x8,
+    Uint32x8,
+    _mm256_slli_epi32,
+    _mm256_srli_epi32,
+    _mm256_sll_epi32,
+    _mm256_srl_epi32
+);
+impl_logical_shifts!(
+    Int64x4,
+    Uint64x4,
+    _mm256_slli_epi64,
+    _mm256_srli_epi64,
+    _mm256_sll_epi64,
+    _mm256_srl_epi64
+);
 
 macro_rules! impl_arithmetic_shift {
-    ($signed: ident, $unsigned: ident, $shift: ident) => {
-        impl_arithmetic_shift!($signed, $shift);
-        impl_arithmetic_shift!($unsigned, $shift);
+    ($name: ident, $shift: ident, $shift_by: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a<const N: i32>(self) -> Self {
+                unsafe { Self($shift::<N>(self.0)) }
+            }
+
+            /// Counts past the lane width fill it with the sign bit.
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a_by(self, n: u32) -> Self {
+                unsafe { Self($shift_by(self.0, _mm_cvtsi32_si128(n as i32))) }
+            }
+        }
+
+        impl_shift_operator!($name, Shr, shr, shr_a_by);
     };
 
-    ($name: ident, $shift: ident) => {
+    // Sign-extends the logical shift by flipping and subtracting the shifted sign bit.
+    (emulated: $name: ident, $type: ty, $unsigned_type: ty) => {
         impl $name {
             #[inline(always)]
             #[must_use]
             pub fn shr_a<const N: i32>(self) -> Self {
-                unsafe { Self($shift::<N>(self.0)) }
+                self.shr_a_by(N as u32)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a_by(self, n: u32) -> Self {
+                let n = n.min(<$type>::BITS - 1);
+                let sign = Self::splat((<$type>::MIN as $unsigned_type >> n) as $type);
+
+                (self.shr_l_by(n) ^ sign) - sign
+            }
+        }
+
+        impl_shift_operator!($name, Shr, shr, shr_a_by);
+    };
+}
+
+impl_arithmetic_shift!(Int16x16, _mm256_srai_epi16, _mm256_sra_epi16);
+impl_arithmetic_shift!(Int32x8, _mm256_srai_epi32, _mm256_sra_epi32);
+impl_arithmetic_shift!(emulated: Int64x4, i64, u64);
+
+// There are no 8-bit shifts, lanes are shifted in 16-bit lanes and bits which crossed into the
+// neighbouring lane are masked out.
+macro_rules! impl_byte_shifts {
+    ($signed: ident, $unsigned: ident, $words: ident) => {
+        impl_byte_shifts!($signed, $words);
+        impl_byte_shifts!($unsigned, $words);
+
+        impl_shift_operator!($unsigned, Shr, shr, shr_l_by);
+        impl_arithmetic_shift!(emulated: $signed, i8, u8);
+    };
+
+    ($name: ident, $words: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn shl<const N: i32>(self) -> Self {
+                self.shl_by(N as u32)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l<const N: i32>(self) -> Self {
+                self.shr_l_by(N as u32)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shl_by(self, n: u32) -> Self {
+                let shifted = self.transmute::<$words>().shl_by(n).transmute::<Self>();
+
+                shifted & Self::splat(0xffu8.checked_shl(n).unwrap_or(0) as _)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l_by(self, n: u32) -> Self {
+                let shifted = self.transmute::<$words>().shr_l_by(n).transmute::<Self>();
+
+                shifted & Self::splat(0xffu8.checked_shr(n).unwrap_or(0) as _)
             }
         }
+
+        impl_shift_operator!($name, Shl, shl, shl_by);
     };
 }
 
-impl_arithmetic_shift!(Int16x16, Uint16x16, _mm256_srai_epi16);
-impl_arithmetic_shift!(Int32x8, Uint32x8, _mm256_srai_epi32);
+impl_byte_shifts!(Int8x32, Uint8x32, Uint16x16);
 
 macro_rules! impl_comparisons {
     (
@@ -398,11 +830,55 @@ impl_comparisons!(
     _mm256_abs_epi32
 );
 
-macro_rules! impl_blend {
-    ($signed: ident, $unsigned: ident, $blend: ident) => {
-        impl_blend!($signed, $blend);
-        impl_blend!($unsigned, $blend);
-    };
+// AVX2 has no 64-bit min, max and abs. Unsigned lanes are compared as signed ones after flipping
+// their sign bits.
+macro_rules! impl_comparisons_64 {
+    ($signed: ident, $unsigned: ident, $blendv: ident) => {
+        impl $signed {
+            #[inline(always)]
+            #[must_use]
+            pub fn abs(self) -> Self {
+                let negative = Self::zero().gt(self);
+
+                (self ^ negative) - negative
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                unsafe { Self($blendv(self.0, rhs.0, self.gt(rhs).0)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                unsafe { Self($blendv(rhs.0, self.0, self.gt(rhs).0)) }
+            }
+        }
+
+        impl $unsigned {
+            #[inline(always)]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                unsafe { Self($blendv(self.0, rhs.0, self.gt(rhs).0)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                unsafe { Self($blendv(rhs.0, self.0, self.gt(rhs).0)) }
+            }
+        }
+    };
+}
+
+impl_comparisons_64!(Int64x4, Uint64x4, _mm256_blendv_epi8);
+
+macro_rules! impl_blend {
+    ($signed: ident, $unsigned: ident, $blend: ident) => {
+        impl_blend!($signed, $blend);
+        impl_blend!($unsigned, $blend);
+    };
 
     ($name: ident, $blend: ident) => {
         impl $name {
@@ -427,18 +903,747 @@ impl_blend!(
     _mm256_blend_epi32
 );
 
-impl_operator! { Int32x8, Mul, mul,
-    fn mul(self, rhs: Self) -> Self {
-        unsafe { Self(_mm256_mul_epi32(self.0, rhs.0)) }
+/// Blend with one control bit for every 8-bit lane, expanded into a constant mask.
+#[inline(always)]
+unsafe fn blend_epi8<const N: i32>(a: __m256i, b: __m256i) -> __m256i {
+    let mask = const {
+        let mut mask = [0i8; 32];
+        let mut i = 0;
+        while i < 32 {
+            mask[i] = -((N >> i) & 1) as i8;
+            i += 1;
+        }
+        mask
+    };
+
+    _mm256_blendv_epi8(a, b, _mm256_loadu_si256(mask.as_ptr().cast()))
+}
+
+/// Blend with one control bit for every 64-bit lane.
+#[inline(always)]
+unsafe fn blend_epi64<const N: i32>(a: __m256i, b: __m256i) -> __m256i {
+    let (a, b) = (_mm256_castsi256_pd(a), _mm256_castsi256_pd(b));
+
+    _mm256_castpd_si256(_mm256_blend_pd::<N>(a, b))
+}
+
+impl_blend!(Int8x32, Uint8x32, blend_epi8);
+impl_blend!(Int64x4, Uint64x4, blend_epi64);
+
+macro_rules! impl_within_lane_shuffles {
+    ($signed: ident, $unsigned: ident, $($method: ident => $shuffle: ident),*) => {
+        impl_within_lane_shuffles!($signed, $($method => $shuffle),*);
+        impl_within_lane_shuffles!($unsigned, $($method => $shuffle),*);
+    };
+
+    ($name: ident, $($method: ident => $shuffle: ident),*) => {
+        impl $name {
+            $(
+                #[inline(always)]
+                #[must_use]
+                pub fn $method<const PATTERN: i32>(self) -> Self {
+                    unsafe { Self($shuffle::<PATTERN>(self.0)) }
+                }
+            )*
+        }
+    };
+}
+
+// Every 2 bits of PATTERN select the source element for the corresponding destination element.
+// The same selection is applied to both 128-bit lanes. 16-bit shuffles only reorder the low or
+// the high four elements of each 128-bit lane and leave the other four untouched.
+impl_within_lane_shuffles!(
+    Int16x16,
+    Uint16x16,
+    shuffle_lo_within_lanes => _mm256_shufflelo_epi16,
+    shuffle_hi_within_lanes => _mm256_shufflehi_epi16
+);
+
+impl_within_lane_shuffles!(
+    Int32x8,
+    Uint32x8,
+    shuffle_within_lanes => _mm256_shuffle_epi32
+);
+
+macro_rules! impl_cross_lane_permutes {
+    (32: $($name: ident),*) => {
+        $(
+            impl $name {
+                /// Sets lane `i` to the lane of `self` selected by the low 3 bits of lane `i` of
+                /// `indices`.
+                #[inline(always)]
+                #[must_use]
+                pub fn permute(self, indices: Int32x8) -> Self {
+                    unsafe { Self(_mm256_permutevar8x32_epi32(self.0, indices.0)) }
+                }
+            }
+        )*
+    };
+
+    (64: $($name: ident),*) => {
+        $(
+            impl $name {
+                /// Every 2 bits of PATTERN select the source element for the corresponding
+                /// destination element from the whole vector.
+                #[inline(always)]
+                #[must_use]
+                pub fn permute4x64<const PATTERN: i32>(self) -> Self {
+                    unsafe { Self(_mm256_permute4x64_epi64::<PATTERN>(self.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_cross_lane_permutes!(32: Int32x8, Uint32x8);
+impl_cross_lane_permutes!(64: Int64x4, Uint64x4);
+
+macro_rules! impl_shuffle_bytes {
+    ($indices: ident, $shuffle: ident, $($name: ident),*) => {
+        $(
+            impl $name {
+                /// Sets byte `i` to the byte of the same 128-bit lane selected by the low 4 bits of
+                /// byte `i` of `indices`, or to zero if its most significant bit is set.
+                #[inline(always)]
+                #[must_use]
+                pub fn shuffle_bytes(self, indices: $indices) -> Self {
+                    unsafe { Self($shuffle(self.0, indices.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_shuffle_bytes!(Uint8x32, _mm256_shuffle_epi8, Int8x32, Uint8x32);
+
+macro_rules! impl_shuffle_bytes_crossing {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                /// Same as `shuffle_bytes`, but the low 5 bits of the indices select bytes from
+                /// the whole vector.
+                #[inline(always)]
+                #[must_use]
+                pub fn shuffle_bytes_crossing(self, indices: Uint8x32) -> Self {
+                    let low = self.duplicate_low_half().shuffle_bytes(indices);
+                    let high = self.duplicate_high_half().shuffle_bytes(indices);
+
+                    // Bit 4 of the indices picks the half, blend looks at the most significant bit.
+                    unsafe { Self(_mm256_blendv_epi8(low.0, high.0, indices.shl::<3>().0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_shuffle_bytes_crossing!(Int8x32, Uint8x32);
+
+macro_rules! impl_mul_lo {
+    ($($name: ident => $mul_lo: ident),*) => {
+        $(
+            impl $name {
+                /// Low halves of the products.
+                #[inline(always)]
+                #[must_use]
+                pub fn mul_lo(self, rhs: Self) -> Self {
+                    unsafe { Self($mul_lo(self.0, rhs.0)) }
+                }
+            }
+
+            impl_operator! { $name, Mul, mul,
+                fn mul(self, rhs: Self) -> Self {
+                    self.mul_lo(rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_mul_even_widening {
+    ($($name: ident => $wide: ident, $mul: ident),*) => {
+        $(
+            impl $name {
+                /// Full 64-bit products of the even lanes.
+                #[inline(always)]
+                #[must_use]
+                pub fn mul_even_widening(self, rhs: Self) -> $wide {
+                    unsafe { $wide($mul(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+/// There is no 8-bit multiplication, even and odd lanes are multiplied in 16-bit lanes.
+#[inline(always)]
+unsafe fn mullo_epi8(a: __m256i, b: __m256i) -> __m256i {
+    let even = _mm256_mullo_epi16(a, b);
+    let odd = _mm256_mullo_epi16(_mm256_srli_epi16::<8>(a), _mm256_srli_epi16::<8>(b));
+
+    _mm256_or_si256(
+        _mm256_and_si256(even, _mm256_set1_epi16(0xff)),
+        _mm256_slli_epi16::<8>(odd),
+    )
+}
+
+/// Low 64 bits of the products assembled from 32x32-bit partial products. The product of the
+/// high halves only affects the high 64 bits.
+#[inline(always)]
+unsafe fn mullo_epi64(a: __m256i, b: __m256i) -> __m256i {
+    let cross = _mm256_add_epi64(
+        _mm256_mul_epu32(a, _mm256_srli_epi64::<32>(b)),
+        _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), b),
+    );
+
+    _mm256_add_epi64(_mm256_mul_epu32(a, b), _mm256_slli_epi64::<32>(cross))
+}
+
+impl_mul_lo!(
+    Int8x32 => mullo_epi8,
+    Uint8x32 => mullo_epi8,
+    Int16x16 => _mm256_mullo_epi16,
+    Uint16x16 => _mm256_mullo_epi16,
+    Int32x8 => _mm256_mullo_epi32,
+    Uint32x8 => _mm256_mullo_epi32,
+    Int64x4 => mullo_epi64,
+    Uint64x4 => mullo_epi64
+);
+
+macro_rules! impl_count_ones {
+    ($($name: ident => $popcnt: ident),*) => {
+        $(
+            impl $name {
+                /// Number of set bits in every lane.
+                #[inline(always)]
+                #[must_use]
+                pub fn count_ones(self) -> Self {
+                    unsafe { Self($popcnt(self.0)) }
+                }
+            }
+        )*
+    };
+}
+
+/// Looks up the counts of the low and the high nibble of every byte and adds them.
+#[cfg(not(all(target_feature = "avx512bitalg", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi8(x: __m256i) -> __m256i {
+    const COUNTS: [u8; 16] = [0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4];
+
+    let table = _mm256_broadcastsi128_si256(_mm_loadu_si128(COUNTS.as_ptr().cast()));
+    let mask = _mm256_set1_epi8(0x0f);
+
+    let low = _mm256_shuffle_epi8(table, _mm256_and_si256(x, mask));
+    let high = _mm256_shuffle_epi8(table, _mm256_and_si256(_mm256_srli_epi16::<4>(x), mask));
+
+    _mm256_add_epi8(low, high)
+}
+
+#[cfg(not(all(target_feature = "avx512bitalg", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi16(x: __m256i) -> __m256i {
+    _mm256_maddubs_epi16(popcnt_epi8(x), _mm256_set1_epi8(1))
+}
+
+#[cfg(not(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi32(x: __m256i) -> __m256i {
+    _mm256_madd_epi16(popcnt_epi16(x), _mm256_set1_epi16(1))
+}
+
+#[cfg(not(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl")))]
+#[inline(always)]
+unsafe fn popcnt_epi64(x: __m256i) -> __m256i {
+    _mm256_sad_epu8(popcnt_epi8(x), _mm256_setzero_si256())
+}
+
+#[cfg(all(target_feature = "avx512bitalg", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi8(x: __m256i) -> __m256i {
+    _mm256_popcnt_epi8(x)
+}
+
+#[cfg(all(target_feature = "avx512bitalg", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi16(x: __m256i) -> __m256i {
+    _mm256_popcnt_epi16(x)
+}
+
+#[cfg(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi32(x: __m256i) -> __m256i {
+    _mm256_popcnt_epi32(x)
+}
+
+#[cfg(all(target_feature = "avx512vpopcntdq", target_feature = "avx512vl"))]
+#[inline(always)]
+unsafe fn popcnt_epi64(x: __m256i) -> __m256i {
+    _mm256_popcnt_epi64(x)
+}
+
+impl_count_ones!(
+    Int8x32 => popcnt_epi8,
+    Uint8x32 => popcnt_epi8,
+    Int16x16 => popcnt_epi16,
+    Uint16x16 => popcnt_epi16,
+    Int32x8 => popcnt_epi32,
+    Uint32x8 => popcnt_epi32,
+    Int64x4 => popcnt_epi64,
+    Uint64x4 => popcnt_epi64
+);
+
+impl_mul_even_widening!(
+    Int32x8 => Int64x4, _mm256_mul_epi32,
+    Uint32x8 => Uint64x4, _mm256_mul_epu32
+);
+
+impl Int16x16 {
+    /// High 16 bits of the 32-bit products.
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_mulhi_epi16(self.0, rhs.0)) }
+    }
+
+    /// Fixed-point Q15 multiplication: (self * rhs + 0x4000) >> 15
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hrs(self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_mulhrs_epi16(self.0, rhs.0)) }
+    }
+
+    /// Multiplies lanes into 32-bit products and adds adjacent pairs of them.
+    #[inline(always)]
+    #[must_use]
+    pub fn madd(self, rhs: Self) -> Int32x8 {
+        unsafe { Int32x8(_mm256_madd_epi16(self.0, rhs.0)) }
     }
 }
 
-impl_operator! { Uint32x8, Mul, mul,
-    fn mul(self, rhs: Self) -> Self {
-        unsafe { Self(_mm256_mul_epu32(self.0, rhs.0)) }
+impl Uint8x32 {
+    /// Low four bits of every element.
+    #[inline(always)]
+    #[must_use]
+    pub fn low_nibbles(self) -> Self {
+        self & Self::splat(0x0f)
+    }
+
+    /// High four bits of every element, shifted into the low four.
+    #[inline(always)]
+    #[must_use]
+    pub fn high_nibbles(self) -> Self {
+        self.shr_l::<4>()
+    }
+
+    /// Replaces every element with `table[element]`. Elements which are out of range of the
+    /// table become zero.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup16(self, table: [u8; 16]) -> Self {
+        unsafe {
+            // Saturating add keeps the low nibble of valid indices and sets the most significant
+            // bit of invalid ones, which makes the shuffle zero them.
+            let indices = _mm256_adds_epu8(self.0, _mm256_set1_epi8(0x70));
+            Self(_mm256_shuffle_epi8(Self::broadcast_128_from(&table).0, indices))
+        }
+    }
+
+    /// Same as `lookup16`, but with a 32-entry table.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup32(self, table: [u8; 32]) -> Self {
+        let [low, high]: [[u8; 16]; 2] = unsafe { core::mem::transmute(table) };
+
+        self.lookup16(low) | (self - Self::splat(16)).lookup16(high)
     }
+
+    /// Same as `lookup16`, but with a 64-entry table.
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup64(self, table: [u8; 64]) -> Self {
+        let [low, high]: [[u8; 32]; 2] = unsafe { core::mem::transmute(table) };
+
+        self.lookup32(low) | (self - Self::splat(32)).lookup32(high)
+    }
+
+    /// Multiplies unsigned lanes of `self` by signed lanes of `rhs` and adds adjacent pairs of the
+    /// products with signed saturation.
+    #[inline(always)]
+    #[must_use]
+    pub fn maddubs(self, rhs: Int8x32) -> Int16x16 {
+        unsafe { Int16x16(_mm256_maddubs_epi16(self.0, rhs.0)) }
+    }
+
+    /// Sums of absolute differences between a four-byte group of `rhs` and eight sliding windows
+    /// of `self`, within every 128-bit lane. Bits 0-1 of `N` select the group and bit 2 the
+    /// offset of the windows (0 or 4 bytes). The high lane uses bits 3-5.
+    #[inline(always)]
+    #[must_use]
+    pub fn mpsadbw<const N: i32>(self, rhs: Self) -> Uint16x16 {
+        unsafe { Uint16x16(_mm256_mpsadbw_epu8::<N>(self.0, rhs.0)) }
+    }
+}
+
+impl Uint16x16 {
+    /// High 16 bits of the 32-bit products.
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_mulhi_epu16(self.0, rhs.0)) }
+    }
+}
+
+macro_rules! impl_dot_accumulate {
+    ($name: ident, $signed: ident, $accumulator: ident, $words: ident, $dpbusd: ident) => {
+        impl $name {
+            /// Multiplies unsigned lanes of `self` by signed lanes of `rhs` and adds the four
+            /// products in every 32-bit lane to `acc`, without intermediate saturation.
+            #[cfg(all(feature = "avxvnni", target_feature = "avxvnni"))]
+            #[inline(always)]
+            #[must_use]
+            pub fn dot_accumulate(self, rhs: $signed, acc: $accumulator) -> $accumulator {
+                unsafe { $accumulator($dpbusd(acc.0, self.0, rhs.0)) }
+            }
+
+            /// Multiplies unsigned lanes of `self` by signed lanes of `rhs` and adds the four
+            /// products in every 32-bit lane to `acc`, without intermediate saturation.
+            #[cfg(not(all(feature = "avxvnni", target_feature = "avxvnni")))]
+            #[inline(always)]
+            #[must_use]
+            pub fn dot_accumulate(self, rhs: $signed, acc: $accumulator) -> $accumulator {
+                // Products of bytes extended to 16 bits fit into 16 bits, unlike the pair sums
+                // of `maddubs`, so even and odd bytes are multiplied separately.
+                let (a, b) = (self.transmute::<$words>(), rhs.transmute::<$words>());
+                let even = (a & $words::splat(0xff)).madd(b.shl::<8>().shr_a::<8>());
+                let odd = a.shr_l::<8>().madd(b.shr_a::<8>());
+
+                acc + even + odd
+            }
+        }
+    };
 }
 
+impl_dot_accumulate!(Uint8x32, Int8x32, Int32x8, Int16x16, _mm256_dpbusd_avx_epi32);
+
+macro_rules! impl_saturating_arithmetic {
+    ($name: ident, $adds: ident, $subs: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                unsafe { Self($adds(self.0, rhs.0)) }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                unsafe { Self($subs(self.0, rhs.0)) }
+            }
+        }
+    };
+}
+
+impl_saturating_arithmetic!(Int8x32, _mm256_adds_epi8, _mm256_subs_epi8);
+impl_saturating_arithmetic!(Uint8x32, _mm256_adds_epu8, _mm256_subs_epu8);
+impl_saturating_arithmetic!(Int16x16, _mm256_adds_epi16, _mm256_subs_epi16);
+impl_saturating_arithmetic!(Uint16x16, _mm256_adds_epu16, _mm256_subs_epu16);
+
+macro_rules! impl_average {
+    ($($name: ident => $avg: ident),*) => {
+        $(
+            impl $name {
+                /// (self + rhs + 1) >> 1, computed without overflow.
+                #[inline(always)]
+                #[must_use]
+                pub fn avg(self, rhs: Self) -> Self {
+                    unsafe { Self($avg(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_average!(Uint8x32 => _mm256_avg_epu8, Uint16x16 => _mm256_avg_epu16);
+
+macro_rules! impl_horizontal {
+    ($($name: ident, $hadd: ident, $hsub: ident, $add: ident, $sub: ident);* $(;)?) => {
+        $(
+            impl $name {
+                /// Adds adjacent pairs of lanes. Within every 128-bit lane the lower half of the
+                /// result comes from `self` and the upper half from `rhs`.
+                #[inline(always)]
+                #[must_use]
+                pub fn $hadd(self, rhs: Self) -> Self {
+                    unsafe { Self($add(self.0, rhs.0)) }
+                }
+
+                /// Same as the addition, but subtracts odd lanes from even ones.
+                #[inline(always)]
+                #[must_use]
+                pub fn $hsub(self, rhs: Self) -> Self {
+                    unsafe { Self($sub(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_horizontal!(
+    Int16x16, hadd, hsub, _mm256_hadd_epi16, _mm256_hsub_epi16;
+    Uint16x16, hadd, hsub, _mm256_hadd_epi16, _mm256_hsub_epi16;
+    Int32x8, hadd, hsub, _mm256_hadd_epi32, _mm256_hsub_epi32;
+    Uint32x8, hadd, hsub, _mm256_hadd_epi32, _mm256_hsub_epi32;
+    Int16x16, saturating_hadd, saturating_hsub, _mm256_hadds_epi16, _mm256_hsubs_epi16;
+);
+
+macro_rules! impl_pack {
+    ($($name: ident => $signed: ident, $unsigned: ident, $packs: ident, $packus: ident),*) => {
+        $(
+            impl $name {
+                /// Narrows lanes with signed saturation. Within every 128-bit lane the lower half
+                /// of the result comes from `self` and the upper half from `rhs`.
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating(self, rhs: Self) -> $signed {
+                    unsafe { $signed($packs(self.0, rhs.0)) }
+                }
+
+                /// Same as `pack_saturating`, but with unsigned saturation.
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating_unsigned(self, rhs: Self) -> $unsigned {
+                    unsafe { $unsigned($packus(self.0, rhs.0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_pack!(
+    Int16x16 => Int8x32, Uint8x32, _mm256_packs_epi16, _mm256_packus_epi16,
+    Int32x8 => Int16x16, Uint16x16, _mm256_packs_epi32, _mm256_packus_epi32
+);
+
+macro_rules! impl_narrow {
+    ($($name: ident => $signed: ident, $unsigned: ident),*) => {
+        $(
+            impl $name {
+                /// Narrows lanes with signed saturation, lanes of `self` followed by lanes of
+                /// `rhs`.
+                #[inline(always)]
+                #[must_use]
+                pub fn narrow_saturating(self, rhs: Self) -> $signed {
+                    let packed = self.pack_saturating(rhs).0;
+                    unsafe { $signed(_mm256_permute4x64_epi64::<0b11_01_10_00>(packed)) }
+                }
+
+                /// Same as `narrow_saturating`, but with unsigned saturation.
+                #[inline(always)]
+                #[must_use]
+                pub fn narrow_saturating_unsigned(self, rhs: Self) -> $unsigned {
+                    let packed = self.pack_saturating_unsigned(rhs).0;
+                    unsafe { $unsigned(_mm256_permute4x64_epi64::<0b11_01_10_00>(packed)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_narrow!(Int16x16 => Int8x32, Uint8x32, Int32x8 => Int16x16, Uint16x16);
+
+macro_rules! impl_abs_diff {
+    ($signed: ident, $unsigned: ident) => {
+        impl $signed {
+            /// |self - rhs|, which always fits in the unsigned lanes.
+            #[inline(always)]
+            #[must_use]
+            pub fn abs_diff(self, rhs: Self) -> $unsigned {
+                (self.max(rhs) - self.min(rhs)).into()
+            }
+        }
+
+        impl $unsigned {
+            #[inline(always)]
+            #[must_use]
+            pub fn abs_diff(self, rhs: Self) -> Self {
+                self.max(rhs) - self.min(rhs)
+            }
+        }
+    };
+
+    ($signed: ident, $unsigned: ident, $sign: expr) => {
+        impl $signed {
+            /// |self - rhs|, which always fits in the unsigned lanes.
+            #[inline(always)]
+            #[must_use]
+            pub fn abs_diff(self, rhs: Self) -> $unsigned {
+                let difference = self - rhs;
+                let negative = rhs.gt(self);
+
+                ((difference ^ negative) - negative).into()
+            }
+        }
+
+        impl $unsigned {
+            #[inline(always)]
+            #[must_use]
+            pub fn abs_diff(self, rhs: Self) -> Self {
+                // Flipping the sign bits makes the signed comparison unsigned and doesn't change
+                // the difference.
+                let bias = $signed::splat($sign);
+
+                ($signed::from(self) ^ bias).abs_diff($signed::from(rhs) ^ bias)
+            }
+        }
+    };
+}
+
+impl_abs_diff!(Int8x32, Uint8x32);
+impl_abs_diff!(Int16x16, Uint16x16);
+impl_abs_diff!(Int32x8, Uint32x8);
+impl_abs_diff!(Int64x4, Uint64x4, i64::MIN);
+
+impl Uint8x32 {
+    /// Sums of absolute differences of every eight consecutive lanes.
+    #[inline(always)]
+    #[must_use]
+    pub fn sad(self, rhs: Self) -> Uint64x4 {
+        unsafe { Uint64x4(_mm256_sad_epu8(self.0, rhs.0)) }
+    }
+}
+
+macro_rules! impl_bit_extract_deposit {
+    ($signed: ident, $unsigned: ident, $unsigned_type: ty, $pext: ident, $pdep: ident) => {
+        impl_bit_extract_deposit!($signed, $unsigned_type, $pext, $pdep);
+        impl_bit_extract_deposit!($unsigned, $unsigned_type, $pext, $pdep);
+    };
+
+    ($name: ident, $unsigned_type: ty, $pext: ident, $pdep: ident) => {
+        impl $name {
+            /// Per-lane `pext`: gathers bits selected by `mask` into the low bits of the lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn extract_bits(self, mask: Self) -> Self {
+                let (x, mask) = (self.to_array(), mask.to_array());
+
+                Self::from_array(core::array::from_fn(|i| {
+                    let (x, mask) = (x[i] as $unsigned_type, mask[i] as $unsigned_type);
+
+                    #[cfg(target_feature = "bmi2")]
+                    let result = unsafe { $pext(x, mask) };
+                    #[cfg(not(target_feature = "bmi2"))]
+                    let result = crate::util::extract_bits(x as u64, mask as u64) as $unsigned_type;
+
+                    result as _
+                }))
+            }
+
+            /// Per-lane `pdep`: scatters low bits of the lane to positions of bits set in `mask`.
+            #[inline(always)]
+            #[must_use]
+            pub fn deposit_bits(self, mask: Self) -> Self {
+                let (x, mask) = (self.to_array(), mask.to_array());
+
+                Self::from_array(core::array::from_fn(|i| {
+                    let (x, mask) = (x[i] as $unsigned_type, mask[i] as $unsigned_type);
+
+                    #[cfg(target_feature = "bmi2")]
+                    let result = unsafe { $pdep(x, mask) };
+                    #[cfg(not(target_feature = "bmi2"))]
+                    let result = crate::util::deposit_bits(x as u64, mask as u64) as $unsigned_type;
+
+                    result as _
+                }))
+            }
+        }
+    };
+}
+
+impl_bit_extract_deposit!(Int32x8, Uint32x8, u32, _pext_u32, _pdep_u32);
+impl_bit_extract_deposit!(Int64x4, Uint64x4, u64, _pext_u64, _pdep_u64);
+
+/// `shuffle_epi8` indices which reverse the byte order of every `size`-byte element.
+pub(crate) const fn swap_bytes_indices<const N: usize>(size: usize) -> [u8; N] {
+    let mut indices = [0; N];
+
+    let mut i = 0;
+    while i < indices.len() {
+        let position = i % 16;
+        indices[i] = (position / size * size + size - 1 - position % size) as u8;
+        i += 1;
+    }
+
+    indices
+}
+
+macro_rules! impl_swap_bytes {
+    ($signed: ident, $unsigned: ident, $size: expr, $bytes: ident, $shuffle: ident) => {
+        impl_swap_bytes!($signed, $size, $bytes, $shuffle);
+        impl_swap_bytes!($unsigned, $size, $bytes, $shuffle);
+    };
+
+    ($name: ident, $size: expr, $bytes: ident, $shuffle: ident) => {
+        impl $name {
+            /// Reverses the byte order of every lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn swap_bytes(self) -> Self {
+                const INDICES: [u8; size_of::<$name>()] = swap_bytes_indices($size);
+
+                unsafe { Self($shuffle(self.0, $bytes::from_array(INDICES).0)) }
+            }
+        }
+    };
+}
+
+impl_swap_bytes!(Int16x16, Uint16x16, 2, Uint8x32, _mm256_shuffle_epi8);
+impl_swap_bytes!(Int32x8, Uint32x8, 4, Uint8x32, _mm256_shuffle_epi8);
+impl_swap_bytes!(Int64x4, Uint64x4, 8, Uint8x32, _mm256_shuffle_epi8);
+
+/// Reverses the bits of every byte by looking up both nibbles reversed and swapping them.
+#[inline(always)]
+unsafe fn reverse_bits_epi8(x: __m256i) -> __m256i {
+    const REVERSED: [u8; 16] = [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15];
+
+    let table = _mm256_broadcastsi128_si256(_mm_loadu_si128(REVERSED.as_ptr().cast()));
+    let mask = _mm256_set1_epi8(0x0f);
+
+    let low = _mm256_shuffle_epi8(table, _mm256_and_si256(x, mask));
+    let high = _mm256_shuffle_epi8(table, _mm256_and_si256(_mm256_srli_epi16::<4>(x), mask));
+
+    _mm256_or_si256(_mm256_slli_epi16::<4>(low), high)
+}
+
+macro_rules! impl_reverse_bits {
+    ($reverse: ident, $($bytes: ident),*; $($name: ident),*) => {
+        $(
+            impl $bytes {
+                /// Reverses the order of bits in every lane.
+                #[inline(always)]
+                #[must_use]
+                pub fn reverse_bits(self) -> Self {
+                    unsafe { Self($reverse(self.0)) }
+                }
+            }
+        )*
+
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn reverse_bits(self) -> Self {
+                    unsafe { Self($reverse(self.swap_bytes().0)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_reverse_bits!(
+    reverse_bits_epi8, Int8x32, Uint8x32;
+    Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+);
+
 macro_rules! impl_signedness_casts {
     ($signed: ident, $unsigned: ident) => {
         impl From<$signed> for $unsigned {
@@ -482,10 +1687,3 @@ impl VectorConvertInto<crate::Float32x8> for Int32x8 {
         unsafe { crate::Float32x8(_mm256_cvtepi32_ps(self.0)) }
     }
 }
-
-impl<ToV: From256i, FromV: To256i> VectorTransmuteInto<ToV> for FromV {
-    #[inline(always)]
-    fn transmute_vector(self) -> ToV {
-        ToV::from_256i(self.to_256i())
-    }
-}
\ No newline at end of file
@@ -0,0 +1,175 @@
+use core::ops;
+
+use crate::{Float32x8, Int32x8};
+
+/// Smallest float greater than every lane, keeping NaN and +infinity unchanged.
+#[inline(always)]
+fn next_up(x: Float32x8) -> Float32x8 {
+    let bits: Int32x8 = x.transmute();
+    let zero = Float32x8::zero();
+
+    // Comparison masks are -1 in selected lanes.
+    let increment: Int32x8 = (x.gt(zero) & x.ne(Float32x8::splat(f32::INFINITY))).transmute();
+    let decrement: Int32x8 = x.lt(zero).transmute();
+    let bits = bits - increment + decrement;
+
+    // Both zeros step to the smallest positive subnormal.
+    let is_zero: Int32x8 = x.eq(zero).transmute();
+    let bits = is_zero.andnot(bits) | (is_zero & Int32x8::splat(1));
+
+    bits.transmute()
+}
+
+/// Largest float smaller than every lane, keeping NaN and -infinity unchanged.
+#[inline(always)]
+fn next_down(x: Float32x8) -> Float32x8 {
+    let sign = Float32x8::splat(-0.0);
+
+    next_up(x ^ sign) ^ sign
+}
+
+/// Replaces NaN lanes of `x` with lanes of `value`.
+#[inline(always)]
+fn replace_nan(x: Float32x8, value: Float32x8) -> Float32x8 {
+    Float32x8::bitselect(x.eq(x), x, value)
+}
+
+/// Lower bound of a result, NaNs (from operations like inf - inf) become -infinity.
+#[inline(always)]
+fn lower(x: Float32x8) -> Float32x8 {
+    next_down(replace_nan(x, Float32x8::splat(f32::NEG_INFINITY)))
+}
+
+/// Upper bound of a result, NaNs (from operations like inf - inf) become +infinity.
+#[inline(always)]
+fn upper(x: Float32x8) -> Float32x8 {
+    next_up(replace_nan(x, Float32x8::splat(f32::INFINITY)))
+}
+
+/// Eight closed intervals `[lo, hi]` of real numbers. Results of arithmetic operations are
+/// computed in round-to-nearest mode and then widened outwards by one ulp, so they always
+/// contain all results of applying the operation to values from the operands, at the cost of
+/// being up to one ulp wider than necessary.
+#[derive(Copy, Clone, Debug)]
+pub struct IntervalF32x8 {
+    pub lo: Float32x8,
+    pub hi: Float32x8,
+}
+
+impl IntervalF32x8 {
+    #[inline(always)]
+    #[must_use]
+    pub fn new(lo: Float32x8, hi: Float32x8) -> Self {
+        Self { lo, hi }
+    }
+
+    /// Degenerate intervals containing exactly `x`.
+    #[inline(always)]
+    #[must_use]
+    pub fn point(x: Float32x8) -> Self {
+        Self::new(x, x)
+    }
+
+    /// Intervals containing all real numbers.
+    #[inline(always)]
+    #[must_use]
+    pub fn entire() -> Self {
+        Self::new(
+            Float32x8::splat(f32::NEG_INFINITY),
+            Float32x8::splat(f32::INFINITY),
+        )
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn width(self) -> Float32x8 {
+        next_up(self.hi - self.lo)
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn midpoint(self) -> Float32x8 {
+        (self.lo + self.hi) * Float32x8::splat(0.5)
+    }
+
+    /// Returns a mask of lanes where `x` lies within the interval.
+    #[inline(always)]
+    #[must_use]
+    pub fn contains(self, x: Float32x8) -> Float32x8 {
+        self.lo.le(x) & x.le(self.hi)
+    }
+
+    /// Smallest intervals containing results of `op` applied to all pairs of bounds.
+    #[inline(always)]
+    fn combine_bounds(self, rhs: Self, op: impl Fn(Float32x8, Float32x8) -> Float32x8) -> Self {
+        let bounds = [
+            op(self.lo, rhs.lo),
+            op(self.lo, rhs.hi),
+            op(self.hi, rhs.lo),
+            op(self.hi, rhs.hi),
+        ];
+
+        let negative_infinity = Float32x8::splat(f32::NEG_INFINITY);
+        let positive_infinity = Float32x8::splat(f32::INFINITY);
+
+        let lo = bounds
+            .map(|x| replace_nan(x, negative_infinity))
+            .into_iter()
+            .reduce(Float32x8::min)
+            .unwrap();
+        let hi = bounds
+            .map(|x| replace_nan(x, positive_infinity))
+            .into_iter()
+            .reduce(Float32x8::max)
+            .unwrap();
+
+        Self::new(next_down(lo), next_up(hi))
+    }
+}
+
+impl ops::Add for IntervalF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(lower(self.lo + rhs.lo), upper(self.hi + rhs.hi))
+    }
+}
+
+impl ops::Sub for IntervalF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(lower(self.lo - rhs.hi), upper(self.hi - rhs.lo))
+    }
+}
+
+impl ops::Mul for IntervalF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        // Products of bounds are NaN only for 0 * infinity, which is 0 in interval arithmetic.
+        self.combine_bounds(rhs, |a, b| replace_nan(a * b, Float32x8::zero()))
+    }
+}
+
+/// Division by intervals containing zero results in [`IntervalF32x8::entire`].
+impl ops::Div for IntervalF32x8 {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self {
+        let zero = Float32x8::zero();
+        let contains_zero = rhs.lo.le(zero) & rhs.hi.ge(zero);
+
+        let quotient = self.combine_bounds(rhs, |a, b| a / b);
+        let entire = Self::entire();
+
+        Self::new(
+            (contains_zero & entire.lo) | contains_zero.andnot(quotient.lo),
+            (contains_zero & entire.hi) | contains_zero.andnot(quotient.hi),
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use crate::SimdVector;
+
+/// Extension methods which process scalar iterators one vector at a time.
+pub trait SimdIterExt: Iterator + Sized {
+    /// Buffers the iterator into vectors of type `V`, applies `f` to every vector and yields
+    /// resulting lanes one by one. The last vector is padded with zeros, results of padding lanes
+    /// are discarded.
+    #[inline]
+    fn simd_map<V, W, F>(self, f: F) -> SimdMap<Self, V, W, F>
+    where
+        V: SimdVector<Element = Self::Item>,
+        W: SimdVector,
+        F: FnMut(V) -> W,
+    {
+        assert_eq!(
+            V::LANES,
+            W::LANES,
+            "Mapped vector must have the same number of lanes."
+        );
+
+        SimdMap {
+            iter: self,
+            f,
+            output: W::zero().to_array(),
+            position: 0,
+            count: 0,
+            _input: core::marker::PhantomData,
+        }
+    }
+
+    /// Buffers the iterator into vectors of type `V` and folds them using `f`. The last vector
+    /// is padded with `padding`, which should be the identity of the fold operation.
+    #[inline]
+    fn simd_fold<V, F>(mut self, init: V, padding: Self::Item, mut f: F) -> V
+    where
+        V: SimdVector<Element = Self::Item>,
+        F: FnMut(V, V) -> V,
+    {
+        let padding = V::splat(padding);
+        let mut accumulator = init;
+
+        loop {
+            let (vector, count) = next_vector(&mut self, padding);
+            if count == 0 {
+                return accumulator;
+            }
+
+            accumulator = f(accumulator, vector);
+
+            if count < V::LANES {
+                return accumulator;
+            }
+        }
+    }
+}
+
+impl<I: Iterator> SimdIterExt for I {}
+
+/// Fills the lanes of `vector` with consecutive iterator items, returning the vector and
+/// the number of lanes which were filled.
+#[inline(always)]
+fn next_vector<V: SimdVector, I: Iterator<Item = V::Element>>(
+    iter: &mut I,
+    vector: V,
+) -> (V, usize) {
+    let mut array = vector.to_array();
+    let mut count = 0;
+
+    for (lane, item) in array.as_mut().iter_mut().zip(iter) {
+        *lane = item;
+        count += 1;
+    }
+
+    (V::from_array(array), count)
+}
+
+/// Iterator returned by [`SimdIterExt::simd_map`].
+pub struct SimdMap<I, V, W: SimdVector, F> {
+    iter: I,
+    f: F,
+    output: W::Array,
+    position: usize,
+    count: usize,
+    _input: core::marker::PhantomData<V>,
+}
+
+impl<I, V, W, F> Iterator for SimdMap<I, V, W, F>
+where
+    I: Iterator,
+    V: SimdVector<Element = I::Item>,
+    W: SimdVector,
+    F: FnMut(V) -> W,
+{
+    type Item = W::Element;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position == self.count {
+            let (vector, count) = next_vector(&mut self.iter, V::zero());
+            if count == 0 {
+                return None;
+            }
+
+            self.output = (self.f)(vector).to_array();
+            self.position = 0;
+            self.count = count;
+        }
+
+        let item = self.output.as_ref()[self.position];
+        self.position += 1;
+
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.count - self.position;
+        let (lower, upper) = self.iter.size_hint();
+
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
@@ -0,0 +1,130 @@
+//! Kernels operating on whole slices, processing them one vector at a time.
+
+use crate::util::abs;
+use crate::Float64x4;
+
+/// Loads 4 values from `chunk`, which must have exactly 4 elements.
+#[inline(always)]
+fn load(chunk: &[f64]) -> Float64x4 {
+    Float64x4::from_array(chunk.try_into().unwrap())
+}
+
+/// Neumaier's improvement of Kahan summation: adds `x` to `sum`, accumulating the rounding
+/// error into `compensation`.
+#[inline(always)]
+fn add_compensated(sum: f64, compensation: f64, x: f64) -> (f64, f64) {
+    let t = sum + x;
+
+    let error = if sum.abs() >= x.abs() {
+        (sum - t) + x
+    } else {
+        (x - t) + sum
+    };
+
+    (t, compensation + error)
+}
+
+/// Sums `values` using compensated (Kahan-Babuška-Neumaier) summation, which keeps the error
+/// bound independent of the number of values. Four independent compensated sums are kept
+/// in vector lanes and combined at the end.
+#[must_use]
+pub fn sum_compensated(values: &[f64]) -> f64 {
+    let (sum, compensation) = sum_compensated_parts(values);
+
+    sum + compensation
+}
+
+/// Compensated sum of `values` and its accumulated rounding error.
+#[inline(always)]
+fn sum_compensated_parts(values: &[f64]) -> (f64, f64) {
+    let mut sum = Float64x4::zero();
+    let mut compensation = Float64x4::zero();
+
+    let chunks = values.chunks_exact(4);
+    let remainder = chunks.remainder();
+
+    for chunk in chunks {
+        let x = load(chunk);
+        let t = sum + x;
+
+        // Pick lanes of the first error term where |sum| >= |x|.
+        let sum_larger = abs(sum).ge(abs(x));
+        let error_sum_larger = (sum - t) + x;
+        let error_x_larger = (x - t) + sum;
+        let error = Float64x4::bitselect(sum_larger, error_sum_larger, error_x_larger);
+
+        sum = t;
+        compensation += error;
+    }
+
+    sum.to_array()
+        .into_iter()
+        .chain(compensation.to_array())
+        .chain(remainder.iter().copied())
+        .fold((0.0, 0.0), |(sum, compensation), x| {
+            add_compensated(sum, compensation, x)
+        })
+}
+
+/// Sums `values` using pairwise summation, which has error growing with the logarithm of the
+/// number of values while being almost as fast as plain summation.
+#[must_use]
+pub fn sum_pairwise(values: &[f64]) -> f64 {
+    const BLOCK_SIZE: usize = 256;
+
+    if values.len() > BLOCK_SIZE {
+        let (left, right) = values.split_at(values.len() / 2);
+
+        return sum_pairwise(left) + sum_pairwise(right);
+    }
+
+    let chunks = values.chunks_exact(4);
+    let remainder = chunks.remainder();
+
+    let sum = chunks.fold(Float64x4::zero(), |sum, chunk| sum + load(chunk));
+    let [a, b, c, d] = sum.to_array();
+
+    (a + b) + (c + d) + remainder.iter().sum::<f64>()
+}
+
+/// Number of values summed by a single task of the parallel kernels, small enough to fit in the
+/// L2 cache.
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_SIZE: usize = 1 << 14;
+
+/// Same as `sum_compensated`, but sums chunks of `values` on the rayon thread pool and combines
+/// their sums and rounding errors with compensated summation.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_sum_compensated(values: &[f64]) -> f64 {
+    use rayon::prelude::*;
+
+    let (sum, compensation) = values
+        .par_chunks(PARALLEL_CHUNK_SIZE)
+        .map(sum_compensated_parts)
+        .reduce(
+            || (0.0, 0.0),
+            |(sum, compensation), (chunk_sum, chunk_compensation)| {
+                let (sum, compensation) = add_compensated(sum, compensation, chunk_sum);
+
+                (sum, compensation + chunk_compensation)
+            },
+        );
+
+    sum + compensation
+}
+
+/// Same as `sum_pairwise`, but sums both halves of large slices on the rayon thread pool. Halves
+/// are split at the same points, so the result is identical to `sum_pairwise`.
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_sum_pairwise(values: &[f64]) -> f64 {
+    if values.len() <= PARALLEL_CHUNK_SIZE {
+        return sum_pairwise(values);
+    }
+
+    let (left, right) = values.split_at(values.len() / 2);
+    let (left, right) = rayon::join(|| par_sum_pairwise(left), || par_sum_pairwise(right));
+
+    left + right
+}
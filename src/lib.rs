@@ -1,10 +1,122 @@
-#[cfg(not(target_feature = "avx2"))]
-compile_error!("This library requires AVX2 CPU feature.");
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Either the `std` or the `libm` feature must be enabled for float functions.");
+
+mod bfloat16;
+mod bit_scan;
+mod bitmask;
+mod carry;
+mod clmul;
 mod conversion;
+mod division;
+mod double;
+mod dual;
+mod endian;
+mod gfni;
+mod gray;
+mod interval;
+mod iter;
+mod lut;
+mod morton;
+mod reduction;
+mod rotate;
+mod shuffle;
+mod traits;
+mod util;
+mod wide;
+mod widening;
+mod wrappers;
 
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+mod arch;
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+#[macro_use]
 mod float_256;
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+mod float_128;
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+#[macro_use]
 mod integer_256;
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+mod integer_128;
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+pub mod avx512;
+#[cfg(feature = "std")]
+pub mod dispatch;
+pub mod geometry;
+pub mod kernels;
+pub mod noise;
+#[cfg(feature = "std")]
+pub mod particles;
+
+#[doc(hidden)]
+pub mod swizzle;
+
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod arbitrary;
+#[cfg(feature = "checked")]
+mod checked;
+#[cfg(feature = "rand")]
+mod random;
+
+#[cfg(feature = "reference")]
+pub mod reference;
+#[cfg(all(
+    not(feature = "reference"),
+    any(not(target_feature = "sse4.1"), feature = "soft")
+))]
+mod reference;
+
+pub use bfloat16::*;
+pub use bitmask::*;
+pub use division::DivisorInt32x8;
+pub use double::*;
+pub use dual::*;
+pub use interval::*;
+pub use iter::*;
+pub use lut::*;
+pub use traits::*;
+pub use wide::*;
+pub use wrappers::*;
 
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+pub use float_128::*;
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
 pub use float_256::*;
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+pub use integer_128::*;
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
 pub use integer_256::*;
+
+// Without SSE4.1 enabled at compile time, or with the `soft` feature, vector types are the scalar
+// reference implementations.
+#[cfg(any(not(target_feature = "sse4.1"), feature = "soft"))]
+pub use reference::*;
+
+#[cfg(feature = "checked")]
+pub use checked::*;
+#[cfg(feature = "rand")]
+pub use random::*;
+
+/// True if vector types use AVX2 intrinsics or pairs of SSE registers, false if they use the
+/// scalar fallback because the crate was compiled without the `sse4.1` target feature or with the
+/// `soft` feature.
+pub const IS_ACCELERATED: bool = cfg!(all(target_feature = "sse4.1", not(feature = "soft")));
+
+/// Returns true if the CPU executing the program supports AVX2. Binaries compiled without
+/// the `avx2` target feature can use it to pick a separately built accelerated code path.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn is_supported() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        false
+    }
+}
@@ -0,0 +1,78 @@
+/// Addressing mode used by `lut_interpolate` for positions outside of the table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LutAddressing {
+    /// Positions are clamped to the first and the last entry.
+    Clamp,
+    /// Positions wrap around, interpolating between the last and the first entry.
+    Wrap,
+}
+
+macro_rules! impl_lut_for_backend {
+    ($($backend: ident)::*) => {
+        impl $($backend)::*::Float32x8 {
+            /// Linearly interpolates `table` at fractional positions `x`, where integer positions
+            /// correspond to table entries. NaN positions are treated as 0.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `table` is empty or has more than `i32::MAX` entries.
+            #[inline(always)]
+            #[must_use]
+            pub fn lut_interpolate(table: &[f32], x: Self, addressing: LutAddressing) -> Self {
+                use $($backend)::*::Int32x8;
+
+                assert!(
+                    !table.is_empty() && table.len() <= i32::MAX as usize,
+                    "Invalid lookup table size."
+                );
+
+                let length = table.len() as f32;
+                let zero = Self::zero();
+
+                // `max` returns its second operand, so NaN positions become 0.
+                let x = match addressing {
+                    LutAddressing::Clamp => x.max(zero).min(Self::splat(length - 1.0)),
+                    LutAddressing::Wrap => {
+                        let length = Self::splat(length);
+
+                        (x - (x / length).floor() * length).max(zero)
+                    }
+                };
+
+                let cell = x.floor();
+                let t = x - cell;
+
+                // Indices are clamped again, so that they stay in bounds even if computing
+                // positions rounded up.
+                let last = Int32x8::splat(table.len() as i32 - 1);
+                let one = Int32x8::splat(1);
+                let i0 = cell.convert::<Int32x8>().max(Int32x8::zero());
+
+                let (i0, i1) = match addressing {
+                    LutAddressing::Clamp => {
+                        let i0 = i0.min(last);
+
+                        (i0, (i0 + one).min(last))
+                    }
+                    LutAddressing::Wrap => {
+                        let wrap = |i: Int32x8| i.gt(last).andnot(i);
+                        let i0 = wrap(i0.min(last + one));
+
+                        (i0, wrap(i0 + one))
+                    }
+                };
+
+                let (a, b) = unsafe {
+                    (Self::gather_unchecked(table, i0), Self::gather_unchecked(table, i1))
+                };
+
+                a + (b - a) * t
+            }
+        }
+    };
+}
+
+impl_lut_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_lut_for_backend!(crate::reference);
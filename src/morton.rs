@@ -0,0 +1,86 @@
+macro_rules! impl_morton {
+    ($name: ty) => {
+        impl $name {
+            /// Spreads the low 16 bits of every lane so that there is one zero bit between each
+            /// of them.
+            #[inline(always)]
+            fn part_1_by_1(self) -> Self {
+                let x = self & Self::splat(0x0000ffff);
+                let x = (x | x.shl::<8>()) & Self::splat(0x00ff00ff);
+                let x = (x | x.shl::<4>()) & Self::splat(0x0f0f0f0f);
+                let x = (x | x.shl::<2>()) & Self::splat(0x33333333);
+                (x | x.shl::<1>()) & Self::splat(0x55555555)
+            }
+
+            /// Inverse of `part_1_by_1`.
+            #[inline(always)]
+            fn compact_1_by_1(self) -> Self {
+                let x = self & Self::splat(0x55555555);
+                let x = (x | x.shr_l::<1>()) & Self::splat(0x33333333);
+                let x = (x | x.shr_l::<2>()) & Self::splat(0x0f0f0f0f);
+                let x = (x | x.shr_l::<4>()) & Self::splat(0x00ff00ff);
+                (x | x.shr_l::<8>()) & Self::splat(0x0000ffff)
+            }
+
+            /// Spreads the low 10 bits of every lane so that there are two zero bits between
+            /// each of them.
+            #[inline(always)]
+            fn part_1_by_2(self) -> Self {
+                let x = self & Self::splat(0x000003ff);
+                let x = (x | x.shl::<16>()) & Self::splat(0xff0000ff);
+                let x = (x | x.shl::<8>()) & Self::splat(0x0300f00f);
+                let x = (x | x.shl::<4>()) & Self::splat(0x030c30c3);
+                (x | x.shl::<2>()) & Self::splat(0x09249249)
+            }
+
+            /// Inverse of `part_1_by_2`.
+            #[inline(always)]
+            fn compact_1_by_2(self) -> Self {
+                let x = self & Self::splat(0x09249249);
+                let x = (x | x.shr_l::<2>()) & Self::splat(0x030c30c3);
+                let x = (x | x.shr_l::<4>()) & Self::splat(0x0300f00f);
+                let x = (x | x.shr_l::<8>()) & Self::splat(0xff0000ff);
+                (x | x.shr_l::<16>()) & Self::splat(0x000003ff)
+            }
+
+            /// Interleaves the low 16 bits of `x` and `y` into 32-bit Z-order codes. Bits of `x`
+            /// occupy the even bit positions.
+            #[inline(always)]
+            #[must_use]
+            pub fn morton_encode_2d(x: Self, y: Self) -> Self {
+                x.part_1_by_1() | y.part_1_by_1().shl::<1>()
+            }
+
+            /// Splits 32-bit Z-order codes into (x, y) coordinates.
+            #[inline(always)]
+            #[must_use]
+            pub fn morton_decode_2d(self) -> (Self, Self) {
+                (self.compact_1_by_1(), self.shr_l::<1>().compact_1_by_1())
+            }
+
+            /// Interleaves the low 10 bits of `x`, `y` and `z` into 30-bit Z-order codes. Bits of
+            /// `x` occupy bit positions which are multiples of 3.
+            #[inline(always)]
+            #[must_use]
+            pub fn morton_encode_3d(x: Self, y: Self, z: Self) -> Self {
+                x.part_1_by_2() | y.part_1_by_2().shl::<1>() | z.part_1_by_2().shl::<2>()
+            }
+
+            /// Splits 30-bit Z-order codes into (x, y, z) coordinates.
+            #[inline(always)]
+            #[must_use]
+            pub fn morton_decode_3d(self) -> (Self, Self, Self) {
+                (
+                    self.compact_1_by_2(),
+                    self.shr_l::<1>().compact_1_by_2(),
+                    self.shr_l::<2>().compact_1_by_2(),
+                )
+            }
+        }
+    };
+}
+
+impl_morton!(crate::Uint32x8);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_morton!(crate::reference::Uint32x8);
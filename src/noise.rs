@@ -0,0 +1,191 @@
+//! Batch gradient noise evaluation.
+//!
+//! Gradients are selected by hashing lattice coordinates instead of looking them up in
+//! a permutation table, so results don't repeat with any period.
+
+use crate::util::{mul_add, select};
+use crate::{Float32x8, Int32x8};
+
+/// Robert Jenkins' 32-bit integer hash.
+#[inline(always)]
+fn hash(a: Int32x8) -> Int32x8 {
+    let c = |v: u32| Int32x8::splat(v as i32);
+
+    let a = (a + c(0x7ed55d16)) + a.shl::<12>();
+    let a = (a ^ c(0xc761c23c)) ^ a.shr_l::<19>();
+    let a = (a + c(0x165667b1)) + a.shl::<5>();
+    let a = (a + c(0xd3a2646c)) ^ a.shl::<9>();
+    let a = (a + c(0xfd7046c5)) + a.shl::<3>();
+    (a ^ c(0xb55a4f09)) ^ a.shr_l::<16>()
+}
+
+/// Negates lanes of `x` where bit `31 - SHIFT` of `h` is set.
+#[inline(always)]
+fn flip_sign<const SHIFT: i32>(h: Int32x8, x: Float32x8) -> Float32x8 {
+    let sign: Float32x8 = (h.shl::<SHIFT>() & Int32x8::splat(i32::MIN)).transmute();
+
+    x ^ sign
+}
+
+/// 6t^5 - 15t^4 + 10t^3
+#[inline(always)]
+fn fade(t: Float32x8) -> Float32x8 {
+    let p = mul_add(t, Float32x8::splat(6.0), Float32x8::splat(-15.0));
+    let p = mul_add(t, p, Float32x8::splat(10.0));
+
+    t * t * t * p
+}
+
+#[inline(always)]
+fn lerp(t: Float32x8, a: Float32x8, b: Float32x8) -> Float32x8 {
+    mul_add(t, b - a, a)
+}
+
+/// Splits coordinates into integer lattice cell and fractional offset within it.
+#[inline(always)]
+fn split(x: Float32x8) -> (Int32x8, Float32x8) {
+    let cell = x.floor();
+
+    (cell.convert(), x - cell)
+}
+
+/// Dot product of offset `(x, y)` with one of 8 gradients selected by `h`.
+#[inline(always)]
+fn gradient_2d(h: Int32x8, x: Float32x8, y: Float32x8) -> Float32x8 {
+    let swap = (h & Int32x8::splat(4)).eq(Int32x8::zero());
+    let u = select(swap.transmute(), y, x);
+    let v = select(swap.transmute(), x, y);
+
+    flip_sign::<31>(h, u) + flip_sign::<30>(h, v + v)
+}
+
+/// Dot product of offset `(x, y, z)` with one of 12 cube edge gradients selected by `h`, as in
+/// improved Perlin noise.
+#[inline(always)]
+fn gradient_3d(h: Int32x8, x: Float32x8, y: Float32x8, z: Float32x8) -> Float32x8 {
+    let h = h & Int32x8::splat(15);
+
+    let u = select(Int32x8::splat(8).gt(h).transmute(), x, y);
+    let v_is_y = Int32x8::splat(4).gt(h);
+    let v_is_x = h.eq(Int32x8::splat(12)) | h.eq(Int32x8::splat(14));
+    let v = select(v_is_y.transmute(), y, select(v_is_x.transmute(), x, z));
+
+    flip_sign::<31>(h, u) + flip_sign::<30>(h, v)
+}
+
+/// 2D Perlin noise evaluated at eight points. Results are approximately in `[-1, 1]` and are
+/// 0 at integer coordinates.
+#[inline(always)]
+#[must_use]
+pub fn noise2x8(x: Float32x8, y: Float32x8) -> Float32x8 {
+    let (xi, xf) = split(x);
+    let (yi, yf) = split(y);
+
+    let one = Float32x8::splat(1.0);
+    let one_i = Int32x8::splat(1);
+
+    let hy0 = hash(yi);
+    let hy1 = hash(yi + one_i);
+    let corner = |dx: Int32x8, hy: Int32x8| hash((xi + dx) ^ hy);
+
+    let n00 = gradient_2d(corner(Int32x8::zero(), hy0), xf, yf);
+    let n10 = gradient_2d(corner(one_i, hy0), xf - one, yf);
+    let n01 = gradient_2d(corner(Int32x8::zero(), hy1), xf, yf - one);
+    let n11 = gradient_2d(corner(one_i, hy1), xf - one, yf - one);
+
+    let u = fade(xf);
+    let v = fade(yf);
+
+    lerp(v, lerp(u, n00, n10), lerp(u, n01, n11)) * Float32x8::splat(0.63)
+}
+
+/// 3D Perlin noise evaluated at eight points. Results are approximately in `[-1, 1]` and are
+/// 0 at integer coordinates.
+#[inline(always)]
+#[must_use]
+pub fn noise3x8(x: Float32x8, y: Float32x8, z: Float32x8) -> Float32x8 {
+    let (xi, xf) = split(x);
+    let (yi, yf) = split(y);
+    let (zi, zf) = split(z);
+
+    let one = Float32x8::splat(1.0);
+    let one_i = Int32x8::splat(1);
+
+    let hz = [hash(zi), hash(zi + one_i)];
+    let hyz = [
+        [hash(yi ^ hz[0]), hash(yi ^ hz[1])],
+        [hash((yi + one_i) ^ hz[0]), hash((yi + one_i) ^ hz[1])],
+    ];
+
+    let xs = [(xi, xf), (xi + one_i, xf - one)];
+    let ys = [yf, yf - one];
+    let zs = [zf, zf - one];
+
+    let corner = |dx: usize, dy: usize, dz: usize| {
+        let (xi, xf) = xs[dx];
+
+        gradient_3d(hash(xi ^ hyz[dy][dz]), xf, ys[dy], zs[dz])
+    };
+
+    let u = fade(xf);
+    let v = fade(yf);
+    let w = fade(zf);
+
+    let x00 = lerp(u, corner(0, 0, 0), corner(1, 0, 0));
+    let x10 = lerp(u, corner(0, 1, 0), corner(1, 1, 0));
+    let x01 = lerp(u, corner(0, 0, 1), corner(1, 0, 1));
+    let x11 = lerp(u, corner(0, 1, 1), corner(1, 1, 1));
+
+    lerp(w, lerp(v, x00, x10), lerp(v, x01, x11))
+}
+
+/// Fractional Brownian motion: sums `octaves` layers of [`noise2x8`], multiplying frequency
+/// by `lacunarity` and amplitude by `gain` for every next layer.
+#[inline(always)]
+#[must_use]
+pub fn fbm2x8(x: Float32x8, y: Float32x8, octaves: u32, lacunarity: f32, gain: f32) -> Float32x8 {
+    let mut sum = Float32x8::zero();
+    let mut frequency = 1.0;
+    let mut amplitude = 1.0;
+
+    for _ in 0..octaves {
+        let f = Float32x8::splat(frequency);
+        sum = mul_add(noise2x8(x * f, y * f), Float32x8::splat(amplitude), sum);
+
+        frequency *= lacunarity;
+        amplitude *= gain;
+    }
+
+    sum
+}
+
+/// Fractional Brownian motion: sums `octaves` layers of [`noise3x8`], multiplying frequency
+/// by `lacunarity` and amplitude by `gain` for every next layer.
+#[inline(always)]
+#[must_use]
+pub fn fbm3x8(
+    x: Float32x8,
+    y: Float32x8,
+    z: Float32x8,
+    octaves: u32,
+    lacunarity: f32,
+    gain: f32,
+) -> Float32x8 {
+    let mut sum = Float32x8::zero();
+    let mut frequency = 1.0;
+    let mut amplitude = 1.0;
+
+    for _ in 0..octaves {
+        let f = Float32x8::splat(frequency);
+        sum = mul_add(
+            noise3x8(x * f, y * f, z * f),
+            Float32x8::splat(amplitude),
+            sum,
+        );
+
+        frequency *= lacunarity;
+        amplitude *= gain;
+    }
+
+    sum
+}
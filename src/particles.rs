@@ -0,0 +1,167 @@
+//! Structure-of-arrays particle system kernels, processing eight particles per iteration.
+
+use crate::util::mul_add;
+use crate::Float32x8;
+
+/// Loads up to 8 values, padding missing lanes with zeros.
+#[inline(always)]
+fn load(slice: &[f32]) -> Float32x8 {
+    let mut array = [0.0; 8];
+    array[..slice.len()].copy_from_slice(slice);
+
+    Float32x8::from_array(array)
+}
+
+/// Stores as many lanes as fit in `slice`.
+#[inline(always)]
+fn store(v: Float32x8, slice: &mut [f32]) {
+    slice.copy_from_slice(&v.to_array()[..slice.len()]);
+}
+
+/// Particles stored as separate arrays for every component. All arrays must have the same length.
+#[derive(Clone, Debug, Default)]
+pub struct Particles {
+    pub position: [Vec<f32>; 3],
+    pub velocity: [Vec<f32>; 3],
+    /// Remaining lifetime, particles with lifetime <= 0 are dead.
+    pub lifetime: Vec<f32>,
+}
+
+impl Particles {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lifetime.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lifetime.is_empty()
+    }
+
+    #[inline]
+    pub fn push(&mut self, position: [f32; 3], velocity: [f32; 3], lifetime: f32) {
+        for axis in 0..3 {
+            self.position[axis].push(position[axis]);
+            self.velocity[axis].push(velocity[axis]);
+        }
+
+        self.lifetime.push(lifetime);
+    }
+
+    /// Returns ranges of indices covering all particles, 8 at a time.
+    #[inline(always)]
+    fn chunks(&self) -> impl Iterator<Item = core::ops::Range<usize>> {
+        let len = self.len();
+
+        assert!(
+            self.position
+                .iter()
+                .chain(&self.velocity)
+                .all(|array| array.len() == len),
+            "Particle arrays have different lengths."
+        );
+
+        (0..len)
+            .step_by(8)
+            .map(move |start| start..len.min(start + 8))
+    }
+
+    /// Semi-implicit Euler step: applies constant `acceleration` to velocities, then moves
+    /// particles by their new velocities.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    pub fn integrate(&mut self, acceleration: [f32; 3], dt: f32) {
+        let dt_v = Float32x8::splat(dt);
+        let acceleration = acceleration.map(Float32x8::splat);
+
+        for range in self.chunks() {
+            let axes = self
+                .position
+                .iter_mut()
+                .zip(&mut self.velocity)
+                .zip(acceleration);
+
+            for ((position, velocity), acceleration) in axes {
+                let velocity = &mut velocity[range.clone()];
+                let position = &mut position[range.clone()];
+
+                let v = mul_add(acceleration, dt_v, load(velocity));
+                let p = mul_add(v, dt_v, load(position));
+
+                store(v, velocity);
+                store(p, position);
+            }
+        }
+    }
+
+    /// Scales velocities by `1 - drag * dt`, clamped so that drag never reverses direction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    pub fn apply_drag(&mut self, drag: f32, dt: f32) {
+        let factor = Float32x8::splat((1.0 - drag * dt).max(0.0));
+
+        for range in self.chunks() {
+            for velocity in &mut self.velocity {
+                let velocity = &mut velocity[range.clone()];
+
+                store(load(velocity) * factor, velocity);
+            }
+        }
+    }
+
+    /// Decreases lifetimes by `dt` and removes particles which died, preserving the order of
+    /// remaining ones. Returns the number of removed particles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    pub fn age(&mut self, dt: f32) -> usize {
+        let dt = Float32x8::splat(dt);
+        let len = self.len();
+        let mut alive = 0;
+
+        for range in self.chunks() {
+            let lifetime = load(&self.lifetime[range.clone()]) - dt;
+            store(lifetime, &mut self.lifetime[range.clone()]);
+
+            // Padding lanes past the end would be alive if `dt` is negative.
+            let valid = (1 << range.len()) - 1;
+            let mask = lifetime.gt(Float32x8::zero()).mask() & valid;
+            let count = mask.count_ones() as usize;
+
+            // Survivors are moved towards the start, never past the chunk which is being read.
+            for array in self.arrays_mut() {
+                let packed = load(&array[range.clone()]).compress(mask);
+                store(packed, &mut array[alive..alive + count]);
+            }
+
+            alive += count;
+        }
+
+        for array in self.arrays_mut() {
+            array.truncate(alive);
+        }
+
+        len - alive
+    }
+
+    #[inline(always)]
+    fn arrays_mut(&mut self) -> [&mut Vec<f32>; 7] {
+        let [px, py, pz] = &mut self.position;
+        let [vx, vy, vz] = &mut self.velocity;
+
+        [px, py, pz, vx, vy, vz, &mut self.lifetime]
+    }
+}
@@ -0,0 +1,140 @@
+use core::array;
+use core::marker::PhantomData;
+
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use rand::distributions::{Distribution, Standard};
+use rand::{Error, Fill, Rng};
+
+use crate::*;
+
+/// Uniform distribution sampler for vector type `V`, using independent bounds for every lane.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformVector<V, T: SampleUniform, const N: usize>([T::Sampler; N], PhantomData<V>);
+
+macro_rules! impl_random {
+    ($name: ident, $type: ty, $lanes: expr) => {
+        impl Distribution<$name> for Standard {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $name {
+                $name::from_array(array::from_fn(|_| rng.gen()))
+            }
+        }
+
+        impl SampleUniform for $name {
+            type Sampler = UniformVector<$name, $type, $lanes>;
+        }
+
+        impl UniformSampler for UniformVector<$name, $type, $lanes> {
+            type X = $name;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = low.borrow().to_array();
+                let high = high.borrow().to_array();
+
+                Self(array::from_fn(|i| UniformSampler::new(low[i], high[i])), PhantomData)
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = low.borrow().to_array();
+                let high = high.borrow().to_array();
+
+                Self(
+                    array::from_fn(|i| UniformSampler::new_inclusive(low[i], high[i])),
+                    PhantomData,
+                )
+            }
+
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+                $name::from_array(array::from_fn(|i| self.0[i].sample(rng)))
+            }
+        }
+
+        impl Fill for $name {
+            fn try_fill<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), Error> {
+                let mut array = self.to_array();
+                rng.try_fill(&mut array[..])?;
+                *self = $name::from_array(array);
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_random!(Float32x4, f32, 4);
+impl_random!(Float64x2, f64, 2);
+
+impl_random!(Int8x16, i8, 16);
+impl_random!(Uint8x16, u8, 16);
+
+impl_random!(Int16x8, i16, 8);
+impl_random!(Uint16x8, u16, 8);
+
+impl_random!(Int32x4, i32, 4);
+impl_random!(Uint32x4, u32, 4);
+
+impl_random!(Int64x2, i64, 2);
+impl_random!(Uint64x2, u64, 2);
+
+impl_random!(Float32x8, f32, 8);
+impl_random!(Float64x4, f64, 4);
+
+impl_random!(Int8x32, i8, 32);
+impl_random!(Uint8x32, u8, 32);
+
+impl_random!(Int16x16, i16, 16);
+impl_random!(Uint16x16, u16, 16);
+
+impl_random!(Int32x8, i32, 8);
+impl_random!(Uint32x8, u32, 8);
+
+impl_random!(Int64x4, i64, 4);
+impl_random!(Uint64x4, u64, 4);
+
+impl_random!(Float32x16, f32, 16);
+impl_random!(Float64x8, f64, 8);
+
+impl_random!(Int8x64, i8, 64);
+impl_random!(Uint8x64, u8, 64);
+
+impl_random!(Int16x32, i16, 32);
+impl_random!(Uint16x32, u16, 32);
+
+impl_random!(Int32x16, i32, 16);
+impl_random!(Uint32x16, u32, 16);
+
+impl_random!(Int64x8, i64, 8);
+impl_random!(Uint64x8, u64, 8);
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+mod avx512 {
+    use super::*;
+    use crate::avx512::{
+        Float32x16, Float64x8, Int16x32, Int32x16, Int64x8, Int8x64, Uint16x32, Uint32x16,
+        Uint64x8, Uint8x64,
+    };
+
+    impl_random!(Float32x16, f32, 16);
+    impl_random!(Float64x8, f64, 8);
+
+    impl_random!(Int8x64, i8, 64);
+    impl_random!(Uint8x64, u8, 64);
+
+    impl_random!(Int16x32, i16, 32);
+    impl_random!(Uint16x32, u16, 32);
+
+    impl_random!(Int32x16, i32, 16);
+    impl_random!(Uint32x16, u32, 16);
+
+    impl_random!(Int64x8, i64, 8);
+    impl_random!(Uint64x8, u64, 8);
+}
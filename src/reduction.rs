@@ -0,0 +1,117 @@
+//! Horizontal reductions of all lanes into a scalar. 256-bit vectors are folded into 128-bit
+//! halves first, which are then folded by shuffling the upper lanes down until one is left.
+
+use core::ops;
+
+macro_rules! impl_reduction {
+    (128: $($backend: ident)::*, $name: ident, $type: ty, $bits: literal) => {
+        impl $($backend)::*::$name {
+            #[inline(always)]
+            fn reduce(self, f: impl Fn(Self, Self) -> Self) -> $type {
+                let mut x = self;
+
+                let halves = x.transmute::<$($backend)::*::Int32x4>();
+                x = f(x, halves.shuffle_within_lanes::<0b01_00_11_10>().transmute());
+
+                if $bits <= 32 {
+                    let words = x.transmute::<$($backend)::*::Int32x4>();
+                    x = f(x, words.shuffle_within_lanes::<0b00_00_00_01>().transmute());
+                }
+
+                if $bits <= 16 {
+                    x = f(x, x.transmute::<$($backend)::*::Uint32x4>().shr_l::<16>().transmute());
+                }
+
+                if $bits <= 8 {
+                    x = f(x, x.transmute::<$($backend)::*::Uint16x8>().shr_l::<8>().transmute());
+                }
+
+                x.to_array()[0]
+            }
+        }
+
+        impl_reduction!(@methods $($backend)::*::$name, $($backend)::*::$name, $type);
+    };
+
+    (256: $($backend: ident)::*, $name: ident, $half: ident, $type: ty) => {
+        impl $($backend)::*::$name {
+            #[inline(always)]
+            fn reduce(
+                self,
+                f: impl Fn($($backend)::*::$half, $($backend)::*::$half) -> $($backend)::*::$half,
+            ) -> $type {
+                f(self.low(), self.high()).reduce(f)
+            }
+        }
+
+        impl_reduction!(@methods $($backend)::*::$name, $($backend)::*::$half, $type);
+    };
+
+    (@methods $name: ty, $half: ty, $type: ty) => {
+        impl $name {
+            /// Wrapping sum of all lanes.
+            #[inline(always)]
+            #[must_use]
+            pub fn reduce_add(self) -> $type {
+                self.reduce(<$half as ops::Add>::add)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn reduce_min(self) -> $type {
+                self.reduce(<$half>::min)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn reduce_max(self) -> $type {
+                self.reduce(<$half>::max)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn reduce_and(self) -> $type {
+                self.reduce(<$half as ops::BitAnd>::bitand)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn reduce_or(self) -> $type {
+                self.reduce(<$half as ops::BitOr>::bitor)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn reduce_xor(self) -> $type {
+                self.reduce(<$half as ops::BitXor>::bitxor)
+            }
+        }
+    };
+}
+
+macro_rules! impl_reduction_for_backend {
+    ($($backend: ident)::*) => {
+        impl_reduction!(128: $($backend)::*, Int8x16, i8, 8);
+        impl_reduction!(128: $($backend)::*, Uint8x16, u8, 8);
+        impl_reduction!(128: $($backend)::*, Int16x8, i16, 16);
+        impl_reduction!(128: $($backend)::*, Uint16x8, u16, 16);
+        impl_reduction!(128: $($backend)::*, Int32x4, i32, 32);
+        impl_reduction!(128: $($backend)::*, Uint32x4, u32, 32);
+        impl_reduction!(128: $($backend)::*, Int64x2, i64, 64);
+        impl_reduction!(128: $($backend)::*, Uint64x2, u64, 64);
+
+        impl_reduction!(256: $($backend)::*, Int8x32, Int8x16, i8);
+        impl_reduction!(256: $($backend)::*, Uint8x32, Uint8x16, u8);
+        impl_reduction!(256: $($backend)::*, Int16x16, Int16x8, i16);
+        impl_reduction!(256: $($backend)::*, Uint16x16, Uint16x8, u16);
+        impl_reduction!(256: $($backend)::*, Int32x8, Int32x4, i32);
+        impl_reduction!(256: $($backend)::*, Uint32x8, Uint32x4, u32);
+        impl_reduction!(256: $($backend)::*, Int64x4, Int64x2, i64);
+        impl_reduction!(256: $($backend)::*, Uint64x4, Uint64x2, u64);
+    };
+}
+
+impl_reduction_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_reduction_for_backend!(crate::reference);
@@ -0,0 +1,91 @@
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+#[cfg(not(any(feature = "std", test)))]
+use super::FloatMath;
+use super::{interleave, map, zip};
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::slice_assume_init_mut;
+
+make_vector_type!(Float32x4, f32, u32, 128, 4);
+make_vector_type!(Float64x2, f64, u64, 128, 2);
+
+impl_interleave!(128: Float32x4, Float64x2);
+
+impl Float32x4 {
+    /// Exact reciprocal square root, see `Float32x8::rsqrt`.
+    pub fn rsqrt(self) -> Self {
+        Self(map(self.0, |x| 1.0 / x.sqrt()))
+    }
+
+    /// Every 2 bits of PATTERN select the source element for the corresponding destination
+    /// element. The same selection is applied to every 128-bit lane.
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle<const PATTERN: i32>(self) -> Self {
+        Self(core::array::from_fn(|i| self.0[(i & !3) | ((PATTERN >> ((i % 4) * 2)) & 3) as usize]))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        Self(map(self.0, |x| f32::from_bits(x.to_bits().swap_bytes())))
+    }
+}
+
+impl Float64x2 {
+    #[inline(always)]
+    #[must_use]
+    pub(crate) fn swap_bytes(self) -> Self {
+        Self(map(self.0, |x| f64::from_bits(x.to_bits().swap_bytes())))
+    }
+}
+
+impl VectorConvertInto<super::Int32x4> for Float32x4 {
+    /// Rounds to nearest even. Lanes which are NaN or out of range become `i32::MIN`.
+    #[inline(always)]
+    fn convert_vector(self) -> super::Int32x4 {
+        super::Int32x4(map(self.0, |x| {
+            let x = x.round_ties_even();
+
+            if (-2147483648.0..2147483648.0).contains(&x) {
+                x as i32
+            } else {
+                i32::MIN
+            }
+        }))
+    }
+}
+
+impl VectorConvertInto<super::Float64x4> for Float32x4 {
+    #[inline(always)]
+    fn convert_vector(self) -> super::Float64x4 {
+        super::Float64x4(map(self.0, |x| x as f64))
+    }
+}
+
+impl VectorConvertInto<Float32x4> for super::Float64x4 {
+    /// Rounds to nearest even.
+    #[inline(always)]
+    fn convert_vector(self) -> Float32x4 {
+        Float32x4(map(self.0, |x| x as f32))
+    }
+}
+
+impl VectorConvertInto<super::Int32x4> for super::Float64x4 {
+    /// Rounds to nearest even. Lanes which are NaN or out of range become `i32::MIN`.
+    #[inline(always)]
+    fn convert_vector(self) -> super::Int32x4 {
+        super::Int32x4(map(self.0, |x| {
+            let x = x.round_ties_even();
+
+            if (-2147483648.0..=2147483647.0).contains(&x) {
+                x as i32
+            } else {
+                i32::MIN
+            }
+        }))
+    }
+}
@@ -0,0 +1,621 @@
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+#[cfg(not(any(feature = "std", test)))]
+use super::FloatMath;
+use super::{interleave, map, zip};
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::slice_assume_init_mut;
+
+macro_rules! impl_operator {
+    ($name: ident, $op: ident, $op_function: ident, $function: item) => {
+        impl ops::$op for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            $function
+        }
+
+        paste! {
+            impl ops::[<$op Assign>] for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: Self) {
+                    *self = <Self as ops::$op>::$op_function(*self, rhs);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! make_vector_type {
+    ($name: ident, $type: ident, $bits: ty, $width: expr, $lanes: expr) => {
+        #[derive(Copy, Clone)]
+        pub struct $name(pub(crate) [$type; $lanes]);
+
+        macro_rules! comparison {
+            ($comparison_name: ident, $comparison: expr) => {
+                #[inline(always)]
+                #[must_use]
+                pub fn $comparison_name(self, rhs: Self) -> Self {
+                    Self(zip(self.0, rhs.0, |a, b| Self::lane_mask($comparison(a, b))))
+                }
+            };
+        }
+
+        impl VectorBits for $name {
+            type Bits = [u8; $width / 8];
+
+            #[inline(always)]
+            fn from_bits(x: Self::Bits) -> Self {
+                Self::from_bytes(x)
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> Self::Bits {
+                self.to_bytes()
+            }
+        }
+
+        impl $name {
+            #[inline(always)]
+            fn lane_mask(condition: bool) -> $type {
+                $type::from_bits(if condition { !0 } else { 0 })
+            }
+
+            #[inline(always)]
+            fn bitwise(self, rhs: Self, f: impl Fn($bits, $bits) -> $bits) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| $type::from_bits(f(a.to_bits(), b.to_bits()))))
+            }
+
+            comparison!(eq, |a: $type, b: $type| a == b);
+            comparison!(ne, |a: $type, b: $type| !a.is_nan() && !b.is_nan() && a != b);
+
+            comparison!(gt, |a: $type, b: $type| a > b);
+            comparison!(lt, |a: $type, b: $type| a < b);
+
+            comparison!(ge, |a: $type, b: $type| a >= b);
+            comparison!(le, |a: $type, b: $type| a <= b);
+
+            #[inline(always)]
+            #[must_use]
+            pub fn zero() -> Self {
+                Self([0.0; $lanes])
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn splat(v: $type) -> Self {
+                Self([v; $lanes])
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_from(v: &$type) -> Self {
+                Self::splat(*v)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_array(array: [$type; $lanes]) -> Self {
+                Self(array)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_array(self) -> [$type; $lanes] {
+                self.0
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; $width / 8]) -> Self {
+                const SIZE: usize = core::mem::size_of::<$type>();
+
+                Self(core::array::from_fn(|i| {
+                    $type::from_ne_bytes(bytes[i * SIZE..(i + 1) * SIZE].try_into().unwrap())
+                }))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_bytes(self) -> [u8; $width / 8] {
+                const SIZE: usize = core::mem::size_of::<$type>();
+
+                let mut bytes = [0; $width / 8];
+                for (chunk, x) in bytes.chunks_exact_mut(SIZE).zip(self.0) {
+                    chunk.copy_from_slice(&x.to_ne_bytes());
+                }
+
+                bytes
+            }
+
+            /// Stores all lanes at the beginning of `slice` and returns the initialized part.
+            /// Panics if `slice` is shorter than the vector.
+            #[inline(always)]
+            pub fn store_into_uninit(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                let slice = &mut slice[..$lanes];
+
+                for (element, x) in slice.iter_mut().zip(self.0) {
+                    element.write(x);
+                }
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Stores as many lanes as fit in `slice` and returns the initialized part.
+            #[inline(always)]
+            pub fn write_to_uninit_slice(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                if slice.len() >= $lanes {
+                    return self.store_into_uninit(slice);
+                }
+
+                for (element, x) in slice.iter_mut().zip(self.to_array()) {
+                    element.write(x);
+                }
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Initializes every element of `slice` to `v`.
+            #[inline(always)]
+            pub fn fill_uninit(slice: &mut [MaybeUninit<$type>], v: $type) -> &mut [$type] {
+                let vector = Self::splat(v);
+
+                let mut chunks = slice.chunks_exact_mut($lanes);
+                for chunk in &mut chunks {
+                    vector.store_into_uninit(chunk);
+                }
+                vector.write_to_uninit_slice(chunks.into_remainder());
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Loads a vector from `ptr` using a volatile read, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for reads and aligned to the element size.
+            #[inline(always)]
+            #[must_use]
+            pub unsafe fn load_volatile(ptr: *const [$type; $lanes]) -> Self {
+                Self::from_array(ptr.read_volatile())
+            }
+
+            /// Stores the vector to `ptr` using a volatile write, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for writes and aligned to the element size.
+            #[inline(always)]
+            pub unsafe fn store_volatile(self, ptr: *mut [$type; $lanes]) {
+                ptr.write_volatile(self.to_array());
+            }
+
+            /// Set each bit of mask based on the most significant bit of the corresponding packed
+            /// floating-point element.
+            #[inline(always)]
+            #[must_use]
+            pub fn mask(self) -> u32 {
+                self.0
+                    .iter()
+                    .enumerate()
+                    .fold(0, |mask, (i, x)| mask | ((x.is_sign_negative() as u32) << i))
+            }
+
+            /// Returns true if all bits are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                self.to_bytes().iter().all(|&x| x == 0)
+            }
+
+            /// Returns true if all bits are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_ones(self) -> bool {
+                self.to_bytes().iter().all(|&x| x == !0)
+            }
+
+            /// Same as `is_zero`, named to pair with `is_all_ones`.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            /// Returns true if any bits of `self` selected by `mask` are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn test(self, mask: Self) -> bool {
+                let mask = mask.to_bytes();
+
+                self.to_bytes().iter().zip(mask).any(|(&x, m)| x & m != 0)
+            }
+
+            /// Returns true if bits of `self` selected by `mask` are neither all zeros nor all ones.
+            #[inline(always)]
+            #[must_use]
+            pub fn test_mixed(self, mask: Self) -> bool {
+                let (bytes, mask) = (self.to_bytes(), mask.to_bytes());
+                let any_set = bytes.iter().zip(mask).any(|(&x, m)| x & m != 0);
+                let any_clear = bytes.iter().zip(mask).any(|(&x, m)| !x & m != 0);
+
+                any_set && any_clear
+            }
+
+            /// ~self & rhs
+            #[inline(always)]
+            #[must_use]
+            pub fn andnot(self, rhs: Self) -> Self {
+                self.bitwise(rhs, |a, b| !a & b)
+            }
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                (mask & if_set) | mask.andnot(if_clear)
+            }
+
+            /// Returns `rhs` if either of the lanes is NaN or both lanes are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| if a < b { a } else { b }))
+            }
+
+            /// Returns `rhs` if either of the lanes is NaN or both lanes are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| if a > b { a } else { b }))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn blend<const I: i32>(self, rhs: Self) -> Self {
+                Self(core::array::from_fn(|i| {
+                    if I & (1 << i) != 0 {
+                        rhs.0[i]
+                    } else {
+                        self.0[i]
+                    }
+                }))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn floor(self) -> Self {
+                Self(map(self.0, $type::floor))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn ceil(self) -> Self {
+                Self(map(self.0, $type::ceil))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn trunc(self) -> Self {
+                Self(map(self.0, $type::trunc))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn round(self) -> Self {
+                Self(map(self.0, $type::round_ties_even))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn sqrt(self) -> Self {
+                Self(map(self.0, $type::sqrt))
+            }
+
+            /// Copies every even element into the following odd one.
+            #[inline(always)]
+            #[must_use]
+            pub fn dup_even(self) -> Self {
+                Self(core::array::from_fn(|i| self.0[i & !1]))
+            }
+
+            /// Copies every odd element into the preceding even one.
+            #[inline(always)]
+            #[must_use]
+            pub fn dup_odd(self) -> Self {
+                Self(core::array::from_fn(|i| self.0[i | 1]))
+            }
+
+            /// (self * b) + c
+            ///
+            /// Like the intrinsic based version, it is only available on x86 targets with the
+            /// `fma` target feature, so that code builds the same way with every implementation.
+            #[cfg(any(
+                target_feature = "fma",
+                not(any(target_arch = "x86", target_arch = "x86_64"))
+            ))]
+            #[inline(always)]
+            #[must_use]
+            pub fn fmadd(self, b: Self, c: Self) -> Self {
+                Self(core::array::from_fn(|i| self.0[i].mul_add(b.0[i], c.0[i])))
+            }
+
+            /// (self * b) - c
+            #[cfg(any(
+                target_feature = "fma",
+                not(any(target_arch = "x86", target_arch = "x86_64"))
+            ))]
+            #[inline(always)]
+            #[must_use]
+            pub fn fmsub(self, b: Self, c: Self) -> Self {
+                Self(core::array::from_fn(|i| self.0[i].mul_add(b.0[i], -c.0[i])))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn convert<T>(self) -> T
+            where
+                Self: VectorConvertInto<T>,
+            {
+                <Self as VectorConvertInto<T>>::convert_vector(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn transmute<T>(self) -> T
+            where
+                Self: VectorTransmuteInto<T>,
+            {
+                <Self as VectorTransmuteInto<T>>::transmute_vector(self)
+            }
+        }
+
+        impl_operator! { $name, Add, add,
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, |a, b| a + b))
+            }
+        }
+
+        impl_operator! { $name, Sub, sub,
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, |a, b| a - b))
+            }
+        }
+
+        impl_operator! { $name, Mul, mul,
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, |a, b| a * b))
+            }
+        }
+
+        impl_operator! { $name, Div, div,
+            fn div(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, |a, b| a / b))
+            }
+        }
+
+        impl_operator! { $name, BitAnd, bitand,
+            fn bitand(self, rhs: Self) -> Self::Output {
+                self.bitwise(rhs, |a, b| a & b)
+            }
+        }
+
+        impl_operator! { $name, BitOr, bitor,
+            fn bitor(self, rhs: Self) -> Self::Output {
+                self.bitwise(rhs, |a, b| a | b)
+            }
+        }
+
+        impl_operator! { $name, BitXor, bitxor,
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                self.bitwise(rhs, |a, b| a ^ b)
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                self.bitwise(self, |a, _| !a)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                <[$type; $lanes] as fmt::Debug>::fmt(&self.to_array(), f)
+            }
+        }
+    };
+}
+
+make_vector_type!(Float32x8, f32, u32, 256, 8);
+make_vector_type!(Float64x4, f64, u64, 256, 4);
+
+macro_rules! impl_256_only {
+    ($($name: ident, $type: ident, $lanes: expr);*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn broadcast_128_from(array: &[$type; $lanes / 2]) -> Self {
+                    Self(core::array::from_fn(|i| array[i % ($lanes / 2)]))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub(crate) fn swap_bytes(self) -> Self {
+                    Self(map(self.0, |x| $type::from_bits(x.to_bits().swap_bytes())))
+                }
+            }
+        )*
+    };
+}
+
+impl_256_only!(Float32x8, f32, 8; Float64x4, f64, 4);
+
+macro_rules! impl_halves {
+    ($($name: ident => $half: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn low(self) -> super::$half {
+                    super::$half(core::array::from_fn(|i| self.0[i]))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn high(self) -> super::$half {
+                    super::$half(core::array::from_fn(|i| self.0[i + self.0.len() / 2]))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn combine(low: super::$half, high: super::$half) -> Self {
+                    Self(core::array::from_fn(|i| {
+                        let half = low.0.len();
+                        if i < half {
+                            low.0[i]
+                        } else {
+                            high.0[i - half]
+                        }
+                    }))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn swap_halves(self) -> Self {
+                    Self::combine(self.high(), self.low())
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn duplicate_low_half(self) -> Self {
+                    Self::combine(self.low(), self.low())
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn duplicate_high_half(self) -> Self {
+                    Self::combine(self.high(), self.high())
+                }
+            }
+        )*
+    };
+}
+
+impl_halves!(Float32x8 => Float32x4, Float64x4 => Float64x2);
+
+macro_rules! impl_interleave {
+    (256: $($name: ident),*) => {
+        $(
+            impl_interleave!(@lanes $name);
+
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn interleave(self, rhs: Self) -> (Self, Self) {
+                    let (lo, hi) = (self.interleave_lo(rhs), self.interleave_hi(rhs));
+
+                    (Self::combine(lo.low(), hi.low()), Self::combine(lo.high(), hi.high()))
+                }
+            }
+        )*
+    };
+
+    (128: $($name: ident),*) => {
+        $(
+            impl_interleave!(@lanes $name);
+
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn interleave(self, rhs: Self) -> (Self, Self) {
+                    (self.interleave_lo(rhs), self.interleave_hi(rhs))
+                }
+            }
+        )*
+    };
+
+    (@lanes $name: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn interleave_lo(self, rhs: Self) -> Self {
+                Self(interleave(self.0, rhs.0, 0))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn interleave_hi(self, rhs: Self) -> Self {
+                Self(interleave(self.0, rhs.0, 1))
+            }
+        }
+    };
+}
+
+impl_interleave!(256: Float32x8, Float64x4);
+
+impl Float32x8 {
+    /// Exact reciprocal square root. The intrinsic version is an approximation with relative
+    /// error of at most 1.5 * 2^-12 which treats denormal inputs as zero, so results are expected
+    /// to differ. This is the only method which doesn't match the intrinsic version bit-exactly.
+    pub fn rsqrt(self) -> Self {
+        Self(map(self.0, |x| 1.0 / x.sqrt()))
+    }
+
+    /// Every 2 bits of PATTERN select the source element for the corresponding destination
+    /// element. The same selection is applied to every 128-bit lane.
+    #[inline(always)]
+    #[must_use]
+    pub fn shuffle<const PATTERN: i32>(self) -> Self {
+        Self(core::array::from_fn(|i| self.0[(i & !3) | ((PATTERN >> ((i % 4) * 2)) & 3) as usize]))
+    }
+
+    /// Loads `table[indices[i]]` into every lane `i`.
+    ///
+    /// # Safety
+    ///
+    /// All indices must be in bounds of `table`.
+    #[inline(always)]
+    #[must_use]
+    pub(crate) unsafe fn gather_unchecked(table: &[f32], indices: super::Int32x8) -> Self {
+        Self(map(indices.0, |i| *table.get_unchecked(i as usize)))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn permute(self, indices: super::Int32x8) -> Self {
+        Self(indices.0.map(|i| self.0[i as usize & 7]))
+    }
+}
+
+impl Float64x4 {
+    #[inline(always)]
+    #[must_use]
+    pub fn permute4x64<const PATTERN: i32>(self) -> Self {
+        Self(core::array::from_fn(|i| self.0[(PATTERN as usize >> (i * 2)) & 3]))
+    }
+}
+
+impl VectorConvertInto<super::Int32x8> for Float32x8 {
+    /// Rounds to nearest even. Lanes which are NaN or out of range become `i32::MIN`.
+    #[inline(always)]
+    fn convert_vector(self) -> super::Int32x8 {
+        super::Int32x8(map(self.0, |x| {
+            let x = x.round_ties_even();
+
+            if (-2147483648.0..2147483648.0).contains(&x) {
+                x as i32
+            } else {
+                i32::MIN
+            }
+        }))
+    }
+}
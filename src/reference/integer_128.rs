@@ -0,0 +1,209 @@
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+use super::{horizontal, interleave, map, pack, zip};
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::{self, slice_assume_init_mut};
+
+make_vector_type!(Int8x16, i8, 128, 16);
+make_vector_type!(Uint8x16, u8, 128, 16);
+
+make_vector_type!(Int16x8, i16, 128, 8);
+make_vector_type!(Uint16x8, u16, 128, 8);
+
+make_vector_type!(Int32x4, i32, 128, 4);
+make_vector_type!(Uint32x4, u32, 128, 4);
+
+make_vector_type!(Int64x2, i64, 128, 2);
+make_vector_type!(Uint64x2, u64, 128, 2);
+
+impl_basic_operations!(Int8x16, i8, Uint8x16, u8);
+impl_basic_operations!(Int16x8, i16, Uint16x8, u16);
+impl_basic_operations!(Int32x4, i32, Uint32x4, u32);
+impl_basic_operations!(Int64x2, i64, Uint64x2, u64);
+
+impl_logical_shifts!(Int8x16, Uint8x16, u8);
+impl_logical_shifts!(Int16x8, Uint16x8, u16);
+impl_logical_shifts!(Int32x4, Uint32x4, u32);
+impl_logical_shifts!(Int64x2, Uint64x2, u64);
+
+impl_arithmetic_shift!(Int8x16, i8);
+impl_arithmetic_shift!(Int16x8, i16);
+impl_arithmetic_shift!(Int32x4, i32);
+impl_arithmetic_shift!(Int64x2, i64);
+
+impl_comparisons!(Int8x16, Uint8x16);
+impl_comparisons!(Int16x8, Uint16x8);
+impl_comparisons!(Int32x4, Uint32x4);
+impl_comparisons!(Int64x2, Uint64x2);
+
+impl_blend!(Int16x8, Uint16x8, 8);
+impl_blend!(Int32x4, Uint32x4, 8);
+impl_blend!(Int8x16, Uint8x16, 16);
+impl_blend!(Int64x2, Uint64x2, 2);
+
+impl_within_lane_shuffles!(
+    Int16x8,
+    Uint16x8,
+    shuffle_lo_within_lanes => 0,
+    shuffle_hi_within_lanes => 4
+);
+
+impl_within_lane_shuffles!(Int32x4, Uint32x4, shuffle_within_lanes => 0);
+
+impl_shuffle_bytes!(Uint8x16, Int8x16, Uint8x16);
+
+impl_interleave!(128:
+    Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2
+);
+
+impl_mul_lo!(Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2);
+impl_mul_even_widening!(Int32x4 => Int64x2, i64, Uint32x4 => Uint64x2, u64);
+
+impl Int16x8 {
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        Self(zip(self.0, rhs.0, |a, b| ((a as i32 * b as i32) >> 16) as i16))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hrs(self, rhs: Self) -> Self {
+        Self(zip(self.0, rhs.0, |a, b| ((a as i32 * b as i32 + 0x4000) >> 15) as i16))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn madd(self, rhs: Self) -> Int32x4 {
+        let products = zip(self.0, rhs.0, |a, b| a as i32 * b as i32);
+
+        Int32x4(core::array::from_fn(|i| products[i * 2].wrapping_add(products[i * 2 + 1])))
+    }
+}
+
+impl Uint8x16 {
+    #[inline(always)]
+    #[must_use]
+    pub fn low_nibbles(self) -> Self {
+        Self(map(self.0, |x| x & 0x0f))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn high_nibbles(self) -> Self {
+        Self(map(self.0, |x| x >> 4))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup16(self, table: [u8; 16]) -> Self {
+        Self(map(self.0, |x| table.get(x as usize).copied().unwrap_or(0)))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup32(self, table: [u8; 32]) -> Self {
+        Self(map(self.0, |x| table.get(x as usize).copied().unwrap_or(0)))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup64(self, table: [u8; 64]) -> Self {
+        Self(map(self.0, |x| table.get(x as usize).copied().unwrap_or(0)))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn maddubs(self, rhs: Int8x16) -> Int16x8 {
+        let products: [i16; 16] = core::array::from_fn(|i| self.0[i] as i16 * rhs.0[i] as i16);
+
+        Int16x8(core::array::from_fn(|i| products[i * 2].saturating_add(products[i * 2 + 1])))
+    }
+}
+
+impl Uint16x8 {
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        Self(zip(self.0, rhs.0, |a, b| ((a as u32 * b as u32) >> 16) as u16))
+    }
+}
+
+impl_mpsadbw!(Uint8x16 => Uint16x8);
+
+impl_saturating_arithmetic!(Int8x16, Uint8x16, Int16x8, Uint16x8);
+impl_average!(Uint8x16: u16, Uint16x8: u32);
+
+impl_horizontal!(
+    Int16x8, hadd, hsub, i16::wrapping_add, i16::wrapping_sub;
+    Uint16x8, hadd, hsub, u16::wrapping_add, u16::wrapping_sub;
+    Int32x4, hadd, hsub, i32::wrapping_add, i32::wrapping_sub;
+    Uint32x4, hadd, hsub, u32::wrapping_add, u32::wrapping_sub;
+    Int16x8, saturating_hadd, saturating_hsub, i16::saturating_add, i16::saturating_sub;
+);
+
+impl_pack!(
+    Int16x8: i16 => Int8x16: i8, Uint8x16: u8,
+    Int32x4: i32 => Int16x8: i16, Uint16x8: u16
+);
+
+impl_abs_diff!(
+    Int8x16, Uint8x16,
+    Int16x8, Uint16x8,
+    Int32x4, Uint32x4,
+    Int64x2, Uint64x2
+);
+
+impl_sad!(Uint8x16 => Uint64x2);
+
+impl_dot_accumulate!(Uint8x16, Int8x16, Int32x4);
+
+impl_bit_extract_deposit!(Int32x4, Uint32x4, u32);
+impl_bit_extract_deposit!(Int64x2, Uint64x2, u64);
+
+impl_swap_bytes!(Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2);
+
+impl_signedness_casts!(Int8x16, Uint8x16);
+impl_signedness_casts!(Int16x8, Uint16x8);
+impl_signedness_casts!(Int32x4, Uint32x4);
+impl_signedness_casts!(Int64x2, Uint64x2);
+
+impl VectorConvertInto<super::Float32x4> for Int32x4 {
+    #[inline(always)]
+    fn convert_vector(self) -> super::Float32x4 {
+        super::Float32x4(map(self.0, |x| x as f32))
+    }
+}
+
+impl VectorConvertInto<super::Float64x4> for Int32x4 {
+    #[inline(always)]
+    fn convert_vector(self) -> super::Float64x4 {
+        super::Float64x4(map(self.0, |x| x as f64))
+    }
+}
+
+macro_rules! impl_widening_conversions {
+    ($($from: ident => $to: ident),* $(,)?) => {
+        $(
+            impl VectorConvertInto<super::$to> for $from {
+                /// Sign or zero extends every lane to twice its width.
+                #[inline(always)]
+                fn convert_vector(self) -> super::$to {
+                    super::$to(map(self.0, |x| x as _))
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_conversions!(
+    Int8x16 => Int16x16,
+    Uint8x16 => Uint16x16,
+    Int16x8 => Int32x8,
+    Uint16x8 => Uint32x8,
+    Int32x4 => Int64x4,
+    Uint32x4 => Uint64x4,
+);
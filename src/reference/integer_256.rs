@@ -0,0 +1,1191 @@
+use core::mem::MaybeUninit;
+use core::{fmt, ops};
+
+use paste::paste;
+
+use super::{horizontal, interleave, map, pack, zip};
+use crate::conversion::{VectorBits, VectorConvertInto, VectorTransmuteInto};
+use crate::util::{self, slice_assume_init_mut};
+
+macro_rules! impl_operator {
+    ($name: ident, $op: ident, $op_function: ident, $function: item) => {
+        impl ops::$op for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            $function
+        }
+
+        paste! {
+            impl ops::[<$op Assign>] for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: Self) {
+                    *self = <Self as ops::$op>::$op_function(*self, rhs);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! make_vector_type {
+    ($name: ident, $type: ty, $width: expr, $lanes: expr) => {
+        #[derive(Copy, Clone)]
+        pub struct $name(pub(crate) [$type; $lanes]);
+
+        impl VectorBits for $name {
+            type Bits = [u8; $width / 8];
+
+            #[inline(always)]
+            fn from_bits(x: Self::Bits) -> Self {
+                Self::from_bytes(x)
+            }
+
+            #[inline(always)]
+            fn to_bits(self) -> Self::Bits {
+                self.to_bytes()
+            }
+        }
+
+        impl $name {
+            #[inline(always)]
+            fn lane_mask(condition: bool) -> $type {
+                if condition {
+                    !0
+                } else {
+                    0
+                }
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn zero() -> Self {
+                Self([0; $lanes])
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_array(array: [$type; $lanes]) -> Self {
+                Self(array)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_array(self) -> [$type; $lanes] {
+                self.0
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_bytes(bytes: [u8; $width / 8]) -> Self {
+                const SIZE: usize = core::mem::size_of::<$type>();
+
+                Self(core::array::from_fn(|i| {
+                    <$type>::from_ne_bytes(bytes[i * SIZE..(i + 1) * SIZE].try_into().unwrap())
+                }))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_bytes(self) -> [u8; $width / 8] {
+                const SIZE: usize = core::mem::size_of::<$type>();
+
+                let mut bytes = [0; $width / 8];
+                for (chunk, x) in bytes.chunks_exact_mut(SIZE).zip(self.0) {
+                    chunk.copy_from_slice(&x.to_ne_bytes());
+                }
+
+                bytes
+            }
+
+            /// Stores all lanes at the beginning of `slice` and returns the initialized part.
+            /// Panics if `slice` is shorter than the vector.
+            #[inline(always)]
+            pub fn store_into_uninit(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                let slice = &mut slice[..$lanes];
+
+                for (element, x) in slice.iter_mut().zip(self.0) {
+                    element.write(x);
+                }
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Stores as many lanes as fit in `slice` and returns the initialized part.
+            #[inline(always)]
+            pub fn write_to_uninit_slice(self, slice: &mut [MaybeUninit<$type>]) -> &mut [$type] {
+                if slice.len() >= $lanes {
+                    return self.store_into_uninit(slice);
+                }
+
+                for (element, x) in slice.iter_mut().zip(self.to_array()) {
+                    element.write(x);
+                }
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Initializes every element of `slice` to `v`.
+            #[inline(always)]
+            pub fn fill_uninit(slice: &mut [MaybeUninit<$type>], v: $type) -> &mut [$type] {
+                let vector = Self::splat(v);
+
+                let mut chunks = slice.chunks_exact_mut($lanes);
+                for chunk in &mut chunks {
+                    vector.store_into_uninit(chunk);
+                }
+                vector.write_to_uninit_slice(chunks.into_remainder());
+
+                unsafe { slice_assume_init_mut(slice) }
+            }
+
+            /// Loads a vector from `ptr` using a volatile read, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for reads and aligned to the element size.
+            #[inline(always)]
+            #[must_use]
+            pub unsafe fn load_volatile(ptr: *const [$type; $lanes]) -> Self {
+                Self::from_array(ptr.read_volatile())
+            }
+
+            /// Stores the vector to `ptr` using a volatile write, which is never elided or
+            /// reordered with other volatile accesses.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for writes and aligned to the element size.
+            #[inline(always)]
+            pub unsafe fn store_volatile(self, ptr: *mut [$type; $lanes]) {
+                ptr.write_volatile(self.to_array());
+            }
+
+            /// Create mask from the most significant bit of each 8-bit element.
+            #[inline(always)]
+            #[must_use]
+            pub fn mask(self) -> u32 {
+                self.to_bytes()
+                    .iter()
+                    .enumerate()
+                    .fold(0, |mask, (i, x)| mask | (((x >> 7) as u32) << i))
+            }
+
+            /// Returns true if all bits are zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                self.to_bytes().iter().all(|&x| x == 0)
+            }
+
+            /// Returns true if all bits are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_ones(self) -> bool {
+                self.to_bytes().iter().all(|&x| x == !0)
+            }
+
+            /// Same as `is_zero`, named to pair with `is_all_ones`.
+            #[inline(always)]
+            #[must_use]
+            pub fn is_all_zero(self) -> bool {
+                self.is_zero()
+            }
+
+            /// Returns true if any bits of `self` selected by `mask` are set.
+            #[inline(always)]
+            #[must_use]
+            pub fn test(self, mask: Self) -> bool {
+                let mask = mask.to_bytes();
+
+                self.to_bytes().iter().zip(mask).any(|(&x, m)| x & m != 0)
+            }
+
+            /// Returns true if bits of `self` selected by `mask` are neither all zeros nor all ones.
+            #[inline(always)]
+            #[must_use]
+            pub fn test_mixed(self, mask: Self) -> bool {
+                let (bytes, mask) = (self.to_bytes(), mask.to_bytes());
+                let any_set = bytes.iter().zip(mask).any(|(&x, m)| x & m != 0);
+                let any_clear = bytes.iter().zip(mask).any(|(&x, m)| !x & m != 0);
+
+                any_set && any_clear
+            }
+
+            /// ~self & rhs
+            #[inline(always)]
+            #[must_use]
+            pub fn andnot(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| !a & b))
+            }
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                (mask & if_set) | mask.andnot(if_clear)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn align_right<const N: i32>(self, rhs: Self) -> Self {
+                let (upper, lower) = (self.to_bytes(), rhs.to_bytes());
+
+                Self::from_bytes(core::array::from_fn(|i| {
+                    let (lane, j) = (i & !15, (i & 15) + N as usize);
+
+                    match j {
+                        0..16 => lower[lane + j],
+                        16..32 => upper[lane + j - 16],
+                        _ => 0,
+                    }
+                }))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn convert<T>(self) -> T where Self: VectorConvertInto<T> {
+                <Self as VectorConvertInto<T>>::convert_vector(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn transmute<T>(self) -> T where Self: VectorTransmuteInto<T> {
+                <Self as VectorTransmuteInto<T>>::transmute_vector(self)
+            }
+        }
+
+        impl_operator! { $name, BitAnd, bitand,
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, |a, b| a & b))
+            }
+        }
+
+        impl_operator! { $name, BitOr, bitor,
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, |a, b| a | b))
+            }
+        }
+
+        impl_operator! { $name, BitXor, bitxor,
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, |a, b| a ^ b))
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                Self(map(self.0, |x| !x))
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                <[$type; $lanes] as fmt::Debug>::fmt(&self.to_array(), f)
+            }
+        }
+    };
+}
+
+make_vector_type!(Int8x32, i8, 256, 32);
+make_vector_type!(Uint8x32, u8, 256, 32);
+
+make_vector_type!(Int16x16, i16, 256, 16);
+make_vector_type!(Uint16x16, u16, 256, 16);
+
+make_vector_type!(Int32x8, i32, 256, 8);
+make_vector_type!(Uint32x8, u32, 256, 8);
+
+make_vector_type!(Int64x4, i64, 256, 4);
+make_vector_type!(Uint64x4, u64, 256, 4);
+
+macro_rules! impl_broadcast_128 {
+    ($($name: ident, $type: ty, $lanes: expr);*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn broadcast_128_from(array: &[$type; $lanes / 2]) -> Self {
+                    Self(core::array::from_fn(|i| array[i % ($lanes / 2)]))
+                }
+            }
+        )*
+    };
+}
+
+impl_broadcast_128!(
+    Int8x32, i8, 32; Uint8x32, u8, 32; Int16x16, i16, 16; Uint16x16, u16, 16;
+    Int32x8, i32, 8; Uint32x8, u32, 8; Int64x4, i64, 4; Uint64x4, u64, 4
+);
+
+macro_rules! impl_halves {
+    ($($name: ident => $half: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn low(self) -> super::$half {
+                    super::$half(core::array::from_fn(|i| self.0[i]))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn high(self) -> super::$half {
+                    super::$half(core::array::from_fn(|i| self.0[i + self.0.len() / 2]))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn combine(low: super::$half, high: super::$half) -> Self {
+                    Self(core::array::from_fn(|i| {
+                        let half = low.0.len();
+                        if i < half {
+                            low.0[i]
+                        } else {
+                            high.0[i - half]
+                        }
+                    }))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn swap_halves(self) -> Self {
+                    Self::combine(self.high(), self.low())
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn duplicate_low_half(self) -> Self {
+                    Self::combine(self.low(), self.low())
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn duplicate_high_half(self) -> Self {
+                    Self::combine(self.high(), self.high())
+                }
+            }
+        )*
+    };
+}
+
+impl_halves!(
+    Int8x32 => Int8x16, Uint8x32 => Uint8x16, Int16x16 => Int16x8, Uint16x16 => Uint16x8,
+    Int32x8 => Int32x4, Uint32x8 => Uint32x4, Int64x4 => Int64x2, Uint64x4 => Uint64x2
+);
+
+impl_interleave!(256:
+    Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+);
+
+macro_rules! impl_basic_operations {
+    ($signed: ident, $signed_type: ty, $unsigned: ident, $unsigned_type: ty) => {
+        impl_basic_operations!($signed, $signed_type);
+        impl_basic_operations!($unsigned, $unsigned_type);
+
+        impl $signed {
+            #[inline(always)]
+            #[must_use]
+            pub fn gt(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| Self::lane_mask(a > b)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn wrapping_neg(self) -> Self {
+                Self(map(self.0, <$signed_type>::wrapping_neg))
+            }
+        }
+
+        impl ops::Neg for $signed {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                self.wrapping_neg()
+            }
+        }
+
+        impl $unsigned {
+            #[inline(always)]
+            #[must_use]
+            pub fn gt(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| Self::lane_mask(a > b)))
+            }
+        }
+    };
+
+    ($name: ident, $type: ty) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn splat(v: $type) -> Self {
+                Self(Self::zero().0.map(|_| v))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_from(v: &$type) -> Self {
+                Self::splat(*v)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn eq(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| Self::lane_mask(a == b)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn ne(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| Self::lane_mask(a != b)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn lt(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| Self::lane_mask(a < b)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn le(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| Self::lane_mask(a <= b)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn ge(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, |a, b| Self::lane_mask(a >= b)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn clamp(self, min: Self, max: Self) -> Self {
+                self.max(min).min(max)
+            }
+
+            /// Number of set bits in every lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn count_ones(self) -> Self {
+                Self(map(self.0, |x| x.count_ones() as _))
+            }
+
+            /// Reverses the order of bits in every lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn reverse_bits(self) -> Self {
+                Self(map(self.0, |x| x.reverse_bits()))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn insert<const I: i32>(self, value: $type) -> Self {
+                let mut array = self.0;
+                array[I as usize] = value;
+                Self(array)
+            }
+        }
+
+        impl_operator! {$name, Add, add,
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, <$type>::wrapping_add))
+            }
+        }
+
+        impl_operator! {$name, Sub, sub,
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(zip(self.0, rhs.0, <$type>::wrapping_sub))
+            }
+        }
+    };
+}
+
+impl_basic_operations!(Int8x32, i8, Uint8x32, u8);
+impl_basic_operations!(Int16x16, i16, Uint16x16, u16);
+impl_basic_operations!(Int32x8, i32, Uint32x8, u32);
+impl_basic_operations!(Int64x4, i64, Uint64x4, u64);
+
+macro_rules! impl_shift_operator {
+    ($name: ident, $op: ident, $op_function: ident, $method: ident) => {
+        impl ops::$op<u32> for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: u32) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+
+        paste! {
+            impl ops::[<$op Assign>]<u32> for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: u32) {
+                    *self = self.$method(rhs);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_logical_shifts {
+    ($signed: ident, $unsigned: ident, $unsigned_type: ty) => {
+        impl_logical_shifts!($signed, $unsigned_type);
+        impl_logical_shifts!($unsigned, $unsigned_type);
+
+        impl_shift_operator!($unsigned, Shr, shr, shr_l_by);
+    };
+
+    ($name: ident, $unsigned_type: ty) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn shl<const N: i32>(self) -> Self {
+                Self(map(self.0, |x| x.checked_shl(N as u32).unwrap_or(0)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l<const N: i32>(self) -> Self {
+                Self(map(self.0, |x| {
+                    (x as $unsigned_type).checked_shr(N as u32).unwrap_or(0) as _
+                }))
+            }
+
+            /// Shifts every lane by the same runtime count, counts past the lane width clear it.
+            #[inline(always)]
+            #[must_use]
+            pub fn shl_by(self, n: u32) -> Self {
+                Self(map(self.0, |x| x.checked_shl(n).unwrap_or(0)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l_by(self, n: u32) -> Self {
+                Self(map(self.0, |x| (x as $unsigned_type).checked_shr(n).unwrap_or(0) as _))
+            }
+        }
+
+        impl_shift_operator!($name, Shl, shl, shl_by);
+    };
+}
+
+impl_logical_shifts!(Int8x32, Uint8x32, u8);
+impl_logical_shifts!(Int16x16, Uint16x16, u16);
+impl_logical_shifts!(Int32x8, Uint32x8, u32);
+impl_logical_shifts!(Int64x4, Uint64x4, u64);
+
+macro_rules! impl_arithmetic_shift {
+    ($name: ident, $type: ty) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a<const N: i32>(self) -> Self {
+                Self(map(self.0, |x| x >> (N as u32).min(<$type>::BITS - 1)))
+            }
+
+            /// Counts past the lane width fill it with the sign bit.
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_a_by(self, n: u32) -> Self {
+                Self(map(self.0, |x| x >> n.min(<$type>::BITS - 1)))
+            }
+        }
+
+        impl_shift_operator!($name, Shr, shr, shr_a_by);
+    };
+}
+
+impl_arithmetic_shift!(Int8x32, i8);
+impl_arithmetic_shift!(Int16x16, i16);
+impl_arithmetic_shift!(Int32x8, i32);
+impl_arithmetic_shift!(Int64x4, i64);
+
+macro_rules! impl_comparisons {
+    ($signed: ident, $unsigned: ident) => {
+        impl $signed {
+            #[inline(always)]
+            #[must_use]
+            pub fn abs(self) -> Self {
+                Self(map(self.0, |x| x.wrapping_abs()))
+            }
+        }
+
+        impl_comparisons!($signed);
+        impl_comparisons!($unsigned);
+    };
+
+    ($name: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, Ord::min))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                Self(zip(self.0, rhs.0, Ord::max))
+            }
+        }
+    };
+}
+
+impl_comparisons!(Int8x32, Uint8x32);
+impl_comparisons!(Int16x16, Uint16x16);
+impl_comparisons!(Int32x8, Uint32x8);
+impl_comparisons!(Int64x4, Uint64x4);
+
+macro_rules! impl_blend {
+    ($signed: ident, $unsigned: ident, $control_bits: expr) => {
+        impl_blend!($signed, $control_bits);
+        impl_blend!($unsigned, $control_bits);
+    };
+
+    ($name: ident, $control_bits: expr) => {
+        impl $name {
+            /// Control bits are reused for every 128-bit lane of 16-bit vectors.
+            #[inline(always)]
+            #[must_use]
+            pub fn blend<const N: i32>(self, rhs: Self) -> Self {
+                Self(core::array::from_fn(|i| {
+                    if N & (1 << (i % $control_bits)) != 0 {
+                        rhs.0[i]
+                    } else {
+                        self.0[i]
+                    }
+                }))
+            }
+        }
+    };
+}
+
+impl_blend!(Int16x16, Uint16x16, 8);
+impl_blend!(Int32x8, Uint32x8, 8);
+impl_blend!(Int8x32, Uint8x32, 32);
+impl_blend!(Int64x4, Uint64x4, 4);
+
+macro_rules! impl_within_lane_shuffles {
+    ($signed: ident, $unsigned: ident, $($method: ident => $first: expr),*) => {
+        impl_within_lane_shuffles!($signed, $($method => $first),*);
+        impl_within_lane_shuffles!($unsigned, $($method => $first),*);
+    };
+
+    ($name: ident, $($method: ident => $first: expr),*) => {
+        impl $name {
+            $(
+                #[inline(always)]
+                #[must_use]
+                pub fn $method<const PATTERN: i32>(self) -> Self {
+                    let lane_len = 128 / 8 / core::mem::size_of_val(&self.0[0]);
+
+                    Self(core::array::from_fn(|i| {
+                        let (lane, element) = (i / lane_len * lane_len, i % lane_len);
+
+                        match element.checked_sub($first) {
+                            Some(j) if j < 4 => {
+                                let source = (PATTERN >> (j * 2)) & 3;
+                                self.0[lane + $first + source as usize]
+                            }
+                            _ => self.0[i],
+                        }
+                    }))
+                }
+            )*
+        }
+    };
+}
+
+impl_within_lane_shuffles!(
+    Int16x16,
+    Uint16x16,
+    shuffle_lo_within_lanes => 0,
+    shuffle_hi_within_lanes => 4
+);
+
+impl_within_lane_shuffles!(Int32x8, Uint32x8, shuffle_within_lanes => 0);
+
+macro_rules! impl_cross_lane_permutes {
+    (32: $($name: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn permute(self, indices: Int32x8) -> Self {
+                    Self(indices.0.map(|i| self.0[i as usize & 7]))
+                }
+            }
+        )*
+    };
+
+    (64: $($name: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn permute4x64<const PATTERN: i32>(self) -> Self {
+                    Self(core::array::from_fn(|i| self.0[(PATTERN as usize >> (i * 2)) & 3]))
+                }
+            }
+        )*
+    };
+}
+
+impl_cross_lane_permutes!(32: Int32x8, Uint32x8);
+impl_cross_lane_permutes!(64: Int64x4, Uint64x4);
+
+macro_rules! impl_shuffle_bytes {
+    ($indices: ident, $($name: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn shuffle_bytes(self, indices: $indices) -> Self {
+                    Self(core::array::from_fn(|i| match indices.0[i] {
+                        0x80.. => 0,
+                        j => self.0[(i & !15) | (j & 15) as usize],
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_shuffle_bytes!(Uint8x32, Int8x32, Uint8x32);
+
+macro_rules! impl_shuffle_bytes_crossing {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn shuffle_bytes_crossing(self, indices: Uint8x32) -> Self {
+                    Self(core::array::from_fn(|i| match indices.0[i] {
+                        0x80.. => 0,
+                        j => self.0[(j & 31) as usize],
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_shuffle_bytes_crossing!(Int8x32, Uint8x32);
+
+macro_rules! impl_mul_lo {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn mul_lo(self, rhs: Self) -> Self {
+                    Self(zip(self.0, rhs.0, |a, b| a.wrapping_mul(b)))
+                }
+            }
+
+            impl_operator! { $name, Mul, mul,
+                fn mul(self, rhs: Self) -> Self {
+                    self.mul_lo(rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_mul_even_widening {
+    ($($name: ident => $wide: ident, $wide_type: ty),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn mul_even_widening(self, rhs: Self) -> super::$wide {
+                    super::$wide(core::array::from_fn(|i| {
+                        self.0[i * 2] as $wide_type * rhs.0[i * 2] as $wide_type
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_mul_lo!(Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4);
+impl_mul_even_widening!(Int32x8 => Int64x4, i64, Uint32x8 => Uint64x4, u64);
+
+impl Int16x16 {
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        Self(zip(self.0, rhs.0, |a, b| ((a as i32 * b as i32) >> 16) as i16))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hrs(self, rhs: Self) -> Self {
+        Self(zip(self.0, rhs.0, |a, b| ((a as i32 * b as i32 + 0x4000) >> 15) as i16))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn madd(self, rhs: Self) -> Int32x8 {
+        let products = zip(self.0, rhs.0, |a, b| a as i32 * b as i32);
+
+        Int32x8(core::array::from_fn(|i| products[i * 2].wrapping_add(products[i * 2 + 1])))
+    }
+}
+
+impl Uint8x32 {
+    #[inline(always)]
+    #[must_use]
+    pub fn low_nibbles(self) -> Self {
+        Self(map(self.0, |x| x & 0x0f))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn high_nibbles(self) -> Self {
+        Self(map(self.0, |x| x >> 4))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup16(self, table: [u8; 16]) -> Self {
+        Self(map(self.0, |x| table.get(x as usize).copied().unwrap_or(0)))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup32(self, table: [u8; 32]) -> Self {
+        Self(map(self.0, |x| table.get(x as usize).copied().unwrap_or(0)))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn lookup64(self, table: [u8; 64]) -> Self {
+        Self(map(self.0, |x| table.get(x as usize).copied().unwrap_or(0)))
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn maddubs(self, rhs: Int8x32) -> Int16x16 {
+        let products: [i16; 32] = core::array::from_fn(|i| self.0[i] as i16 * rhs.0[i] as i16);
+
+        Int16x16(core::array::from_fn(|i| products[i * 2].saturating_add(products[i * 2 + 1])))
+    }
+}
+
+macro_rules! impl_mpsadbw {
+    ($name: ident => $sums: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn mpsadbw<const N: i32>(self, rhs: Self) -> $sums {
+                $sums(core::array::from_fn(|i| {
+                    let (lane, control) = (i / 8 * 16, (N as usize >> (i / 8 * 3)) & 7);
+                    let windows = &self.0[lane + (control >> 2) * 4 + i % 8..][..4];
+                    let group = &rhs.0[lane + (control & 3) * 4..][..4];
+
+                    windows.iter().zip(group).map(|(&a, &b)| a.abs_diff(b) as u16).sum()
+                }))
+            }
+        }
+    };
+}
+
+impl_mpsadbw!(Uint8x32 => Uint16x16);
+
+impl Uint16x16 {
+    #[inline(always)]
+    #[must_use]
+    pub fn mul_hi(self, rhs: Self) -> Self {
+        Self(zip(self.0, rhs.0, |a, b| ((a as u32 * b as u32) >> 16) as u16))
+    }
+}
+
+macro_rules! impl_saturating_arithmetic {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn saturating_add(self, rhs: Self) -> Self {
+                    Self(zip(self.0, rhs.0, |a, b| a.saturating_add(b)))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn saturating_sub(self, rhs: Self) -> Self {
+                    Self(zip(self.0, rhs.0, |a, b| a.saturating_sub(b)))
+                }
+            }
+        )*
+    };
+}
+
+impl_saturating_arithmetic!(Int8x32, Uint8x32, Int16x16, Uint16x16);
+
+macro_rules! impl_average {
+    ($($name: ident: $wide_type: ty),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn avg(self, rhs: Self) -> Self {
+                    Self(zip(self.0, rhs.0, |a, b| {
+                        ((a as $wide_type + b as $wide_type + 1) >> 1) as _
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+impl_average!(Uint8x32: u16, Uint16x16: u32);
+
+macro_rules! impl_horizontal {
+    ($($name: ident, $hadd: ident, $hsub: ident, $add: path, $sub: path);* $(;)?) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn $hadd(self, rhs: Self) -> Self {
+                    Self(horizontal(self.0, rhs.0, $add))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn $hsub(self, rhs: Self) -> Self {
+                    Self(horizontal(self.0, rhs.0, $sub))
+                }
+            }
+        )*
+    };
+}
+
+impl_horizontal!(
+    Int16x16, hadd, hsub, i16::wrapping_add, i16::wrapping_sub;
+    Uint16x16, hadd, hsub, u16::wrapping_add, u16::wrapping_sub;
+    Int32x8, hadd, hsub, i32::wrapping_add, i32::wrapping_sub;
+    Uint32x8, hadd, hsub, u32::wrapping_add, u32::wrapping_sub;
+    Int16x16, saturating_hadd, saturating_hsub, i16::saturating_add, i16::saturating_sub;
+);
+
+macro_rules! impl_pack {
+    ($($name: ident: $type: ty => $signed: ident: $signed_type: ty,
+        $unsigned: ident: $unsigned_type: ty),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating(self, rhs: Self) -> $signed {
+                    let (min, max) = (<$signed_type>::MIN.into(), <$signed_type>::MAX.into());
+
+                    $signed(pack(self.0, rhs.0, |x: $type| x.clamp(min, max) as $signed_type))
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn pack_saturating_unsigned(self, rhs: Self) -> $unsigned {
+                    let max = <$unsigned_type>::MAX.into();
+
+                    $unsigned(pack(self.0, rhs.0, |x: $type| x.clamp(0, max) as $unsigned_type))
+                }
+            }
+        )*
+    };
+}
+
+impl_pack!(
+    Int16x16: i16 => Int8x32: i8, Uint8x32: u8,
+    Int32x8: i32 => Int16x16: i16, Uint16x16: u16
+);
+
+macro_rules! impl_narrow {
+    ($($name: ident => $signed: ident, $unsigned: ident),*) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                #[must_use]
+                pub fn narrow_saturating(self, rhs: Self) -> $signed {
+                    $signed::combine(
+                        self.low().pack_saturating(self.high()),
+                        rhs.low().pack_saturating(rhs.high()),
+                    )
+                }
+
+                #[inline(always)]
+                #[must_use]
+                pub fn narrow_saturating_unsigned(self, rhs: Self) -> $unsigned {
+                    $unsigned::combine(
+                        self.low().pack_saturating_unsigned(self.high()),
+                        rhs.low().pack_saturating_unsigned(rhs.high()),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_narrow!(Int16x16 => Int8x32, Uint8x32, Int32x8 => Int16x16, Uint16x16);
+
+macro_rules! impl_abs_diff {
+    ($($signed: ident, $unsigned: ident),*) => {
+        $(
+            impl $signed {
+                #[inline(always)]
+                #[must_use]
+                pub fn abs_diff(self, rhs: Self) -> $unsigned {
+                    $unsigned(zip(self.0, rhs.0, |a, b| a.abs_diff(b)))
+                }
+            }
+
+            impl $unsigned {
+                #[inline(always)]
+                #[must_use]
+                pub fn abs_diff(self, rhs: Self) -> Self {
+                    Self(zip(self.0, rhs.0, |a, b| a.abs_diff(b)))
+                }
+            }
+        )*
+    };
+}
+
+impl_abs_diff!(
+    Int8x32, Uint8x32,
+    Int16x16, Uint16x16,
+    Int32x8, Uint32x8,
+    Int64x4, Uint64x4
+);
+
+macro_rules! impl_sad {
+    ($name: ident => $sums: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn sad(self, rhs: Self) -> $sums {
+                let differences = self.abs_diff(rhs).0;
+
+                $sums(core::array::from_fn(|i| {
+                    differences[i * 8..][..8].iter().map(|&x| x as u64).sum()
+                }))
+            }
+        }
+    };
+}
+
+impl_sad!(Uint8x32 => Uint64x4);
+
+macro_rules! impl_dot_accumulate {
+    ($name: ident, $signed: ident, $accumulator: ident) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn dot_accumulate(self, rhs: $signed, acc: $accumulator) -> $accumulator {
+                $accumulator(core::array::from_fn(|i| {
+                    (i * 4..i * 4 + 4).fold(acc.0[i], |sum, j| {
+                        sum.wrapping_add(i32::from(self.0[j]) * i32::from(rhs.0[j]))
+                    })
+                }))
+            }
+        }
+    };
+}
+
+impl_dot_accumulate!(Uint8x32, Int8x32, Int32x8);
+
+macro_rules! impl_bit_extract_deposit {
+    ($signed: ident, $unsigned: ident, $unsigned_type: ty) => {
+        impl_bit_extract_deposit!($signed, $unsigned_type);
+        impl_bit_extract_deposit!($unsigned, $unsigned_type);
+    };
+
+    ($name: ident, $unsigned_type: ty) => {
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn extract_bits(self, mask: Self) -> Self {
+                Self(zip(self.0, mask.0, |x, mask| {
+                    let (x, mask) = (x as $unsigned_type, mask as $unsigned_type);
+                    util::extract_bits(x as u64, mask as u64) as _
+                }))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn deposit_bits(self, mask: Self) -> Self {
+                Self(zip(self.0, mask.0, |x, mask| {
+                    let (x, mask) = (x as $unsigned_type, mask as $unsigned_type);
+                    util::deposit_bits(x as u64, mask as u64) as _
+                }))
+            }
+        }
+    };
+}
+
+impl_bit_extract_deposit!(Int32x8, Uint32x8, u32);
+impl_bit_extract_deposit!(Int64x4, Uint64x4, u64);
+
+macro_rules! impl_swap_bytes {
+    ($($name: ident),*) => {
+        $(
+            impl $name {
+                /// Reverses the byte order of every lane.
+                #[inline(always)]
+                #[must_use]
+                pub fn swap_bytes(self) -> Self {
+                    Self(map(self.0, |x| x.swap_bytes()))
+                }
+            }
+        )*
+    };
+}
+
+impl_swap_bytes!(Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4);
+
+macro_rules! impl_signedness_casts {
+    ($signed: ident, $unsigned: ident) => {
+        impl From<$signed> for $unsigned {
+            #[inline(always)]
+            fn from(x: $signed) -> Self {
+                Self(map(x.0, |x| x as _))
+            }
+        }
+
+        impl From<$unsigned> for $signed {
+            #[inline(always)]
+            fn from(x: $unsigned) -> Self {
+                Self(map(x.0, |x| x as _))
+            }
+        }
+
+        impl VectorConvertInto<$signed> for $unsigned {
+            #[inline(always)]
+            fn convert_vector(self) -> $signed {
+                self.into()
+            }
+        }
+
+        impl VectorConvertInto<$unsigned> for $signed {
+            #[inline(always)]
+            fn convert_vector(self) -> $unsigned {
+                self.into()
+            }
+        }
+    };
+}
+
+impl_signedness_casts!(Int8x32, Uint8x32);
+impl_signedness_casts!(Int16x16, Uint16x16);
+impl_signedness_casts!(Int32x8, Uint32x8);
+impl_signedness_casts!(Int64x4, Uint64x4);
+
+impl VectorConvertInto<super::Float32x8> for Int32x8 {
+    #[inline(always)]
+    fn convert_vector(self) -> super::Float32x8 {
+        super::Float32x8(map(self.0, |x| x as f32))
+    }
+}
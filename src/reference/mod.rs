@@ -0,0 +1,126 @@
+//! Slow scalar implementations of every vector type with the same method signatures and the same
+//! lane semantics as the intrinsic based ones. They are meant to be used as an oracle when
+//! testing SIMD kernels against straightforward scalar code. When the crate is compiled without
+//! the `sse4.1` target feature or with the `soft` feature they are also the vector types exported
+//! from the crate root.
+
+use core::array;
+
+#[macro_use]
+mod float_256;
+mod float_128;
+#[macro_use]
+mod integer_256;
+mod integer_128;
+
+pub use float_128::*;
+pub use float_256::*;
+pub use integer_128::*;
+pub use integer_256::*;
+
+/// Float functions which are provided by `std`. Without it they come from `libm`. Test builds
+/// always link `std`, which makes the inherent methods available again.
+#[cfg(not(any(feature = "std", test)))]
+trait FloatMath: Sized {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn trunc(self) -> Self;
+    fn round_ties_even(self) -> Self;
+    fn sqrt(self) -> Self;
+    #[cfg(any(target_feature = "fma", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+#[cfg(not(any(feature = "std", test)))]
+macro_rules! impl_float_math {
+    (
+        $type: ty,
+        $($(#[$attribute: meta])* $function: ident => $libm: ident($($argument: ident),*)),*
+    ) => {
+        impl FloatMath for $type {
+            $(
+                $(#[$attribute])*
+                #[inline(always)]
+                fn $function(self $(, $argument: Self)*) -> Self {
+                    libm::$libm(self $(, $argument)*)
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(not(any(feature = "std", test)))]
+impl_float_math!(f32,
+    floor => floorf(), ceil => ceilf(), trunc => truncf(), round_ties_even => roundevenf(),
+    sqrt => sqrtf(),
+    #[cfg(any(target_feature = "fma", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+    mul_add => fmaf(a, b)
+);
+
+#[cfg(not(any(feature = "std", test)))]
+impl_float_math!(f64,
+    floor => floor(), ceil => ceil(), trunc => trunc(), round_ties_even => roundeven(),
+    sqrt => sqrt(),
+    #[cfg(any(target_feature = "fma", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+    mul_add => fma(a, b)
+);
+
+/// Interleaves lanes from the lower (`half` = 0) or the upper (`half` = 1) halves of every 128-bit
+/// lane of `a` and `b`.
+#[inline(always)]
+fn interleave<T: Copy, const N: usize>(a: [T; N], b: [T; N], half: usize) -> [T; N] {
+    let lane = 16 / core::mem::size_of::<T>();
+
+    array::from_fn(|i| {
+        let k = i / lane * lane + half * lane / 2 + i % lane / 2;
+
+        if i % 2 == 0 {
+            a[k]
+        } else {
+            b[k]
+        }
+    })
+}
+
+#[inline(always)]
+fn map<T: Copy, U, const N: usize>(a: [T; N], f: impl Fn(T) -> U) -> [U; N] {
+    array::from_fn(|i| f(a[i]))
+}
+
+/// Applies `f` to adjacent pairs of lanes. Within every 128-bit lane the lower half of the result
+/// comes from `a` and the upper half from `b`.
+#[inline(always)]
+fn horizontal<T: Copy, const N: usize>(a: [T; N], b: [T; N], f: impl Fn(T, T) -> T) -> [T; N] {
+    let lane = 16 / core::mem::size_of::<T>();
+
+    array::from_fn(|i| {
+        let (start, j) = (i / lane * lane, i % lane);
+        let source = if j < lane / 2 { &a } else { &b };
+        let k = start + j % (lane / 2) * 2;
+
+        f(source[k], source[k + 1])
+    })
+}
+
+/// Narrows lanes with `f`. Within every 128-bit lane the lower half of the result comes from `a`
+/// and the upper half from `b`.
+#[inline(always)]
+fn pack<T: Copy, U, const N: usize, const M: usize>(
+    a: [T; N],
+    b: [T; N],
+    f: impl Fn(T) -> U,
+) -> [U; M] {
+    let half = 8 / core::mem::size_of::<U>();
+
+    array::from_fn(|i| {
+        let (start, j) = (i / (half * 2) * half, i % (half * 2));
+        let source = if j < half { &a } else { &b };
+
+        f(source[start + j % half])
+    })
+}
+
+#[inline(always)]
+fn zip<T: Copy, U, const N: usize>(a: [T; N], b: [T; N], f: impl Fn(T, T) -> U) -> [U; N] {
+    array::from_fn(|i| f(a[i], b[i]))
+}
@@ -0,0 +1,72 @@
+macro_rules! impl_rotate {
+    ($name: ty, $type: ty) => {
+        impl $name {
+            /// Rotates every lane left by `N` bits, modulo the lane width.
+            #[inline(always)]
+            #[must_use]
+            pub fn rotate_left<const N: i32>(self) -> Self {
+                self.rotate_left_by(N as u32)
+            }
+
+            /// Rotates every lane right by `N` bits, modulo the lane width.
+            #[inline(always)]
+            #[must_use]
+            pub fn rotate_right<const N: i32>(self) -> Self {
+                self.rotate_right_by(N as u32)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn rotate_left_by(self, n: u32) -> Self {
+                let n = n % <$type>::BITS;
+
+                // Shifting by the full lane width clears the lane, so zero counts work too.
+                self.shl_by(n) | self.shr_l_by(<$type>::BITS - n)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn rotate_right_by(self, n: u32) -> Self {
+                let n = n % <$type>::BITS;
+
+                self.shr_l_by(n) | self.shl_by(<$type>::BITS - n)
+            }
+        }
+    };
+}
+
+macro_rules! impl_rotate_for_backend {
+    ($($backend: ident)::*) => {
+        impl_rotate!($($backend)::*::Int8x16, u8);
+        impl_rotate!($($backend)::*::Uint8x16, u8);
+        impl_rotate!($($backend)::*::Int16x8, u16);
+        impl_rotate!($($backend)::*::Uint16x8, u16);
+        impl_rotate!($($backend)::*::Int32x4, u32);
+        impl_rotate!($($backend)::*::Uint32x4, u32);
+        impl_rotate!($($backend)::*::Int64x2, u64);
+        impl_rotate!($($backend)::*::Uint64x2, u64);
+
+        impl_rotate!($($backend)::*::Int8x32, u8);
+        impl_rotate!($($backend)::*::Uint8x32, u8);
+        impl_rotate!($($backend)::*::Int16x16, u16);
+        impl_rotate!($($backend)::*::Uint16x16, u16);
+        impl_rotate!($($backend)::*::Int32x8, u32);
+        impl_rotate!($($backend)::*::Uint32x8, u32);
+        impl_rotate!($($backend)::*::Int64x4, u64);
+        impl_rotate!($($backend)::*::Uint64x4, u64);
+    };
+}
+
+impl_rotate_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_rotate_for_backend!(crate::reference);
+
+impl_rotate!(crate::Int8x64, u8);
+impl_rotate!(crate::Uint8x64, u8);
+impl_rotate!(crate::Int16x32, u16);
+impl_rotate!(crate::Uint16x32, u16);
+impl_rotate!(crate::Int32x16, u32);
+impl_rotate!(crate::Uint32x16, u32);
+impl_rotate!(crate::Int64x8, u64);
+impl_rotate!(crate::Uint64x8, u64);
@@ -0,0 +1,340 @@
+//! Shuffles selecting lanes by constant source indices. The indices are turned into byte shuffle
+//! and permute index vectors at compile time, so every shuffle is a fixed short instruction
+//! sequence.
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+use core::arch::x86_64::*;
+
+/// `shuffle_epi8` indices which copy the `size`-byte element starting at byte `offset` of every
+/// 128-bit lane to all elements of that lane.
+const fn broadcast_bytes_indices<const N: usize>(offset: usize, size: usize) -> [u8; N] {
+    let mut indices = [0; N];
+
+    let mut i = 0;
+    while i < indices.len() {
+        indices[i] = (offset + i % size) as u8;
+        i += 1;
+    }
+
+    indices
+}
+
+/// `permutevar8x32` indices which copy the 32-bit elements holding lane `lane` of `size` bytes to
+/// all elements.
+const fn broadcast_dwords_indices<const N: usize>(lane: usize, size: usize) -> [i32; N] {
+    let mut indices = [0; N];
+
+    let mut i = 0;
+    while i < indices.len() {
+        indices[i] = (lane * size / 4 + i % size.div_ceil(4)) as i32;
+        i += 1;
+    }
+
+    indices
+}
+
+// 256-bit and wider vectors broadcast the 32-bit elements holding the lane first, smaller lanes
+// are then picked out of them with a byte shuffle.
+macro_rules! impl_broadcast_lane {
+    (@doc $name: ty, $self: ident, $body: expr) => {
+        impl $name {
+            /// Sets every lane to lane `I` of `self`.
+            #[inline(always)]
+            #[must_use]
+            pub fn broadcast_lane<const I: usize>($self) -> Self {
+                $body
+            }
+        }
+    };
+
+    (128: $($backend: ident)::*, $name: ident, $lanes: literal) => {
+        impl_broadcast_lane!(@doc $($backend)::*::$name, self, {
+            let indices = const {
+                assert!(I < $lanes, "Lane index is out of range.");
+
+                broadcast_bytes_indices(I * (16 / $lanes), 16 / $lanes)
+            };
+
+            let bytes = self.transmute::<$($backend)::*::Uint8x16>();
+
+            bytes.shuffle_bytes($($backend)::*::Uint8x16::from_array(indices)).transmute()
+        });
+    };
+
+    (256: $($backend: ident)::*, $name: ident, $lanes: literal) => {
+        impl_broadcast_lane!(@doc $($backend)::*::$name, self, {
+            let (dwords, bytes) = const {
+                assert!(I < $lanes, "Lane index is out of range.");
+
+                let size = 32 / $lanes;
+                (broadcast_dwords_indices(I, size), broadcast_bytes_indices(I * size % 4, size))
+            };
+
+            let dwords = $($backend)::*::Int32x8::from_array(dwords);
+            let x = self.transmute::<$($backend)::*::Uint32x8>().permute(dwords);
+            let x = x.transmute::<$($backend)::*::Uint8x32>();
+
+            if $lanes > 8 {
+                x.shuffle_bytes($($backend)::*::Uint8x32::from_array(bytes)).transmute()
+            } else {
+                x.transmute()
+            }
+        });
+    };
+
+    (double: $name: ident, $lanes: literal) => {
+        impl_broadcast_lane!(@doc crate::$name, self, {
+            let (dwords, bytes) = const {
+                assert!(I < $lanes, "Lane index is out of range.");
+
+                let (size, lane) = (64 / $lanes, I % ($lanes / 2));
+                (broadcast_dwords_indices(lane, size), broadcast_bytes_indices(lane * size % 4, size))
+            };
+
+            let half = if I < $lanes / 2 { self.0 } else { self.1 };
+
+            let dwords = crate::Int32x8::from_array(dwords);
+            let x = half.transmute::<crate::Uint32x8>().permute(dwords);
+            let x = x.transmute::<crate::Uint8x32>();
+            let x = if $lanes > 16 { x.shuffle_bytes(crate::Uint8x32::from_array(bytes)) } else { x };
+
+            Self(x.transmute(), x.transmute())
+        });
+    };
+
+    (avx512: $name: ident, $lanes: literal) => {
+        impl_broadcast_lane!(@doc crate::avx512::$name, self, {
+            let (dwords, bytes) = const {
+                assert!(I < $lanes, "Lane index is out of range.");
+
+                let size = 64 / $lanes;
+                (broadcast_dwords_indices(I, size), broadcast_bytes_indices(I * size % 4, size))
+            };
+
+            let dwords = crate::avx512::Int32x16::from_array(dwords);
+            let bytes = crate::avx512::Uint8x64::from_array(bytes);
+            let x = self.transmute::<crate::avx512::Uint8x64>();
+
+            unsafe {
+                let x = _mm512_permutexvar_epi32(dwords.0, x.0);
+                let x = if $lanes > 16 { _mm512_shuffle_epi8(x, bytes.0) } else { x };
+
+                crate::avx512::Uint8x64(x).transmute()
+            }
+        });
+    };
+}
+
+/// Lane indices moving lanes selected by every 8-bit mask to the front, followed by the other
+/// lanes. Both groups keep their order.
+static COMPRESS_INDICES: [[i32; 8]; 256] = {
+    let mut table = [[0; 8]; 256];
+
+    let mut mask = 0;
+    while mask < 256 {
+        let mut count = 0;
+
+        // Selected lanes are taken in the first pass and the other ones in the second.
+        let mut i: usize = 0;
+        while i < 16 {
+            let lane = i % 8;
+            if ((mask >> lane) & 1 != 0) == (i < 8) {
+                table[mask][count] = lane as i32;
+                count += 1;
+            }
+            i += 1;
+        }
+
+        mask += 1;
+    }
+
+    table
+};
+
+macro_rules! impl_compress {
+    ($name: ty, $indices: ty) => {
+        impl $name {
+            /// Moves lanes selected by the low 8 bits of `mask` to the beginning of the vector,
+            /// followed by the other lanes. Both groups keep their order, so the first
+            /// `mask.count_ones()` lanes are the selected ones.
+            #[inline(always)]
+            #[must_use]
+            pub fn compress(self, mask: u32) -> Self {
+                let indices = COMPRESS_INDICES[(mask & 0xff) as usize];
+
+                self.permute(<$indices>::from_array(indices))
+            }
+        }
+    };
+}
+
+macro_rules! impl_shuffle_alias {
+    ($name: ty) => {
+        impl $name {
+            /// Same as `shuffle_within_lanes`, named like the `shuffle` of float vectors.
+            #[inline(always)]
+            #[must_use]
+            pub fn shuffle<const PATTERN: i32>(self) -> Self {
+                self.shuffle_within_lanes::<PATTERN>()
+            }
+        }
+    };
+}
+
+/// `shuffle_epi8` indices which set 32-bit element `i` of every 128-bit lane to element
+/// `lanes[i]` of the same lane.
+const fn shuffle_lanes_indices<const N: usize>(lanes: [usize; 4]) -> [u8; N] {
+    let mut indices = [0; N];
+
+    let mut i = 0;
+    while i < indices.len() {
+        indices[i] = (lanes[i % 16 / 4] * 4 + i % 4) as u8;
+        i += 1;
+    }
+
+    indices
+}
+
+macro_rules! impl_shuffle_lanes {
+    (@doc $name: ty, $self: ident, $body: expr) => {
+        impl $name {
+            /// Sets element `i` of every 128-bit lane to the element of the same lane selected by
+            /// the `i`-th index, for example `shuffle_lanes::<3, 2, 1, 0>()` reverses every lane.
+            #[inline(always)]
+            #[must_use]
+            pub fn shuffle_lanes<const A: usize, const B: usize, const C: usize, const D: usize>(
+                $self,
+            ) -> Self {
+                $body
+            }
+        }
+    };
+
+    ($name: ty, $bytes: ty) => {
+        impl_shuffle_lanes!(@doc $name, self, {
+            let indices = const {
+                assert!(A < 4 && B < 4 && C < 4 && D < 4, "Shuffle index is out of range.");
+
+                shuffle_lanes_indices([A, B, C, D])
+            };
+
+            let bytes = self.transmute::<$bytes>();
+
+            bytes.shuffle_bytes(<$bytes>::from_array(indices)).transmute()
+        });
+    };
+
+    (double: $name: ty) => {
+        impl_shuffle_lanes!(@doc $name, self, {
+            Self(self.0.shuffle_lanes::<A, B, C, D>(), self.1.shuffle_lanes::<A, B, C, D>())
+        });
+    };
+
+    (avx512: $name: ty) => {
+        impl_shuffle_lanes!(@doc $name, self, {
+            let indices = const {
+                assert!(A < 4 && B < 4 && C < 4 && D < 4, "Shuffle index is out of range.");
+
+                shuffle_lanes_indices([A, B, C, D])
+            };
+
+            let bytes = self.transmute::<crate::avx512::Uint8x64>();
+            let indices = crate::avx512::Uint8x64::from_array(indices);
+
+            unsafe { crate::avx512::Uint8x64(_mm512_shuffle_epi8(bytes.0, indices.0)) }.transmute()
+        });
+    };
+}
+
+macro_rules! impl_shuffles_for_backend {
+    ($($backend: ident)::*) => {
+        impl_shuffle_alias!($($backend)::*::Int32x4);
+        impl_shuffle_alias!($($backend)::*::Uint32x4);
+        impl_shuffle_alias!($($backend)::*::Int32x8);
+        impl_shuffle_alias!($($backend)::*::Uint32x8);
+
+        impl_shuffle_lanes!($($backend)::*::Int32x4, $($backend)::*::Uint8x16);
+        impl_shuffle_lanes!($($backend)::*::Uint32x4, $($backend)::*::Uint8x16);
+        impl_shuffle_lanes!($($backend)::*::Float32x4, $($backend)::*::Uint8x16);
+        impl_shuffle_lanes!($($backend)::*::Int32x8, $($backend)::*::Uint8x32);
+        impl_shuffle_lanes!($($backend)::*::Uint32x8, $($backend)::*::Uint8x32);
+        impl_shuffle_lanes!($($backend)::*::Float32x8, $($backend)::*::Uint8x32);
+
+        impl_compress!($($backend)::*::Int32x8, $($backend)::*::Int32x8);
+        impl_compress!($($backend)::*::Uint32x8, $($backend)::*::Int32x8);
+        impl_compress!($($backend)::*::Float32x8, $($backend)::*::Int32x8);
+
+        impl_broadcast_lane!(128: $($backend)::*, Int8x16, 16);
+        impl_broadcast_lane!(128: $($backend)::*, Uint8x16, 16);
+        impl_broadcast_lane!(128: $($backend)::*, Int16x8, 8);
+        impl_broadcast_lane!(128: $($backend)::*, Uint16x8, 8);
+        impl_broadcast_lane!(128: $($backend)::*, Int32x4, 4);
+        impl_broadcast_lane!(128: $($backend)::*, Uint32x4, 4);
+        impl_broadcast_lane!(128: $($backend)::*, Int64x2, 2);
+        impl_broadcast_lane!(128: $($backend)::*, Uint64x2, 2);
+        impl_broadcast_lane!(128: $($backend)::*, Float32x4, 4);
+        impl_broadcast_lane!(128: $($backend)::*, Float64x2, 2);
+
+        impl_broadcast_lane!(256: $($backend)::*, Int8x32, 32);
+        impl_broadcast_lane!(256: $($backend)::*, Uint8x32, 32);
+        impl_broadcast_lane!(256: $($backend)::*, Int16x16, 16);
+        impl_broadcast_lane!(256: $($backend)::*, Uint16x16, 16);
+        impl_broadcast_lane!(256: $($backend)::*, Int32x8, 8);
+        impl_broadcast_lane!(256: $($backend)::*, Uint32x8, 8);
+        impl_broadcast_lane!(256: $($backend)::*, Int64x4, 4);
+        impl_broadcast_lane!(256: $($backend)::*, Uint64x4, 4);
+        impl_broadcast_lane!(256: $($backend)::*, Float32x8, 8);
+        impl_broadcast_lane!(256: $($backend)::*, Float64x4, 4);
+    };
+}
+
+impl_shuffles_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_shuffles_for_backend!(crate::reference);
+
+impl_shuffle_alias!(crate::Int32x16);
+impl_shuffle_alias!(crate::Uint32x16);
+
+impl_shuffle_lanes!(double: crate::Int32x16);
+impl_shuffle_lanes!(double: crate::Uint32x16);
+impl_shuffle_lanes!(double: crate::Float32x16);
+
+impl_broadcast_lane!(double: Int8x64, 64);
+impl_broadcast_lane!(double: Uint8x64, 64);
+impl_broadcast_lane!(double: Int16x32, 32);
+impl_broadcast_lane!(double: Uint16x32, 32);
+impl_broadcast_lane!(double: Int32x16, 16);
+impl_broadcast_lane!(double: Uint32x16, 16);
+impl_broadcast_lane!(double: Int64x8, 8);
+impl_broadcast_lane!(double: Uint64x8, 8);
+impl_broadcast_lane!(double: Float32x16, 16);
+impl_broadcast_lane!(double: Float64x8, 8);
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_shuffle_lanes!(avx512: crate::avx512::Int32x16);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_shuffle_lanes!(avx512: crate::avx512::Uint32x16);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_shuffle_lanes!(avx512: crate::avx512::Float32x16);
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Int8x64, 64);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Uint8x64, 64);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Int16x32, 32);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Uint16x32, 32);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Int32x16, 16);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Uint32x16, 16);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Int64x8, 8);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Uint64x8, 8);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Float32x16, 16);
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_broadcast_lane!(avx512: Float64x8, 8);
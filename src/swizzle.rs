@@ -0,0 +1,380 @@
+//! Implementation details of the [`swizzle!`](crate::swizzle!) and
+//! [`swizzle2!`](crate::swizzle2!) macros. Patterns are classified at compile time so that every
+//! swizzle is lowered to the cheapest instruction sequence which implements it.
+
+pub const IDENTITY: u8 = 0;
+/// Same shuffle within both 128-bit lanes (`vpermilps`).
+pub const IN_LANE: u8 = 1;
+/// Permutation of 64-bit elements across lanes (`vpermpd`).
+pub const PERMUTE: u8 = 2;
+/// Arbitrary permutation (`vpermps` with an index vector).
+pub const GENERAL: u8 = 3;
+/// Every element stays in place but comes from either input (`vblendps`/`vblendpd`).
+pub const BLEND: u8 = 4;
+/// Same two-input shuffle within both 128-bit lanes (`vshufps`/`vshufpd`).
+pub const SHUFFLE: u8 = 5;
+
+pub struct Plan {
+    pub kind: u8,
+    pub imm: i32,
+    pub blend: i32,
+}
+
+/// Classifies a single input pattern.
+pub const fn plan<const N: usize>(pattern: [u8; N]) -> Plan {
+    assert!(
+        N == 4 || N == 8,
+        "Swizzle pattern must have 4 or 8 indices."
+    );
+
+    let mut identity = true;
+    let mut i = 0;
+    while i < N {
+        assert!((pattern[i] as usize) < N, "Swizzle index is out of range.");
+        identity &= pattern[i] as usize == i;
+        i += 1;
+    }
+
+    if identity {
+        return Plan {
+            kind: IDENTITY,
+            imm: 0,
+            blend: 0,
+        };
+    }
+
+    if N == 4 {
+        let mut imm = 0;
+        let mut i = 0;
+        while i < 4 {
+            imm |= (pattern[i] as i32) << (i * 2);
+            i += 1;
+        }
+
+        return Plan {
+            kind: PERMUTE,
+            imm,
+            blend: 0,
+        };
+    }
+
+    let mut in_lane = true;
+    let mut imm = 0;
+    let mut i = 0;
+    while i < 4 {
+        in_lane &= pattern[i] < 4 && pattern[i + 4] == pattern[i] + 4;
+        imm |= (pattern[i] as i32 & 3) << (i * 2);
+        i += 1;
+    }
+
+    if in_lane {
+        return Plan {
+            kind: IN_LANE,
+            imm,
+            blend: 0,
+        };
+    }
+
+    let mut permute = true;
+    let mut imm = 0;
+    let mut i = 0;
+    while i < 4 {
+        permute &= pattern[i * 2].is_multiple_of(2) && pattern[i * 2 + 1] == pattern[i * 2] + 1;
+        imm |= (pattern[i * 2] as i32 / 2) << (i * 2);
+        i += 1;
+    }
+
+    if permute {
+        return Plan {
+            kind: PERMUTE,
+            imm,
+            blend: 0,
+        };
+    }
+
+    Plan {
+        kind: GENERAL,
+        imm: 0,
+        blend: 0,
+    }
+}
+
+/// Classifies a two input pattern, where indices `N..2 * N` select elements of the second input.
+pub const fn plan2<const N: usize>(pattern: [u8; N]) -> Plan {
+    assert!(
+        N == 4 || N == 8,
+        "Swizzle pattern must have 4 or 8 indices."
+    );
+
+    let mut in_place = true;
+    let mut blend = 0;
+    let mut i = 0;
+    while i < N {
+        let index = pattern[i] as usize;
+        assert!(index < N * 2, "Swizzle index is out of range.");
+
+        in_place &= index % N == i;
+        blend |= ((index >= N) as i32) << i;
+        i += 1;
+    }
+
+    if in_place {
+        let kind = if blend == 0 { IDENTITY } else { BLEND };
+        return Plan {
+            kind,
+            imm: 0,
+            blend,
+        };
+    }
+
+    // Within every 128-bit lane the low half of the result comes from the first input and
+    // the high half from the second one.
+    let half = N / 4;
+    let mut shuffle = true;
+    let mut imm = 0;
+    let mut i = 0;
+    while i < N {
+        let lane_start = i / (N / 2) * (N / 2);
+        let source_start = if i % (N / 2) < half {
+            lane_start
+        } else {
+            lane_start + N
+        };
+
+        let index = pattern[i] as usize;
+        if index < source_start || index >= source_start + N / 2 {
+            shuffle = false;
+        } else if N == 8 {
+            let relative = (index - source_start) as i32;
+            shuffle &= i >= 4 || pattern[i + 4] as usize == index + 4;
+            imm |= relative << ((i % 4) * 2);
+        } else {
+            imm |= ((index - source_start) as i32) << i;
+        }
+
+        i += 1;
+    }
+
+    if shuffle {
+        return Plan {
+            kind: SHUFFLE,
+            imm,
+            blend: 0,
+        };
+    }
+
+    // Permute both inputs using the same indices and blend the results.
+    let mut imm = 0;
+    if N == 4 {
+        let mut i = 0;
+        while i < 4 {
+            imm |= (pattern[i] as i32 % 4) << (i * 2);
+            i += 1;
+        }
+    }
+
+    Plan {
+        kind: GENERAL,
+        imm,
+        blend,
+    }
+}
+
+#[doc(hidden)]
+pub trait Swizzle<const N: usize>: Sized {
+    fn swizzle<const KIND: u8, const IMM: i32>(self, pattern: [u8; N]) -> Self;
+
+    fn swizzle2<const KIND: u8, const IMM: i32, const MASK: i32>(
+        self,
+        other: Self,
+        pattern: [u8; N],
+    ) -> Self;
+}
+
+#[cfg(all(target_feature = "sse4.1", not(feature = "soft")))]
+mod intrinsics {
+    use super::*;
+    use crate::arch::*;
+    use crate::conversion::VectorBits;
+    use crate::*;
+
+    #[inline(always)]
+    fn permute_indices(pattern: [u8; 8]) -> __m256i {
+        let indices = pattern.map(|i| (i % 8) as i32);
+
+        unsafe { _mm256_loadu_si256(indices.as_ptr() as *const __m256i) }
+    }
+
+    macro_rules! impl_swizzle_32 {
+        ($($name: ident),*) => {
+            $(
+                impl Swizzle<8> for $name {
+                    #[inline(always)]
+                    fn swizzle<const KIND: u8, const IMM: i32>(self, pattern: [u8; 8]) -> Self {
+                        unsafe {
+                            let x = _mm256_castsi256_ps(self.to_bits());
+
+                            let result = match KIND {
+                                IDENTITY => x,
+                                IN_LANE => _mm256_permute_ps::<IMM>(x),
+                                PERMUTE => _mm256_castpd_ps(_mm256_permute4x64_pd::<IMM>(
+                                    _mm256_castps_pd(x),
+                                )),
+                                _ => _mm256_permutevar8x32_ps(x, permute_indices(pattern)),
+                            };
+
+                            Self::from_bits(_mm256_castps_si256(result))
+                        }
+                    }
+
+                    #[inline(always)]
+                    fn swizzle2<const KIND: u8, const IMM: i32, const MASK: i32>(
+                        self,
+                        other: Self,
+                        pattern: [u8; 8],
+                    ) -> Self {
+                        unsafe {
+                            let a = _mm256_castsi256_ps(self.to_bits());
+                            let b = _mm256_castsi256_ps(other.to_bits());
+
+                            let result = match KIND {
+                                IDENTITY => a,
+                                BLEND => _mm256_blend_ps::<MASK>(a, b),
+                                SHUFFLE => _mm256_shuffle_ps::<IMM>(a, b),
+                                _ => {
+                                    let indices = permute_indices(pattern);
+
+                                    _mm256_blend_ps::<MASK>(
+                                        _mm256_permutevar8x32_ps(a, indices),
+                                        _mm256_permutevar8x32_ps(b, indices),
+                                    )
+                                }
+                            };
+
+                            Self::from_bits(_mm256_castps_si256(result))
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_swizzle_64 {
+        ($($name: ident),*) => {
+            $(
+                impl Swizzle<4> for $name {
+                    #[inline(always)]
+                    fn swizzle<const KIND: u8, const IMM: i32>(self, _pattern: [u8; 4]) -> Self {
+                        unsafe {
+                            let x = _mm256_castsi256_pd(self.to_bits());
+
+                            let result = match KIND {
+                                IDENTITY => x,
+                                _ => _mm256_permute4x64_pd::<IMM>(x),
+                            };
+
+                            Self::from_bits(_mm256_castpd_si256(result))
+                        }
+                    }
+
+                    #[inline(always)]
+                    fn swizzle2<const KIND: u8, const IMM: i32, const MASK: i32>(
+                        self,
+                        other: Self,
+                        _pattern: [u8; 4],
+                    ) -> Self {
+                        unsafe {
+                            let a = _mm256_castsi256_pd(self.to_bits());
+                            let b = _mm256_castsi256_pd(other.to_bits());
+
+                            let result = match KIND {
+                                IDENTITY => a,
+                                BLEND => _mm256_blend_pd::<MASK>(a, b),
+                                SHUFFLE => _mm256_shuffle_pd::<IMM>(a, b),
+                                _ => _mm256_blend_pd::<MASK>(
+                                    _mm256_permute4x64_pd::<IMM>(a),
+                                    _mm256_permute4x64_pd::<IMM>(b),
+                                ),
+                            };
+
+                            Self::from_bits(_mm256_castpd_si256(result))
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_swizzle_32!(Float32x8, Int32x8, Uint32x8);
+    impl_swizzle_64!(Float64x4, Int64x4, Uint64x4);
+}
+
+#[cfg(any(feature = "reference", not(target_feature = "sse4.1"), feature = "soft"))]
+mod reference {
+    use super::Swizzle;
+    use crate::reference::*;
+
+    macro_rules! impl_swizzle_reference {
+        ($lanes: expr, $($name: ident),*) => {
+            $(
+                impl Swizzle<$lanes> for $name {
+                    #[inline(always)]
+                    fn swizzle<const KIND: u8, const IMM: i32>(
+                        self,
+                        pattern: [u8; $lanes],
+                    ) -> Self {
+                        let a = self.to_array();
+
+                        Self::from_array(pattern.map(|i| a[i as usize]))
+                    }
+
+                    #[inline(always)]
+                    fn swizzle2<const KIND: u8, const IMM: i32, const MASK: i32>(
+                        self,
+                        other: Self,
+                        pattern: [u8; $lanes],
+                    ) -> Self {
+                        let (a, b) = (self.to_array(), other.to_array());
+
+                        Self::from_array(pattern.map(|i| {
+                            let i = i as usize;
+                            if i < $lanes { a[i] } else { b[i - $lanes] }
+                        }))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_swizzle_reference!(8, Float32x8, Int32x8, Uint32x8);
+    impl_swizzle_reference!(4, Float64x4, Int64x4, Uint64x4);
+}
+
+/// Rearranges elements of a vector with 32-bit or 64-bit elements according to a constant
+/// pattern of source indices, for example `swizzle!(v, [7, 6, 5, 4, 3, 2, 1, 0])` reverses
+/// a `Float32x8`. The pattern is validated and lowered to the cheapest instruction sequence
+/// at compile time.
+#[macro_export]
+macro_rules! swizzle {
+    ($v: expr, [$($index: expr),+ $(,)?]) => {{
+        const PATTERN: [u8; [$($index),+].len()] = [$($index),+];
+        const PLAN: $crate::swizzle::Plan = $crate::swizzle::plan(PATTERN);
+
+        $crate::swizzle::Swizzle::swizzle::<{ PLAN.kind }, { PLAN.imm }>($v, PATTERN)
+    }};
+}
+
+/// Two input version of [`swizzle!`]. Indices past the last lane select elements of the second
+/// vector, for example `swizzle2!(a, b, [0, 8, 1, 9, 2, 10, 3, 11])` interleaves low halves
+/// of two `Float32x8` vectors.
+#[macro_export]
+macro_rules! swizzle2 {
+    ($a: expr, $b: expr, [$($index: expr),+ $(,)?]) => {{
+        const PATTERN: [u8; [$($index),+].len()] = [$($index),+];
+        const PLAN: $crate::swizzle::Plan = $crate::swizzle::plan2(PATTERN);
+
+        $crate::swizzle::Swizzle::swizzle2::<{ PLAN.kind }, { PLAN.imm }, { PLAN.blend }>(
+            $a, $b, PATTERN,
+        )
+    }};
+}
@@ -0,0 +1,487 @@
+use core::{fmt, ops};
+
+use crate::*;
+
+/// Scalar types stored in lanes of vector types.
+pub trait SimdElement: Copy + Default + PartialEq + PartialOrd + fmt::Debug + 'static {}
+
+impl SimdElement for f32 {}
+impl SimdElement for f64 {}
+impl SimdElement for i8 {}
+impl SimdElement for u8 {}
+impl SimdElement for i16 {}
+impl SimdElement for u16 {}
+impl SimdElement for i32 {}
+impl SimdElement for u32 {}
+impl SimdElement for i64 {}
+impl SimdElement for u64 {}
+
+/// Operations shared by all vector types, for code which is generic over the vector type.
+pub trait SimdVector: Copy {
+    type Element: SimdElement;
+    type Array: Copy + AsRef<[Self::Element]> + AsMut<[Self::Element]>;
+
+    const LANES: usize;
+
+    fn zero() -> Self;
+    fn splat(v: Self::Element) -> Self;
+
+    fn from_array(array: Self::Array) -> Self;
+    fn to_array(self) -> Self::Array;
+}
+
+macro_rules! impl_simd_vector {
+    ($name: ident, $type: ty, $lanes: expr) => {
+        impl SimdVector for $name {
+            type Element = $type;
+            type Array = [$type; $lanes];
+
+            const LANES: usize = $lanes;
+
+            #[inline(always)]
+            fn zero() -> Self {
+                $name::zero()
+            }
+
+            #[inline(always)]
+            fn splat(v: $type) -> Self {
+                $name::splat(v)
+            }
+
+            #[inline(always)]
+            fn from_array(array: [$type; $lanes]) -> Self {
+                $name::from_array(array)
+            }
+
+            #[inline(always)]
+            fn to_array(self) -> [$type; $lanes] {
+                $name::to_array(self)
+            }
+        }
+    };
+}
+
+impl_simd_vector!(Float32x4, f32, 4);
+impl_simd_vector!(Float64x2, f64, 2);
+
+impl_simd_vector!(Int8x16, i8, 16);
+impl_simd_vector!(Uint8x16, u8, 16);
+
+impl_simd_vector!(Int16x8, i16, 8);
+impl_simd_vector!(Uint16x8, u16, 8);
+
+impl_simd_vector!(Int32x4, i32, 4);
+impl_simd_vector!(Uint32x4, u32, 4);
+
+impl_simd_vector!(Int64x2, i64, 2);
+impl_simd_vector!(Uint64x2, u64, 2);
+
+impl_simd_vector!(Float32x8, f32, 8);
+impl_simd_vector!(Float64x4, f64, 4);
+
+impl_simd_vector!(Int8x32, i8, 32);
+impl_simd_vector!(Uint8x32, u8, 32);
+
+impl_simd_vector!(Int16x16, i16, 16);
+impl_simd_vector!(Uint16x16, u16, 16);
+
+impl_simd_vector!(Int32x8, i32, 8);
+impl_simd_vector!(Uint32x8, u32, 8);
+
+impl_simd_vector!(Int64x4, i64, 4);
+impl_simd_vector!(Uint64x4, u64, 4);
+
+impl_simd_vector!(Float32x16, f32, 16);
+impl_simd_vector!(Float64x8, f64, 8);
+
+impl_simd_vector!(Int8x64, i8, 64);
+impl_simd_vector!(Uint8x64, u8, 64);
+
+impl_simd_vector!(Int16x32, i16, 32);
+impl_simd_vector!(Uint16x32, u16, 32);
+
+impl_simd_vector!(Int32x16, i32, 16);
+impl_simd_vector!(Uint32x16, u32, 16);
+
+impl_simd_vector!(Int64x8, i64, 8);
+impl_simd_vector!(Uint64x8, u64, 8);
+
+/// Operations shared by all floating-point vector types. Comparisons set all bits of lanes for
+/// which they hold and clear the other lanes.
+pub trait SimdFloat:
+    SimdVector
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + ops::BitAnd<Output = Self>
+    + ops::BitOr<Output = Self>
+    + ops::BitXor<Output = Self>
+    + ops::Not<Output = Self>
+    + fmt::Debug
+{
+    fn eq(self, rhs: Self) -> Self;
+    fn ne(self, rhs: Self) -> Self;
+    fn gt(self, rhs: Self) -> Self;
+    fn lt(self, rhs: Self) -> Self;
+    fn ge(self, rhs: Self) -> Self;
+    fn le(self, rhs: Self) -> Self;
+
+    fn min(self, rhs: Self) -> Self;
+    fn max(self, rhs: Self) -> Self;
+
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn trunc(self) -> Self;
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+
+    /// Bit `i` is the sign bit of lane `i`.
+    fn mask(self) -> u64;
+    fn is_zero(self) -> bool;
+
+    /// ~self & rhs
+    fn andnot(self, rhs: Self) -> Self;
+
+    /// Takes lanes of `if_true` where `self` has all bits set and lanes of `if_false` where it
+    /// has none.
+    #[inline(always)]
+    fn select(self, if_true: Self, if_false: Self) -> Self {
+        (self & if_true) | self.andnot(if_false)
+    }
+}
+
+/// Operations shared by all integer vector types. Comparisons set all bits of lanes for which
+/// they hold and clear the other lanes.
+pub trait SimdInt:
+    SimdVector
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::BitAnd<Output = Self>
+    + ops::BitOr<Output = Self>
+    + ops::BitXor<Output = Self>
+    + ops::Not<Output = Self>
+    + fmt::Debug
+{
+    fn eq(self, rhs: Self) -> Self;
+    fn ne(self, rhs: Self) -> Self;
+    fn gt(self, rhs: Self) -> Self;
+    fn lt(self, rhs: Self) -> Self;
+    fn ge(self, rhs: Self) -> Self;
+    fn le(self, rhs: Self) -> Self;
+
+    fn min(self, rhs: Self) -> Self;
+    fn max(self, rhs: Self) -> Self;
+
+    /// Bit `i` is the most significant bit of byte `i`.
+    fn mask(self) -> u64;
+    fn is_zero(self) -> bool;
+
+    /// ~self & rhs
+    fn andnot(self, rhs: Self) -> Self;
+
+    /// Takes lanes of `if_true` where `self` has all bits set and lanes of `if_false` where it
+    /// has none.
+    #[inline(always)]
+    fn select(self, if_true: Self, if_false: Self) -> Self {
+        (self & if_true) | self.andnot(if_false)
+    }
+}
+
+macro_rules! forward {
+    ($($method: ident($($argument: ident)?)),*) => {
+        $(
+            #[inline(always)]
+            fn $method(self $(, $argument: Self)?) -> Self {
+                Self::$method(self $(, $argument)?)
+            }
+        )*
+    };
+}
+
+macro_rules! impl_simd_float {
+    ($($name: ident),*) => {
+        $(
+            impl SimdFloat for $name {
+                forward!(
+                    eq(rhs), ne(rhs), gt(rhs), lt(rhs), ge(rhs), le(rhs), min(rhs), max(rhs),
+                    andnot(rhs), floor(), ceil(), trunc(), round(), sqrt()
+                );
+
+                #[inline(always)]
+                fn mask(self) -> u64 {
+                    $name::mask(self) as u64
+                }
+
+                #[inline(always)]
+                fn is_zero(self) -> bool {
+                    $name::is_zero(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_simd_int {
+    ($($name: ident),*) => {
+        $(
+            impl SimdInt for $name {
+                forward!(
+                    eq(rhs), ne(rhs), gt(rhs), lt(rhs), ge(rhs), le(rhs), min(rhs), max(rhs),
+                    andnot(rhs)
+                );
+
+                #[inline(always)]
+                fn mask(self) -> u64 {
+                    $name::mask(self) as u64
+                }
+
+                #[inline(always)]
+                fn is_zero(self) -> bool {
+                    $name::is_zero(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_simd_float!(Float32x4, Float64x2, Float32x8, Float64x4, Float32x16, Float64x8);
+
+impl_simd_int!(
+    Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2, Int8x32, Uint8x32,
+    Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4, Int8x64, Uint8x64, Int16x32,
+    Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8
+);
+
+/// Element types for which a vector type with `N` lanes exists. Together with [`Simd`] it allows
+/// writing code which is generic over the lane type and count.
+pub trait SimdLanes<const N: usize>: Copy {
+    type Vector: SimdVector<Element = Self, Array = [Self; N]>
+        + ops::Add<Output = Self::Vector>
+        + ops::Sub<Output = Self::Vector>
+        + ops::BitAnd<Output = Self::Vector>
+        + ops::BitOr<Output = Self::Vector>
+        + ops::BitXor<Output = Self::Vector>
+        + ops::Not<Output = Self::Vector>
+        + fmt::Debug;
+}
+
+/// Vector type with `N` lanes of type `T`, for example `Simd<f32, 8>` is `Float32x8`.
+pub type Simd<T, const N: usize> = <T as SimdLanes<N>>::Vector;
+
+macro_rules! impl_simd_lanes {
+    ($($type: ty => $($name: ident, $lanes: expr);*),*) => {
+        $($(
+            impl SimdLanes<$lanes> for $type {
+                type Vector = $name;
+            }
+        )*)*
+    };
+}
+
+impl_simd_lanes!(
+    f32 => Float32x4, 4; Float32x8, 8; Float32x16, 16,
+    f64 => Float64x2, 2; Float64x4, 4; Float64x8, 8,
+    i8 => Int8x16, 16; Int8x32, 32; Int8x64, 64,
+    u8 => Uint8x16, 16; Uint8x32, 32; Uint8x64, 64,
+    i16 => Int16x8, 8; Int16x16, 16; Int16x32, 32,
+    u16 => Uint16x8, 8; Uint16x16, 16; Uint16x32, 32,
+    i32 => Int32x4, 4; Int32x8, 8; Int32x16, 16,
+    u32 => Uint32x4, 4; Uint32x8, 8; Uint32x16, 16,
+    i64 => Int64x2, 2; Int64x4, 4; Int64x8, 8,
+    u64 => Uint64x2, 2; Uint64x4, 4; Uint64x8, 8
+);
+
+/// Element types of [`Batch`] vectors.
+pub trait BatchElement: SimdElement {
+    type Vector: SimdVector<Element = Self>
+        + ops::Add<Output = Self::Vector>
+        + ops::Sub<Output = Self::Vector>
+        + ops::BitAnd<Output = Self::Vector>
+        + ops::BitOr<Output = Self::Vector>
+        + ops::BitXor<Output = Self::Vector>
+        + ops::Not<Output = Self::Vector>
+        + fmt::Debug;
+}
+
+/// Vector of `T` with the width selected by cargo features: 128 bits with `batch128`, 512 bits
+/// with `batch512` and 256 bits otherwise. 512-bit vectors are the native ones from the `avx512`
+/// module when it is available. `Batch::<T>::LANES` is its lane count.
+pub type Batch<T> = <T as BatchElement>::Vector;
+
+#[cfg(all(feature = "batch128", feature = "batch512"))]
+compile_error!("Only one of the `batch128` and `batch512` features can be enabled.");
+
+macro_rules! impl_batch {
+    ($($type: ty => $name: ty),*) => {
+        $(
+            impl BatchElement for $type {
+                type Vector = $name;
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "batch128", not(feature = "batch512")))]
+impl_batch!(
+    f32 => Float32x4, f64 => Float64x2, i8 => Int8x16, u8 => Uint8x16, i16 => Int16x8,
+    u16 => Uint16x8, i32 => Int32x4, u32 => Uint32x4, i64 => Int64x2, u64 => Uint64x2
+);
+
+#[cfg(not(any(feature = "batch128", feature = "batch512")))]
+impl_batch!(
+    f32 => Float32x8, f64 => Float64x4, i8 => Int8x32, u8 => Uint8x32, i16 => Int16x16,
+    u16 => Uint16x16, i32 => Int32x8, u32 => Uint32x8, i64 => Int64x4, u64 => Uint64x4
+);
+
+#[cfg(all(
+    feature = "batch512",
+    not(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))
+))]
+impl_batch!(
+    f32 => Float32x16, f64 => Float64x8, i8 => Int8x64, u8 => Uint8x64, i16 => Int16x32,
+    u16 => Uint16x32, i32 => Int32x16, u32 => Uint32x16, i64 => Int64x8, u64 => Uint64x8
+);
+
+#[cfg(all(
+    feature = "batch512",
+    feature = "avx512",
+    target_feature = "avx512f",
+    target_feature = "avx512bw"
+))]
+impl_batch!(
+    f32 => crate::avx512::Float32x16, f64 => crate::avx512::Float64x8,
+    i8 => crate::avx512::Int8x64, u8 => crate::avx512::Uint8x64,
+    i16 => crate::avx512::Int16x32, u16 => crate::avx512::Uint16x32,
+    i32 => crate::avx512::Int32x16, u32 => crate::avx512::Uint32x16,
+    i64 => crate::avx512::Int64x8, u64 => crate::avx512::Uint64x8
+);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+mod reference {
+    use super::{SimdFloat, SimdInt, SimdVector};
+    use crate::reference::*;
+
+    impl_simd_float!(Float32x4, Float64x2, Float32x8, Float64x4);
+
+    impl_simd_int!(
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2, Int8x32,
+        Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+    );
+
+    impl_simd_vector!(Float32x4, f32, 4);
+    impl_simd_vector!(Float64x2, f64, 2);
+
+    impl_simd_vector!(Int8x16, i8, 16);
+    impl_simd_vector!(Uint8x16, u8, 16);
+
+    impl_simd_vector!(Int16x8, i16, 8);
+    impl_simd_vector!(Uint16x8, u16, 8);
+
+    impl_simd_vector!(Int32x4, i32, 4);
+    impl_simd_vector!(Uint32x4, u32, 4);
+
+    impl_simd_vector!(Int64x2, i64, 2);
+    impl_simd_vector!(Uint64x2, u64, 2);
+
+    impl_simd_vector!(Float32x8, f32, 8);
+    impl_simd_vector!(Float64x4, f64, 4);
+
+    impl_simd_vector!(Int8x32, i8, 32);
+    impl_simd_vector!(Uint8x32, u8, 32);
+
+    impl_simd_vector!(Int16x16, i16, 16);
+    impl_simd_vector!(Uint16x16, u16, 16);
+
+    impl_simd_vector!(Int32x8, i32, 8);
+    impl_simd_vector!(Uint32x8, u32, 8);
+
+    impl_simd_vector!(Int64x4, i64, 4);
+    impl_simd_vector!(Uint64x4, u64, 4);
+}
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+mod avx512 {
+    use super::{SimdFloat, SimdInt, SimdVector};
+    use crate::avx512::*;
+
+    /// Comparisons of native AVX-512 vectors return bitmasks, which are expanded to lanes with
+    /// all bits set or clear.
+    macro_rules! expand_comparisons {
+        ($($comparison: ident),*) => {
+            $(
+                #[inline(always)]
+                fn $comparison(self, rhs: Self) -> Self {
+                    Self::zero().blend_mask(!Self::zero(), Self::$comparison(self, rhs))
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_simd_float {
+        ($($name: ident),*) => {
+            $(
+                impl SimdFloat for $name {
+                    expand_comparisons!(eq, ne, gt, lt, ge, le);
+
+                    forward!(
+                        min(rhs), max(rhs), andnot(rhs), floor(), ceil(), trunc(), round(), sqrt()
+                    );
+
+                    #[inline(always)]
+                    fn mask(self) -> u64 {
+                        $name::mask(self) as u64
+                    }
+
+                    #[inline(always)]
+                    fn is_zero(self) -> bool {
+                        $name::is_zero(self)
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_simd_int {
+        ($($name: ident),*) => {
+            $(
+                impl SimdInt for $name {
+                    expand_comparisons!(eq, ne, gt, lt, ge, le);
+
+                    forward!(min(rhs), max(rhs), andnot(rhs));
+
+                    #[inline(always)]
+                    fn mask(self) -> u64 {
+                        self.transmute::<Uint8x64>().mask()
+                    }
+
+                    #[inline(always)]
+                    fn is_zero(self) -> bool {
+                        $name::is_zero(self)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_simd_float!(Float32x16, Float64x8);
+
+    impl_simd_int!(
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8
+    );
+
+    impl_simd_vector!(Float32x16, f32, 16);
+    impl_simd_vector!(Float64x8, f64, 8);
+
+    impl_simd_vector!(Int8x64, i8, 64);
+    impl_simd_vector!(Uint8x64, u8, 64);
+
+    impl_simd_vector!(Int16x32, i16, 32);
+    impl_simd_vector!(Uint16x32, u16, 32);
+
+    impl_simd_vector!(Int32x16, i32, 16);
+    impl_simd_vector!(Uint32x16, u32, 16);
+
+    impl_simd_vector!(Int64x8, i64, 8);
+    impl_simd_vector!(Uint64x8, u64, 8);
+}
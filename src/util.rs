@@ -0,0 +1,82 @@
+use core::mem::MaybeUninit;
+
+use crate::{Float32x8, SimdFloat};
+
+/// Same as the unstable `MaybeUninit::slice_assume_init_mut`.
+#[inline(always)]
+pub(crate) unsafe fn slice_assume_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    &mut *(slice as *mut [MaybeUninit<T>] as *mut [T])
+}
+
+/// Clears the sign bit of every lane.
+#[inline(always)]
+pub(crate) fn abs<V: SimdFloat>(x: V) -> V
+where
+    V::Element: From<f32>,
+{
+    V::splat((-0.0).into()).andnot(x)
+}
+
+/// (a * b) + c, fused when FMA is available.
+#[inline(always)]
+pub(crate) fn mul_add(a: Float32x8, b: Float32x8, c: Float32x8) -> Float32x8 {
+    #[cfg(target_feature = "fma")]
+    return a.fmadd(b, c);
+
+    #[cfg(not(target_feature = "fma"))]
+    return a * b + c;
+}
+
+/// Picks lanes of `a` where `mask` is set and lanes of `b` elsewhere.
+#[inline(always)]
+pub(crate) fn select(mask: Float32x8, a: Float32x8, b: Float32x8) -> Float32x8 {
+    Float32x8::bitselect(mask, a, b)
+}
+
+/// Portable equivalent of BMI2 `pext`: gathers bits of `x` selected by `mask` into the low bits
+/// of the result.
+#[cfg(any(
+    not(all(target_feature = "sse4.1", target_feature = "bmi2")),
+    feature = "reference",
+    feature = "soft"
+))]
+#[inline(always)]
+pub(crate) fn extract_bits(x: u64, mut mask: u64) -> u64 {
+    let mut result = 0;
+    let mut bit = 1;
+
+    while mask != 0 {
+        if x & mask & mask.wrapping_neg() != 0 {
+            result |= bit;
+        }
+
+        bit <<= 1;
+        mask &= mask - 1;
+    }
+
+    result
+}
+
+/// Portable equivalent of BMI2 `pdep`: scatters low bits of `x` to the positions of bits set
+/// in `mask`.
+#[cfg(any(
+    not(all(target_feature = "sse4.1", target_feature = "bmi2")),
+    feature = "reference",
+    feature = "soft"
+))]
+#[inline(always)]
+pub(crate) fn deposit_bits(x: u64, mut mask: u64) -> u64 {
+    let mut result = 0;
+    let mut bit = 1;
+
+    while mask != 0 {
+        if x & bit != 0 {
+            result |= mask & mask.wrapping_neg();
+        }
+
+        bit <<= 1;
+        mask &= mask - 1;
+    }
+
+    result
+}
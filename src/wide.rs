@@ -0,0 +1,295 @@
+//! Vectors of 128-bit integers. Every lane is stored in two 64-bit lanes of a `Uint64x4`, the low
+//! half first. Carries and comparisons are propagated between the halves within 128-bit lanes.
+
+use core::{fmt, ops};
+
+use paste::paste;
+
+use crate::{Int32x8, Int64x4, Uint64x4};
+
+const SIGN: u64 = 1 << 63;
+
+/// Moves the low half of every 128-bit lane into its high half and zeroes the low half.
+#[inline(always)]
+fn to_high(x: Uint64x4) -> Uint64x4 {
+    let moved = x.transmute::<Int32x8>().shuffle_within_lanes::<0b01_00_00_00>();
+
+    moved.transmute::<Uint64x4>() & Uint64x4::from_array([0, !0, 0, !0])
+}
+
+/// Moves the high half of every 128-bit lane into its low half and zeroes the high half.
+#[inline(always)]
+fn to_low(x: Uint64x4) -> Uint64x4 {
+    let moved = x.transmute::<Int32x8>().shuffle_within_lanes::<0b00_00_11_10>();
+
+    moved.transmute::<Uint64x4>() & Uint64x4::from_array([!0, 0, !0, 0])
+}
+
+/// Copies the high half of every 128-bit lane into its low half.
+#[inline(always)]
+fn broadcast_high(x: Uint64x4) -> Uint64x4 {
+    x.transmute::<Int32x8>().shuffle_within_lanes::<0b11_10_11_10>().transmute()
+}
+
+/// Signed comparison of 64-bit lanes after flipping the bits selected by `bias`.
+#[inline(always)]
+fn gt_biased(a: Uint64x4, b: Uint64x4, bias: Uint64x4) -> Uint64x4 {
+    (a ^ bias).transmute::<Int64x4>().gt((b ^ bias).transmute()).transmute()
+}
+
+macro_rules! impl_operator {
+    ($name: ident, $op: ident, $op_function: ident, $function: item) => {
+        impl ops::$op for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            $function
+        }
+
+        paste! {
+            impl ops::[<$op Assign>] for $name {
+                #[inline(always)]
+                fn [<$op_function _assign>](&mut self, rhs: Self) {
+                    *self = <Self as ops::$op>::$op_function(*self, rhs);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! make_vector_type {
+    ($name: ident, $type: ty, $unsigned_type: ty, $high_bias: expr) => {
+        #[derive(Copy, Clone)]
+        #[repr(transparent)]
+        pub struct $name(Uint64x4);
+
+        impl $name {
+            #[inline(always)]
+            #[must_use]
+            pub fn zero() -> Self {
+                Self(Uint64x4::zero())
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn splat(v: $type) -> Self {
+                Self::from_array([v; 2])
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn from_array(array: [$type; 2]) -> Self {
+                let [a, b] = array.map(|x| x as $unsigned_type);
+
+                Self(Uint64x4::from_array([
+                    a as u64,
+                    (a >> 64) as u64,
+                    b as u64,
+                    (b >> 64) as u64,
+                ]))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_array(self) -> [$type; 2] {
+                let x = self.0.to_array();
+
+                core::array::from_fn(|i| {
+                    (x[i * 2] as $unsigned_type | (x[i * 2 + 1] as $unsigned_type) << 64) as $type
+                })
+            }
+
+            /// Creates a vector from 64-bit halves of its lanes, the low half first.
+            #[inline(always)]
+            #[must_use]
+            pub fn from_halves(halves: Uint64x4) -> Self {
+                Self(halves)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn to_halves(self) -> Uint64x4 {
+                self.0
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn eq(self, rhs: Self) -> Self {
+                let eq = self.0.eq(rhs.0);
+
+                Self(broadcast_high(eq & to_high(eq)))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn gt(self, rhs: Self) -> Self {
+                let bias = Uint64x4::from_array([SIGN, $high_bias, SIGN, $high_bias]);
+                let gt = gt_biased(self.0, rhs.0, bias);
+
+                // High halves decide unless they are equal, then low halves do.
+                Self(broadcast_high(gt | (self.0.eq(rhs.0) & to_high(gt))))
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn lt(self, rhs: Self) -> Self {
+                rhs.gt(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn ne(self, rhs: Self) -> Self {
+                !self.eq(rhs)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn le(self, rhs: Self) -> Self {
+                !self.gt(rhs)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn ge(self, rhs: Self) -> Self {
+                !rhs.gt(self)
+            }
+
+            #[inline(always)]
+            #[must_use]
+            pub fn is_zero(self) -> bool {
+                self.0.is_zero()
+            }
+
+            /// ~self & rhs
+            #[inline(always)]
+            #[must_use]
+            pub fn andnot(self, rhs: Self) -> Self {
+                Self(self.0.andnot(rhs.0))
+            }
+
+            /// Takes bits of `if_set` where `mask` is set and bits of `if_clear` elsewhere.
+            #[inline(always)]
+            #[must_use]
+            pub fn bitselect(mask: Self, if_set: Self, if_clear: Self) -> Self {
+                Self(Uint64x4::bitselect(mask.0, if_set.0, if_clear.0))
+            }
+
+            /// Shifts the halves separately and carries bits across them. Shifts by 128 bits or
+            /// more give zero.
+            #[inline(always)]
+            #[must_use]
+            pub fn shl<const N: i32>(self) -> Self {
+                let n = N as u32;
+
+                if n < 64 {
+                    Self(self.0.shl_by(n) | to_high(self.0).shr_l_by(64 - n))
+                } else {
+                    Self(to_high(self.0).shl_by(n - 64))
+                }
+            }
+
+            /// Same as `shl`, but shifts right and fills with zeros.
+            #[inline(always)]
+            #[must_use]
+            pub fn shr_l<const N: i32>(self) -> Self {
+                let n = N as u32;
+
+                if n < 64 {
+                    Self(self.0.shr_l_by(n) | to_low(self.0).shl_by(64 - n))
+                } else {
+                    Self(to_low(self.0).shr_l_by(n - 64))
+                }
+            }
+        }
+
+        impl_operator! { $name, Add, add,
+            fn add(self, rhs: Self) -> Self::Output {
+                let sum = self.0 + rhs.0;
+                let carry = gt_biased(self.0, sum, Uint64x4::splat(SIGN));
+
+                // Carry masks are -1, subtracting them adds one to the high halves.
+                Self(sum - to_high(carry))
+            }
+        }
+
+        impl_operator! { $name, Sub, sub,
+            fn sub(self, rhs: Self) -> Self::Output {
+                let difference = self.0 - rhs.0;
+                let borrow = gt_biased(rhs.0, self.0, Uint64x4::splat(SIGN));
+
+                Self(difference + to_high(borrow))
+            }
+        }
+
+        impl_operator! { $name, BitAnd, bitand,
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl_operator! { $name, BitOr, bitor,
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl_operator! { $name, BitXor, bitxor,
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl ops::Not for $name {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self::Output {
+                Self(!self.0)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                <[$type; 2] as fmt::Debug>::fmt(&self.to_array(), f)
+            }
+        }
+    };
+}
+
+make_vector_type!(Uint128x2, u128, u128, SIGN);
+make_vector_type!(Int128x2, i128, u128, 0);
+
+impl Int128x2 {
+    #[inline(always)]
+    #[must_use]
+    pub fn shr_a<const N: i32>(self) -> Self {
+        let n = (N as u32).min(i128::BITS - 1);
+        let high = Uint64x4::from_array([0, !0, 0, !0]);
+
+        if n < 64 {
+            let logical = self.0.shr_l_by(n) | to_low(self.0).shl_by(64 - n);
+            let arithmetic = self.0.transmute::<Int64x4>().shr_a_by(n).transmute();
+
+            Self(Uint64x4::bitselect(high, arithmetic, logical))
+        } else {
+            let x = broadcast_high(self.0).transmute::<Int64x4>();
+            let sign = x.shr_a::<63>().transmute();
+
+            Self(Uint64x4::bitselect(high, sign, x.shr_a_by(n - 64).transmute()))
+        }
+    }
+}
+
+impl From<Int128x2> for Uint128x2 {
+    #[inline(always)]
+    fn from(x: Int128x2) -> Self {
+        Self(x.0)
+    }
+}
+
+impl From<Uint128x2> for Int128x2 {
+    #[inline(always)]
+    fn from(x: Uint128x2) -> Self {
+        Self(x.0)
+    }
+}
@@ -0,0 +1,122 @@
+macro_rules! impl_mul_widening {
+    ($name: ty => $wide: ty $(, $even: ty)?) => {
+        impl $name {
+            /// Exact products of the lower and the upper half of lanes, in lanes twice as wide.
+            #[inline(always)]
+            #[must_use]
+            pub fn mul_widening(self, rhs: Self) -> ($wide, $wide) {
+                let widen = |x: Self| (x.low().convert::<$wide>(), x.high().convert::<$wide>());
+                let ((low, high), (rhs_low, rhs_high)) = (widen(self), widen(rhs));
+
+                impl_mul_widening!(@multiply low, high, rhs_low, rhs_high $(, $even)?)
+            }
+        }
+    };
+
+    (@multiply $low: ident, $high: ident, $rhs_low: ident, $rhs_high: ident) => {
+        ($low * $rhs_low, $high * $rhs_high)
+    };
+
+    // There is no 64-bit multiplication, but after extension the values are in the even 32-bit
+    // lanes, whose full products `mul_even_widening` computes.
+    (@multiply $low: ident, $high: ident, $rhs_low: ident, $rhs_high: ident, $even: ty) => {
+        (
+            $low.transmute::<$even>().mul_even_widening($rhs_low.transmute()),
+            $high.transmute::<$even>().mul_even_widening($rhs_high.transmute()),
+        )
+    };
+}
+
+macro_rules! impl_mul_wide {
+    ($name: ty, $half: ty) => {
+        impl $name {
+            /// Full 128-bit products, split into the low and the high 64 bits. They are assembled
+            /// from four 32x32-bit partial products.
+            #[inline(always)]
+            #[must_use]
+            pub fn mul_wide(self, rhs: Self) -> (Self, Self) {
+                let multiply = |a: Self, b: Self| -> Self {
+                    a.transmute::<$half>().mul_even_widening(b.transmute()).transmute()
+                };
+                let (a_high, b_high) = (self.shr_l::<32>(), rhs.shr_l::<32>());
+                let mask = Self::splat(0xffff_ffff);
+
+                let low_low = multiply(self, rhs);
+                let low_high = multiply(self, b_high);
+                let high_low = multiply(a_high, rhs);
+                let high_high = multiply(a_high, b_high);
+
+                // Sums of the middle 32-bit columns, which can't overflow.
+                let middle = low_low.shr_l::<32>() + (low_high & mask) + (high_low & mask);
+                let high = high_high + low_high.shr_l::<32>() + high_low.shr_l::<32>();
+
+                ((low_low & mask) | middle.shl::<32>(), high + middle.shr_l::<32>())
+            }
+        }
+    };
+}
+
+macro_rules! impl_saturating_mul {
+    ($name: ty => $wide: ty, $signed_wide: ty, $narrow: ident $(, $max: literal)?) => {
+        impl $name {
+            /// Products saturated at the bounds of the element type.
+            #[inline(always)]
+            #[must_use]
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                let (low, high) = self.mul_widening(rhs);
+
+                // Unsigned products are clamped first, so they stay positive as signed lanes.
+                $(
+                    let max = <$wide>::splat($max);
+                    let (low, high) = (low.min(max), high.min(max));
+                )?
+
+                let (low, high) = (low.transmute::<$signed_wide>(), high.transmute());
+
+                low.$narrow(high).transmute()
+            }
+        }
+    };
+}
+
+macro_rules! impl_mul_widening_for_backend {
+    ($($backend: ident)::*) => {
+        impl_mul_widening!($($backend)::*::Int8x32 => $($backend)::*::Int16x16);
+        impl_mul_widening!($($backend)::*::Uint8x32 => $($backend)::*::Uint16x16);
+
+        impl_mul_widening!($($backend)::*::Int16x16 => $($backend)::*::Int32x8);
+        impl_mul_widening!($($backend)::*::Uint16x16 => $($backend)::*::Uint32x8);
+
+        impl_mul_widening!(
+            $($backend)::*::Int32x8 => $($backend)::*::Int64x4, $($backend)::*::Int32x8
+        );
+        impl_mul_widening!(
+            $($backend)::*::Uint32x8 => $($backend)::*::Uint64x4, $($backend)::*::Uint32x8
+        );
+
+        impl_saturating_mul!(
+            $($backend)::*::Int8x32 => $($backend)::*::Int16x16, $($backend)::*::Int16x16,
+            narrow_saturating
+        );
+        impl_saturating_mul!(
+            $($backend)::*::Uint8x32 => $($backend)::*::Uint16x16, $($backend)::*::Int16x16,
+            narrow_saturating_unsigned, 0xff
+        );
+        impl_saturating_mul!(
+            $($backend)::*::Int16x16 => $($backend)::*::Int32x8, $($backend)::*::Int32x8,
+            narrow_saturating
+        );
+        impl_saturating_mul!(
+            $($backend)::*::Uint16x16 => $($backend)::*::Uint32x8, $($backend)::*::Int32x8,
+            narrow_saturating_unsigned, 0xffff
+        );
+
+        impl_mul_wide!($($backend)::*::Uint64x2, $($backend)::*::Uint32x4);
+        impl_mul_wide!($($backend)::*::Uint64x4, $($backend)::*::Uint32x8);
+    };
+}
+
+impl_mul_widening_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_mul_widening_for_backend!(crate::reference);
@@ -0,0 +1,140 @@
+use core::ops;
+
+/// Integer vector whose arithmetic operators wrap around on overflow, like
+/// [`std::num::Wrapping`]. Shift amounts are taken modulo the lane width.
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(transparent)]
+pub struct Wrapping<T>(pub T);
+
+/// Integer vector whose arithmetic operators saturate at the bounds of the element type, like
+/// [`std::num::Saturating`]. Multiplication is available only for 8-bit and 16-bit elements,
+/// whose products are computed exactly in lanes twice as wide and then narrowed with saturation.
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+macro_rules! impl_wrapper_operator {
+    ($wrapper: ident, $name: ty, $op: ident, $op_function: ident, $op_assign: ident,
+     $op_assign_function: ident, |$a: ident, $b: ident| $body: expr) => {
+        impl ops::$op for $wrapper<$name> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: Self) -> Self {
+                let ($a, $b) = (self.0, rhs.0);
+                $wrapper($body)
+            }
+        }
+
+        impl ops::$op_assign for $wrapper<$name> {
+            #[inline(always)]
+            fn $op_assign_function(&mut self, rhs: Self) {
+                *self = ops::$op::$op_function(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_bitwise_operators {
+    ($wrapper: ident, $name: ty) => {
+        impl_wrapper_operator!($wrapper, $name, BitAnd, bitand, BitAndAssign, bitand_assign,
+            |a, b| a & b);
+        impl_wrapper_operator!($wrapper, $name, BitOr, bitor, BitOrAssign, bitor_assign,
+            |a, b| a | b);
+        impl_wrapper_operator!($wrapper, $name, BitXor, bitxor, BitXorAssign, bitxor_assign,
+            |a, b| a ^ b);
+
+        impl ops::Not for $wrapper<$name> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn not(self) -> Self {
+                $wrapper(!self.0)
+            }
+        }
+    };
+}
+
+macro_rules! impl_wrapping_shift {
+    ($name: ty, $bits: literal, $op: ident, $op_function: ident, $op_assign: ident,
+     $op_assign_function: ident, $symbol: tt) => {
+        impl ops::$op<u32> for Wrapping<$name> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op_function(self, rhs: u32) -> Self {
+                Wrapping(self.0 $symbol (rhs & ($bits - 1)))
+            }
+        }
+
+        impl ops::$op_assign<u32> for Wrapping<$name> {
+            #[inline(always)]
+            fn $op_assign_function(&mut self, rhs: u32) {
+                *self = ops::$op::$op_function(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_wrapping {
+    ($name: ty, $bits: literal) => {
+        impl_wrapper_operator!(Wrapping, $name, Add, add, AddAssign, add_assign, |a, b| a + b);
+        impl_wrapper_operator!(Wrapping, $name, Sub, sub, SubAssign, sub_assign, |a, b| a - b);
+        impl_wrapper_operator!(Wrapping, $name, Mul, mul, MulAssign, mul_assign, |a, b| a * b);
+        impl_wrapping_shift!($name, $bits, Shl, shl, ShlAssign, shl_assign, <<);
+        impl_wrapping_shift!($name, $bits, Shr, shr, ShrAssign, shr_assign, >>);
+        impl_bitwise_operators!(Wrapping, $name);
+
+        impl ops::Neg for Wrapping<$name> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Wrapping(<$name>::zero() - self.0)
+            }
+        }
+    };
+}
+
+macro_rules! impl_saturating {
+    ($name: ty) => {
+        impl_wrapper_operator!(Saturating, $name, Add, add, AddAssign, add_assign,
+            |a, b| a.saturating_add(b));
+        impl_wrapper_operator!(Saturating, $name, Sub, sub, SubAssign, sub_assign,
+            |a, b| a.saturating_sub(b));
+        impl_bitwise_operators!(Saturating, $name);
+    };
+
+    (mul: $name: ty) => {
+        impl_saturating!($name);
+        impl_wrapper_operator!(Saturating, $name, Mul, mul, MulAssign, mul_assign,
+            |a, b| a.saturating_mul(b));
+    };
+}
+
+macro_rules! impl_wrappers_for_backend {
+    ($($backend: ident)::*) => {
+        impl_wrapping!($($backend)::*::Int8x32, 8);
+        impl_wrapping!($($backend)::*::Uint8x32, 8);
+        impl_wrapping!($($backend)::*::Int16x16, 16);
+        impl_wrapping!($($backend)::*::Uint16x16, 16);
+        impl_wrapping!($($backend)::*::Int32x8, 32);
+        impl_wrapping!($($backend)::*::Uint32x8, 32);
+        impl_wrapping!($($backend)::*::Int64x4, 64);
+        impl_wrapping!($($backend)::*::Uint64x4, 64);
+
+        impl_saturating!(mul: $($backend)::*::Int8x32);
+        impl_saturating!(mul: $($backend)::*::Uint8x32);
+        impl_saturating!(mul: $($backend)::*::Int16x16);
+        impl_saturating!(mul: $($backend)::*::Uint16x16);
+        impl_saturating!($($backend)::*::Int32x8);
+        impl_saturating!($($backend)::*::Uint32x8);
+        impl_saturating!($($backend)::*::Int64x4);
+        impl_saturating!($($backend)::*::Uint64x4);
+    };
+}
+
+impl_wrappers_for_backend!(crate);
+
+#[cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+impl_wrappers_for_backend!(crate::reference);
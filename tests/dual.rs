@@ -0,0 +1,90 @@
+//! Checks derivatives computed with dual numbers against closed-form derivatives and against
+//! central differences computed in `f64`.
+
+mod common;
+
+use common::Rng;
+use packed_vectors::{DualF32x8, Float32x8};
+
+const ITERATIONS: u64 = 1000;
+
+/// Positive values between 1/64 and 64, where `f32` derivatives are accurate.
+fn random_vector(rng: &mut Rng) -> [f32; 8] {
+    core::array::from_fn(|_| {
+        let t = (rng.next() >> 40) as f32 / (1 << 24) as f32;
+
+        (t * 12.0 - 6.0).exp2()
+    })
+}
+
+fn central_difference(f: impl Fn(f64) -> f64, x: f64) -> f64 {
+    let h = x * 1e-6;
+
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+fn assert_close(actual: Float32x8, expected: [f64; 8], what: &str, inputs: [f32; 8]) {
+    for (i, &actual) in actual.to_array().iter().enumerate() {
+        let error = (actual as f64 - expected[i]).abs() / expected[i].abs();
+
+        assert!(
+            error < 1e-4,
+            "{what} at {} is {actual}, expected {}",
+            inputs[i],
+            expected[i],
+        );
+    }
+}
+
+#[test]
+fn sqrt_derivative() {
+    for seed in 0..ITERATIONS {
+        let x = random_vector(&mut Rng::new(seed));
+        let result = DualF32x8::variable(Float32x8::from_array(x)).sqrt();
+
+        let closed_form = x.map(|x| 0.5 / (x as f64).sqrt());
+        let difference = x.map(|x| central_difference(f64::sqrt, x as f64));
+
+        assert_close(result.derivative, closed_form, "d/dx sqrt(x)", x);
+        assert_close(result.derivative, difference, "d/dx sqrt(x)", x);
+    }
+}
+
+#[test]
+fn recip_derivative() {
+    for seed in 0..ITERATIONS {
+        let x = random_vector(&mut Rng::new(seed));
+        let result = DualF32x8::variable(Float32x8::from_array(x)).recip();
+
+        let closed_form = x.map(|x| -1.0 / (x as f64 * x as f64));
+        let difference = x.map(|x| central_difference(f64::recip, x as f64));
+
+        assert_close(result.derivative, closed_form, "d/dx 1/x", x);
+        assert_close(result.derivative, difference, "d/dx 1/x", x);
+    }
+}
+
+#[test]
+fn div_derivative() {
+    for seed in 0..ITERATIONS {
+        let mut rng = Rng::new(seed);
+        let (x, c) = (random_vector(&mut rng), random_vector(&mut rng));
+
+        let variable = DualF32x8::variable(Float32x8::from_array(x));
+        let constant = DualF32x8::constant(Float32x8::from_array(c));
+
+        // d/dx x/c = 1/c
+        let expected = core::array::from_fn(|i| 1.0 / c[i] as f64);
+        assert_close((variable / constant).derivative, expected, "d/dx x/c", x);
+
+        // d/dx c/x = -c/x²
+        let expected = core::array::from_fn(|i| -(c[i] as f64) / (x[i] as f64 * x[i] as f64));
+        assert_close((constant / variable).derivative, expected, "d/dx c/x", x);
+
+        // d/dx x²/(x+c) = x(x+2c)/(x+c)²
+        let f = |i: usize| move |x: f64| x * x / (x + c[i] as f64);
+        let expected = core::array::from_fn(|i| central_difference(f(i), x[i] as f64));
+        let result = (variable * variable) / (variable + constant);
+        assert_close(result.derivative, expected, "d/dx x²/(x+c)", x);
+    }
+}
@@ -0,0 +1,180 @@
+//! Checks that results of interval arithmetic contain the exact results, which are computed in
+//! `f64`. Products and sums of two `f32` values are exact there, and rounding quotients to `f64`
+//! can't move them past an `f32` bound.
+
+mod common;
+
+use common::Rng;
+use packed_vectors::{Float32x8, IntervalF32x8};
+
+const ITERATIONS: u64 = 10000;
+
+/// Finite floats of all magnitudes, a quarter of them exponent edge cases and signed zeros.
+fn random_lane(rng: &mut Rng) -> f32 {
+    const EDGES: [f32; 8] = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f32::MIN_POSITIVE,
+        1e-45,
+        f32::MAX,
+        f32::MIN,
+    ];
+
+    if rng.next().is_multiple_of(4) {
+        return EDGES[rng.next() as usize % EDGES.len()];
+    }
+
+    loop {
+        let x = f32::from_bits(rng.next() as u32);
+
+        if x.is_finite() {
+            return x;
+        }
+    }
+}
+
+fn random_vector(rng: &mut Rng) -> [f32; 8] {
+    core::array::from_fn(|_| random_lane(rng))
+}
+
+/// Asserts that every lane of `result` contains the corresponding lane of `exact`.
+fn assert_contains(
+    result: IntervalF32x8,
+    exact: [f64; 8],
+    operation: &str,
+    a: [f32; 8],
+    b: [f32; 8],
+) {
+    let (lo, hi) = (result.lo.to_array(), result.hi.to_array());
+
+    for i in 0..8 {
+        assert!(
+            lo[i] as f64 <= exact[i] && exact[i] <= hi[i] as f64,
+            "{} {operation} {} = {}, which is outside of [{}, {}]",
+            a[i],
+            b[i],
+            exact[i],
+            lo[i],
+            hi[i],
+        );
+    }
+}
+
+#[test]
+fn point_arithmetic_contains_exact_results() {
+    for seed in 0..ITERATIONS {
+        let mut rng = Rng::new(seed);
+        let (a, b) = (random_vector(&mut rng), random_vector(&mut rng));
+
+        let x = IntervalF32x8::point(Float32x8::from_array(a));
+        let y = IntervalF32x8::point(Float32x8::from_array(b));
+
+        let exact =
+            |op: fn(f64, f64) -> f64| core::array::from_fn(|i| op(a[i] as f64, b[i] as f64));
+
+        assert_contains(x + y, exact(|a, b| a + b), "+", a, b);
+        assert_contains(x - y, exact(|a, b| a - b), "-", a, b);
+        assert_contains(x * y, exact(|a, b| a * b), "*", a, b);
+
+        // Division by zero is checked separately.
+        let b = b.map(|x| if x == 0.0 { 1.0 } else { x });
+        let y = IntervalF32x8::point(Float32x8::from_array(b));
+        let exact = core::array::from_fn(|i| a[i] as f64 / b[i] as f64);
+
+        assert_contains(x / y, exact, "/", a, b);
+    }
+}
+
+#[test]
+fn arithmetic_contains_results_for_all_members() {
+    for seed in 0..ITERATIONS {
+        let mut rng = Rng::new(seed);
+
+        let mut bounds = || {
+            let (a, b) = (random_vector(&mut rng), random_vector(&mut rng));
+            let lo: [f32; 8] = core::array::from_fn(|i| a[i].min(b[i]));
+            let hi: [f32; 8] = core::array::from_fn(|i| a[i].max(b[i]));
+
+            (lo, hi)
+        };
+        let ((a_lo, a_hi), (b_lo, b_hi)) = (bounds(), bounds());
+
+        let x = IntervalF32x8::new(Float32x8::from_array(a_lo), Float32x8::from_array(a_hi));
+        let y = IntervalF32x8::new(Float32x8::from_array(b_lo), Float32x8::from_array(b_hi));
+
+        // Members at both bounds and in the middle of the intervals.
+        let middle = |lo: [f32; 8], hi: [f32; 8]| -> [f32; 8] {
+            core::array::from_fn(|i| (lo[i] / 2.0 + hi[i] / 2.0).clamp(lo[i], hi[i]))
+        };
+        let members = [
+            (a_lo, b_hi),
+            (a_hi, b_lo),
+            (middle(a_lo, a_hi), middle(b_lo, b_hi)),
+        ];
+
+        for (a, b) in members {
+            let exact =
+                |op: fn(f64, f64) -> f64| core::array::from_fn(|i| op(a[i] as f64, b[i] as f64));
+
+            assert_contains(x + y, exact(|a, b| a + b), "+", a, b);
+            assert_contains(x - y, exact(|a, b| a - b), "-", a, b);
+            assert_contains(x * y, exact(|a, b| a * b), "*", a, b);
+
+            let quotient = exact(|a, b| a / b);
+            if quotient.iter().all(|x| x.is_finite()) {
+                assert_contains(x / y, quotient, "/", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn zero_times_infinity_contains_zero() {
+    let zero = IntervalF32x8::point(Float32x8::zero());
+    let infinity = IntervalF32x8::point(Float32x8::splat(f32::INFINITY));
+
+    for product in [
+        zero * infinity,
+        infinity * zero,
+        zero * IntervalF32x8::entire(),
+    ] {
+        assert_eq!(product.contains(Float32x8::zero()).mask(), 0xff);
+    }
+}
+
+#[test]
+fn division_by_intervals_containing_zero_is_entire() {
+    let one = IntervalF32x8::point(Float32x8::splat(1.0));
+    let divisors = [
+        IntervalF32x8::point(Float32x8::zero()),
+        IntervalF32x8::point(Float32x8::splat(-0.0)),
+        IntervalF32x8::new(Float32x8::splat(-1.0), Float32x8::splat(1.0)),
+        IntervalF32x8::new(Float32x8::zero(), Float32x8::splat(2.0)),
+        IntervalF32x8::new(Float32x8::splat(-2.0), Float32x8::splat(-0.0)),
+    ];
+
+    for divisor in divisors {
+        let quotient = one / divisor;
+
+        assert_eq!(quotient.lo.to_array(), [f32::NEG_INFINITY; 8]);
+        assert_eq!(quotient.hi.to_array(), [f32::INFINITY; 8]);
+    }
+}
+
+/// Both zeros are rounded outwards to the smallest subnormals.
+#[test]
+fn zero_results_are_widened_past_both_zeros() {
+    let negative_zero = IntervalF32x8::point(Float32x8::splat(-0.0));
+    let zero = IntervalF32x8::point(Float32x8::zero());
+
+    for result in [
+        negative_zero - zero,
+        negative_zero + negative_zero,
+        zero + zero,
+    ] {
+        assert_eq!(result.lo.to_array(), [-f32::from_bits(1); 8]);
+        assert_eq!(result.hi.to_array(), [f32::from_bits(1); 8]);
+    }
+}
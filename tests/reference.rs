@@ -0,0 +1,649 @@
+//! Differential tests comparing the intrinsic based vector types with the scalar ones from
+//! `packed_vectors::reference` on random and edge case inputs. They need the `reference` feature
+//! and should be run both with and without AVX2, for example with
+//! `RUSTFLAGS="-C target-cpu=x86-64-v2"`, so that the SSE emulation of 256-bit vectors is covered.
+//! With the `avx512` feature the types from `packed_vectors::avx512` are also compared with the
+//! double-pumped 512-bit types.
+
+#![cfg(all(feature = "reference", target_feature = "sse4.1", not(feature = "soft")))]
+
+mod common;
+
+use common::Rng;
+
+const ITERATIONS: u64 = 1000;
+
+trait Lane: Copy + 'static {
+    const EDGES: &'static [Self];
+
+    fn random(bits: u64) -> Self;
+
+    /// Bits which have to match between both implementations. All NaNs are equal, because their
+    /// payloads are not specified.
+    fn to_bits(self) -> u64;
+
+    /// Every fourth lane is an edge case, the other ones are random.
+    fn generate(rng: &mut Rng) -> Self {
+        if rng.next().is_multiple_of(4) {
+            Self::EDGES[rng.next() as usize % Self::EDGES.len()]
+        } else {
+            Self::random(rng.next())
+        }
+    }
+}
+
+macro_rules! impl_lane {
+    (int: $($type: ty),*) => {
+        $(
+            impl Lane for $type {
+                const EDGES: &'static [Self] = &[
+                    0, 1, 2, 3, <$type>::MIN, <$type>::MAX, <$type>::MIN + 1, <$type>::MAX - 1,
+                    <$type>::MIN / 2, <$type>::MAX / 2, <$type>::MAX / 2 + 1,
+                ];
+
+                fn random(bits: u64) -> Self {
+                    bits as $type
+                }
+
+                fn to_bits(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+
+    (float: $($type: ty, $bits: ty, [$($edge: expr),*]);*) => {
+        $(
+            impl Lane for $type {
+                const EDGES: &'static [Self] = &[
+                    0.0, -0.0, 0.5, -0.5, 1.5, 2.5, -2.5, <$type>::MIN, <$type>::MAX,
+                    <$type>::MIN_POSITIVE, -<$type>::MIN_POSITIVE, <$type>::from_bits(1),
+                    -<$type>::from_bits(1), <$type>::INFINITY, <$type>::NEG_INFINITY, <$type>::NAN,
+                    $($edge),*
+                ];
+
+                // Random bit patterns cover every class of floats, but they are rarely small
+                // enough for rounding and conversions to integers, so half of lanes are small
+                // values with a fraction.
+                fn random(bits: u64) -> Self {
+                    if bits & 1 == 0 {
+                        <$type>::from_bits(bits as $bits)
+                    } else {
+                        (bits as i32 >> 8) as $type / 256.0
+                    }
+                }
+
+                fn to_bits(self) -> u64 {
+                    if self.is_nan() {
+                        u64::MAX
+                    } else {
+                        <$type>::to_bits(self) as u64
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_lane!(int: i8, u8, i16, u16, i32, u32, i64, u64);
+impl_lane!(float:
+    f32, u32, [2147483648.0, -2147483648.0, 2147483520.0, -2147483904.0, 8388608.5];
+    f64, u64, [2147483648.0, -2147483648.0, 2147483647.5, -2147483648.5, 4503599627370496.5]
+);
+
+trait Input {
+    fn generate(rng: &mut Rng) -> Self;
+}
+
+impl<T: Lane, const N: usize> Input for [T; N] {
+    fn generate(rng: &mut Rng) -> Self {
+        core::array::from_fn(|_| T::generate(rng))
+    }
+}
+
+/// Shift and rotation counts, including ones at and past the lane width.
+impl Input for u32 {
+    fn generate(rng: &mut Rng) -> Self {
+        const EDGES: [u32; 16] = [0, 1, 7, 8, 9, 15, 16, 17, 31, 32, 33, 63, 64, 65, 128, u32::MAX];
+
+        if rng.next().is_multiple_of(2) {
+            EDGES[rng.next() as usize % EDGES.len()]
+        } else {
+            (rng.next() % 72) as u32
+        }
+    }
+}
+
+/// Divisors of `Int32x8` lanes, half of them ones which need special magic numbers or overflow
+/// them.
+#[derive(Copy, Clone, Debug)]
+struct Divisors([i32; 8]);
+
+impl Input for Divisors {
+    fn generate(rng: &mut Rng) -> Self {
+        const EDGES: [i32; 9] = [1, -1, 3, 7, -7, 641, i32::MIN, i32::MAX, 0x4000_0000];
+
+        Self(core::array::from_fn(|_| {
+            if rng.next().is_multiple_of(2) {
+                EDGES[rng.next() as usize % EDGES.len()]
+            } else {
+                i32::generate(rng)
+            }
+        }))
+    }
+}
+
+/// The reference backend has no precomputed divisors, so they divide by the plain divisor there.
+/// Glob imports of the native backend shadow it.
+#[derive(Copy, Clone)]
+struct DivisorInt32x8(packed_vectors::reference::Int32x8);
+
+impl DivisorInt32x8 {
+    fn new(divisor: packed_vectors::reference::Int32x8) -> Self {
+        Self(divisor)
+    }
+}
+
+impl std::ops::Div<DivisorInt32x8> for packed_vectors::reference::Int32x8 {
+    type Output = Self;
+
+    fn div(self, rhs: DivisorInt32x8) -> Self {
+        self / rhs.0
+    }
+}
+
+impl std::ops::Rem<DivisorInt32x8> for packed_vectors::reference::Int32x8 {
+    type Output = Self;
+
+    fn rem(self, rhs: DivisorInt32x8) -> Self {
+        self % rhs.0
+    }
+}
+
+trait Output {
+    fn bits(self) -> Vec<u64>;
+}
+
+impl<T: Lane> Output for T {
+    fn bits(self) -> Vec<u64> {
+        vec![self.to_bits()]
+    }
+}
+
+impl Output for bool {
+    fn bits(self) -> Vec<u64> {
+        vec![self as u64]
+    }
+}
+
+macro_rules! impl_output_for_tuple {
+    ($($name: ident),*) => {
+        #[allow(non_snake_case)]
+        impl<$($name: Output),*> Output for ($($name,)*) {
+            fn bits(self) -> Vec<u64> {
+                let ($($name,)*) = self;
+
+                [$($name.bits()),*].concat()
+            }
+        }
+    };
+}
+
+impl_output_for_tuple!(A, B);
+impl_output_for_tuple!(A, B, C);
+impl_output_for_tuple!(A, B, C, D);
+impl_output_for_tuple!(A, B, C, D, E);
+
+macro_rules! impl_vectors {
+    ($($name: ident),*) => {
+        $(
+            impl Input for $name {
+                fn generate(rng: &mut Rng) -> Self {
+                    Self::from_array(Input::generate(rng))
+                }
+            }
+
+            impl Output for $name {
+                fn bits(self) -> Vec<u64> {
+                    self.to_array().into_iter().map(Lane::to_bits).collect()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_vectors_for_backend {
+    ($module: ident, $($backend: ident)::*) => {
+        mod $module {
+            use super::{Input, Lane, Output, Rng};
+            pub use $($backend)::*::*;
+
+            impl_vectors!(
+                Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+                Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4,
+                Float32x4, Float64x2, Float32x8, Float64x4
+            );
+        }
+    };
+}
+
+impl_vectors_for_backend!(native, packed_vectors);
+impl_vectors_for_backend!(reference, packed_vectors::reference);
+
+/// One bit per lane. Comparisons of `packed_vectors::avx512` types return it directly, vector masks
+/// of the other ones are converted from `mask()`.
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+trait LaneMask {
+    fn lane_mask(self) -> u64;
+}
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+macro_rules! impl_lane_mask {
+    (bits: $($type: ty),*) => {
+        $(
+            impl LaneMask for $type {
+                fn lane_mask(self) -> u64 {
+                    self as u64
+                }
+            }
+        )*
+    };
+
+    (vectors: $($name: ident),*) => {
+        $(
+            impl LaneMask for $name {
+                fn lane_mask(self) -> u64 {
+                    self.mask() as u64
+                }
+            }
+        )*
+    };
+
+    // Masks of integer vectors have one bit per byte, the most significant one of every lane is
+    // taken.
+    (bytes: $($name: ident, $lanes: literal),*) => {
+        $(
+            impl LaneMask for $name {
+                fn lane_mask(self) -> u64 {
+                    let (mask, size) = (self.mask(), 64 / $lanes);
+
+                    (0..$lanes).fold(0, |bits, i| bits | ((mask >> (i * size + size - 1)) & 1) << i)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+impl_lane_mask!(bits: u8, u16, u32, u64);
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+mod double {
+    use super::{Input, Lane, LaneMask, Output, Rng};
+    use packed_vectors::*;
+
+    impl_vectors!(
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8,
+        Float32x16, Float64x8
+    );
+    impl_lane_mask!(bytes:
+        Int8x64, 64, Uint8x64, 64, Int16x32, 32, Uint16x32, 32, Int32x16, 16, Uint32x16, 16,
+        Int64x8, 8, Uint64x8, 8
+    );
+    impl_lane_mask!(vectors: Float32x16, Float64x8);
+}
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+mod avx512 {
+    use super::{Input, Lane, LaneMask, Output, Rng};
+    pub use packed_vectors::avx512::*;
+
+    impl_vectors!(
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8,
+        Float32x16, Float64x8
+    );
+    impl_lane_mask!(vectors:
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8,
+        Float32x16, Float64x8
+    );
+}
+
+/// Defines a test which runs `$body` with the same inputs on the intrinsic based and on the
+/// reference implementation of every listed vector type and compares the results. `T` is the
+/// vector type which is currently tested. With `avx512:` the types from `packed_vectors::avx512`
+/// are compared with the double-pumped ones instead.
+macro_rules! differential {
+    ($($test: ident $arguments: tt for $($name: ident),+ => $body: expr;)*) => {
+        $(
+            #[test]
+            fn $test() {
+                $(differential!(@compare reference, native, $name, $arguments, $body);)+
+            }
+        )*
+    };
+
+    (avx512: $($test: ident $arguments: tt for $($name: ident),+ => $body: expr;)*) => {
+        $(
+            #[test]
+            fn $test() {
+                $(differential!(@compare native, avx512, $name, $arguments, $body);)+
+            }
+        )*
+    };
+
+    (
+        @compare $expected: ident, $actual: ident, $name: ident,
+        ($($argument: ident: $type: ty),*), $body: expr
+    ) => {
+        for seed in 0..ITERATIONS {
+            let (inputs, expected) = {
+                use crate::$expected::*;
+                #[allow(dead_code)]
+                type T = $name;
+
+                let mut rng = Rng::new(seed);
+                $(let $argument = <$type as Input>::generate(&mut rng);)*
+
+                (format!("{:?}", ($($argument,)*)), Output::bits($body))
+            };
+
+            let actual = {
+                use crate::$actual::*;
+                #[allow(dead_code)]
+                type T = $name;
+
+                let mut rng = Rng::new(seed);
+                $(let $argument = <$type as Input>::generate(&mut rng);)*
+
+                Output::bits($body)
+            };
+
+            assert_eq!(
+                actual,
+                expected,
+                "`{}` for {} differs on {}",
+                stringify!($body),
+                stringify!($name),
+                inputs,
+            );
+        }
+    };
+}
+
+differential! {
+    integer_arithmetic(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x + y, x - y, x.mul_lo(y), x.abs_diff(y));
+
+    integer_bitwise(x: T, y: T, z: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x & y, x | y, x ^ y, (!x, x.andnot(y)), T::bitselect(x, y, z));
+
+    integer_comparisons(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => ((x.eq(y), x.ne(y), x.lt(y)), (x.le(y), x.gt(y), x.ge(y)));
+
+    integer_min_max(x: T, y: T, z: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.min(y), x.max(y), x.clamp(y, z));
+
+    shifts(x: T, n: u32) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => ((x.shl::<1>(), x.shl::<7>()), (x.shr_l::<1>(), x.shr_l::<7>()), x.shl_by(n),
+            x.shr_l_by(n), (x << n, x >> n));
+
+    arithmetic_shifts(x: T, n: u32) for
+        Int8x16, Int16x8, Int32x4, Int64x2, Int8x32, Int16x16, Int32x8, Int64x4
+        => (x.shr_a::<1>(), x.shr_a::<7>(), x.shr_a_by(n));
+
+    rotations(x: T, n: u32) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.rotate_left::<3>(), x.rotate_right::<5>(), x.rotate_left_by(n),
+            x.rotate_right_by(n));
+
+    negation(x: T) for
+        Int8x16, Int16x8, Int32x4, Int64x2, Int8x32, Int16x16, Int32x8, Int64x4
+        => (-x, x.wrapping_neg(), x.abs());
+
+    bit_counts(x: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.count_ones(), x.reverse_bits(), x.leading_zeros(), x.trailing_zeros());
+
+    swap_bytes(x: T) for
+        Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => x.swap_bytes();
+
+    saturating_arithmetic(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.saturating_add(y), x.saturating_sub(y));
+
+    saturating_mul(x: T, y: T) for Int8x32, Uint8x32, Int16x16, Uint16x16
+        => x.saturating_mul(y);
+
+    average(x: T, y: T) for Uint8x16, Uint16x8, Uint8x32, Uint16x16 => x.avg(y);
+
+    mul_hi(x: T, y: T) for Int16x8, Int16x16 => (x.mul_hi(y), x.mul_hrs(y), x.madd(y));
+
+    mul_widening(x: T, y: T) for Int8x32, Uint8x32, Int16x16, Uint16x16
+        => x.mul_widening(y);
+
+    mul_even_widening(x: T, y: T) for Int32x4, Uint32x4, Int32x8, Uint32x8
+        => x.mul_even_widening(y);
+
+    mul_wide(x: T, y: T) for Uint64x2, Uint64x4 => x.mul_wide(y);
+
+    maddubs_128(x: T, y: Int8x16, z: Int32x4) for Uint8x16
+        => (x.maddubs(y), x.dot_accumulate(y, z));
+
+    maddubs_256(x: T, y: Int8x32, z: Int32x8) for Uint8x32
+        => (x.maddubs(y), x.dot_accumulate(y, z));
+
+    sad_128(x: T, y: T) for Uint8x16
+        => (x.sad(y), x.mpsadbw::<0>(y), x.mpsadbw::<5>(y), x.mpsadbw::<7>(y));
+
+    sad_256(x: T, y: T) for Uint8x32
+        => (x.sad(y), x.mpsadbw::<0>(y), x.mpsadbw::<0b101_010>(y), x.mpsadbw::<0b111_111>(y));
+
+    horizontal(x: T, y: T) for
+        Int16x8, Uint16x8, Int32x4, Uint32x4, Int16x16, Uint16x16, Int32x8, Uint32x8
+        => (x.hadd(y), x.hsub(y));
+
+    saturating_horizontal(x: T, y: T) for Int16x8, Int16x16
+        => (x.saturating_hadd(y), x.saturating_hsub(y));
+
+    pack(x: T, y: T) for Int16x8, Int32x4, Int16x16, Int32x8
+        => (x.pack_saturating(y), x.pack_saturating_unsigned(y));
+
+    narrow(x: T, y: T) for Int16x16, Int32x8
+        => (x.narrow_saturating(y), x.narrow_saturating_unsigned(y));
+
+    bit_extract_deposit(x: T, y: T) for
+        Int32x4, Uint32x4, Int64x2, Uint64x2, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.extract_bits(y), x.deposit_bits(y));
+
+    overflowing(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.overflowing_add(y), x.overflowing_sub(y));
+
+    add_with_carry(x: T, y: T, z: T) for Uint32x8, Uint64x4 => x.add_with_carry(y, z);
+
+    division(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x / y, x % y);
+
+    precomputed_division(x: T, y: Divisors) for Int32x8
+        => {
+            let divisor = DivisorInt32x8::new(T::from_array(y.0));
+            (x / divisor, x % divisor)
+        };
+
+    gray_code(x: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.to_gray(), x.from_gray());
+
+    reductions(x: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => ((x.reduce_add(), x.reduce_min(), x.reduce_max()),
+            (x.reduce_and(), x.reduce_or(), x.reduce_xor()));
+
+    shuffle_bytes_128(x: T, indices: Uint8x16) for Int8x16, Uint8x16
+        => x.shuffle_bytes(indices);
+
+    shuffle_bytes_256(x: T, indices: Uint8x32) for Int8x32, Uint8x32
+        => (x.shuffle_bytes(indices), x.shuffle_bytes_crossing(indices));
+
+    lookups(x: T, a: [u8; 16], b: [u8; 32], c: [u8; 64]) for Uint8x16, Uint8x32
+        => (x.lookup16(a), x.lookup32(b), x.lookup64(c), x.low_nibbles(), x.high_nibbles());
+
+    shuffles_16(x: T) for Int16x8, Uint16x8, Int16x16, Uint16x16
+        => (x.shuffle_lo_within_lanes::<0b00_01_10_11>(),
+            x.shuffle_hi_within_lanes::<0b10_11_00_01>());
+
+    shuffles_32(x: T) for Int32x4, Uint32x4, Int32x8, Uint32x8
+        => (x.shuffle_within_lanes::<0b00_01_10_11>(), x.shuffle_lanes::<1, 1, 3, 0>());
+
+    permutes_32(x: T, indices: Int32x8) for Int32x8, Uint32x8, Float32x8
+        => (x.permute(indices), x.compress(indices.mask()));
+
+    permutes_64(x: T) for Int64x4, Uint64x4, Float64x4
+        => (x.permute4x64::<0b00_11_01_10>(), x.permute4x64::<0b01_01_01_01>());
+
+    align_right(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.align_right::<1>(y), x.align_right::<8>(y), x.align_right::<15>(y));
+
+    blend(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8,
+        Uint32x8, Float32x8
+        => (x.blend::<0b0110_1001>(y), x.blend::<0b1100>(y));
+
+    narrow_blend(x: T, y: T) for
+        Int32x4, Uint32x4, Int64x2, Uint64x2, Int64x4, Uint64x4, Float32x4, Float64x2, Float64x4
+        => (x.blend::<0b01>(y), x.blend::<0b10>(y));
+
+    interleave(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4,
+        Float32x4, Float64x2, Float32x8, Float64x4
+        => (x.interleave_lo(y), x.interleave_hi(y));
+
+    broadcast(x: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4,
+        Float32x4, Float64x2, Float32x8, Float64x4
+        => (x.broadcast_lane::<0>(), x.broadcast_lane::<1>(), T::splat(x.to_array()[1]));
+
+    halves(x: T, y: T) for
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4,
+        Float32x8, Float64x4
+        => ((x.low(), x.high()), T::combine(y.low(), x.high()), x.swap_halves(),
+            x.duplicate_low_half(), x.duplicate_high_half());
+
+    insert(x: T, y: T) for
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4
+        => (x.insert::<0>(y.to_array()[0]), x.insert::<3>(y.to_array()[1]));
+
+    masks(x: T, y: T) for
+        Int8x16, Uint8x16, Int16x8, Uint16x8, Int32x4, Uint32x4, Int64x2, Uint64x2,
+        Int8x32, Uint8x32, Int16x16, Uint16x16, Int32x8, Uint32x8, Int64x4, Uint64x4,
+        Float32x4, Float64x2, Float32x8, Float64x4
+        => (x.mask(), x.test(y), x.test_mixed(y), (x.is_zero(), x.is_all_ones()));
+
+    float_arithmetic(x: T, y: T) for Float32x4, Float64x2, Float32x8, Float64x4
+        => (x + y, x - y, x * y, x / y, (x.min(y), x.max(y)));
+
+    float_rounding(x: T) for Float32x4, Float64x2, Float32x8, Float64x4
+        => (x.floor(), x.ceil(), x.trunc(), x.round(), x.sqrt());
+
+    float_bitwise(x: T, y: T, z: T) for Float32x4, Float64x2, Float32x8, Float64x4
+        => (x & y, x | y, x ^ y, (!x, x.andnot(y)), T::bitselect(x, y, z));
+
+    float_shuffles(x: T) for Float32x4, Float32x8
+        => (x.shuffle::<0b00_01_10_11>(), x.shuffle::<0b10_01_11_00>(), x.shuffle_lanes::<1, 1, 3, 0>());
+
+    duplicate_even_odd(x: T) for Float32x8, Float64x4 => (x.dup_even(), x.dup_odd());
+
+    float_conversions(x: Float32x8, y: Float32x4, z: Float64x4) for Float32x8
+        => (x.convert::<Int32x8>(), y.convert::<Int32x4>(), y.convert::<Float64x4>(),
+            z.convert::<Float32x4>(), z.convert::<Int32x4>());
+
+    integer_conversions(x: Int32x8, y: Int32x4) for Int32x8
+        => (x.convert::<Float32x8>(), y.convert::<Float32x4>(), y.convert::<Float64x4>(),
+            y.convert::<Int64x4>(), x.convert::<Uint32x8>());
+
+    widening_conversions(a: Int8x16, b: Uint8x16, c: Int16x8, d: Uint16x8, e: Uint32x4) for Int8x16
+        => (a.convert::<Int16x16>(), b.convert::<Uint16x16>(), c.convert::<Int32x8>(),
+            d.convert::<Uint32x8>(), e.convert::<Uint64x4>());
+}
+
+#[cfg(target_feature = "fma")]
+differential! {
+    fused_multiply_add(x: T, y: T, z: T) for Float32x4, Float64x2, Float32x8, Float64x4
+        => (x.fmadd(y, z), x.fmsub(y, z));
+}
+
+#[cfg(feature = "pclmulqdq")]
+differential! {
+    clmul(x: T, y: T) for Uint64x2, Uint64x4
+        => (x.clmul::<0x00>(y), x.clmul::<0x11>(y), x.clmul::<0x01>(y), x.clmul::<0x10>(y));
+}
+
+// The identity matrix has row `i` in byte `7 - i`, with the rows stored in the opposite order it
+// would reverse the bits.
+#[cfg(feature = "gfni")]
+differential! {
+    gf2p8(x: T, y: T, matrix: Uint64x4) for Uint8x32
+        => (x.gf2p8_affine::<0>(matrix), x.gf2p8_affine_inv::<0x63>(matrix), x.gf2p8_mul(y),
+            x.gf2p8_affine::<0>(Uint64x4::splat(0x0102_0408_1020_4080)));
+}
+
+#[cfg(all(feature = "avx512", target_feature = "avx512f", target_feature = "avx512bw"))]
+differential! { avx512:
+    avx512_integer_arithmetic(x: T, y: T) for
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8
+        => (x + y, x - y, x * y, x.min(y), x.max(y));
+
+    avx512_integer_comparisons(x: T, y: T) for
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8
+        => ((x.eq(y).lane_mask(), x.ne(y).lane_mask(), x.lt(y).lane_mask()),
+            (x.le(y).lane_mask(), x.gt(y).lane_mask(), x.ge(y).lane_mask()));
+
+    avx512_shifts(x: T, n: u32) for
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8
+        => ((x.shl::<1>(), x.shl::<7>()), (x.shr_l::<1>(), x.shr_l::<7>()), x.shl_by(n),
+            x.shr_l_by(n));
+
+    avx512_arithmetic_shifts(x: T, n: u32) for Int8x64, Int16x32, Int32x16, Int64x8
+        => (x.shr_a::<1>(), x.shr_a::<7>(), x.shr_a_by(n));
+
+    avx512_float_arithmetic(x: T, y: T) for Float32x16, Float64x8
+        => (x + y, x - y, x * y, x / y, (x.min(y), x.max(y)));
+
+    avx512_float_comparisons(x: T, y: T) for Float32x16, Float64x8
+        => ((x.eq(y).lane_mask(), x.ne(y).lane_mask(), x.lt(y).lane_mask()),
+            (x.le(y).lane_mask(), x.gt(y).lane_mask(), x.ge(y).lane_mask()));
+
+    avx512_broadcast(x: T) for
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8,
+        Float32x16, Float64x8
+        => (x.broadcast_lane::<0>(), x.broadcast_lane::<5>(), x.broadcast_lane::<7>());
+
+    avx512_masks(x: T) for
+        Int8x64, Uint8x64, Int16x32, Uint16x32, Int32x16, Uint32x16, Int64x8, Uint64x8,
+        Float32x16, Float64x8
+        => (x.lane_mask(), x.is_zero(), x.is_all_ones(), (x & T::zero()).is_zero());
+}